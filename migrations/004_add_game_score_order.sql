@@ -0,0 +1,10 @@
+-- Per-game score direction and default leaderboard sort
+-- score_order decides which end of the board is "best" for ranking and default ordering
+ALTER TABLE game ADD COLUMN score_order TEXT NOT NULL DEFAULT 'higher_is_better'
+    CHECK (score_order IN ('higher_is_better', 'lower_is_better'));
+
+ALTER TABLE game ADD COLUMN default_sort_by TEXT NOT NULL DEFAULT 'score'
+    CHECK (default_sort_by IN ('score', 'date', 'user_name'));
+
+-- Ascending score index for lower-is-better boards
+CREATE INDEX idx_score_game_score_asc ON score(game_hex_id, score_val ASC, id) WHERE deleted_at IS NULL;
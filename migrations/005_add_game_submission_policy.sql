@@ -0,0 +1,6 @@
+-- Per-game submission policy: keep every score, or one entry per player
+ALTER TABLE game ADD COLUMN submission_policy TEXT NOT NULL DEFAULT 'keep_all'
+    CHECK (submission_policy IN ('keep_all', 'keep_best', 'keep_latest'));
+
+-- Lookup of a player's existing entries on a game
+CREATE INDEX idx_score_game_user_id ON score(game_hex_id, user_id) WHERE deleted_at IS NULL;
@@ -0,0 +1,2 @@
+-- Time-windowed leaderboards filter a game's scores by submission time before ranking
+CREATE INDEX idx_score_game_submitted_score ON score(game_hex_id, submitted_at, score_val) WHERE deleted_at IS NULL;
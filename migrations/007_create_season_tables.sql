@@ -0,0 +1,39 @@
+-- Scheduled seasons on a game's leaderboard
+CREATE TABLE season (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_hex_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    starts_at DATETIME NOT NULL,
+    ends_at DATETIME NOT NULL,
+    closed_at DATETIME,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Foreign key constraint
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id),
+
+    -- Check constraints
+    CHECK (length(name) > 0 AND length(name) <= 255),
+    CHECK (ends_at > starts_at)
+);
+
+-- Final standings frozen when a season closes
+CREATE TABLE season_standing (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    season_id INTEGER NOT NULL,
+    rank INTEGER NOT NULL,
+    user_id TEXT NOT NULL,
+    user_name TEXT NOT NULL,
+    score TEXT NOT NULL,
+    score_val REAL NOT NULL,
+    submitted_at DATETIME NOT NULL,
+
+    -- Foreign key constraint
+    FOREIGN KEY (season_id) REFERENCES season(id)
+);
+
+-- Season indexes
+CREATE INDEX idx_season_game_starts_at ON season(game_hex_id, starts_at DESC);
+CREATE INDEX idx_season_open_ends_at ON season(ends_at) WHERE closed_at IS NULL;
+
+-- Archived standings are read in rank order
+CREATE INDEX idx_season_standing_rank ON season_standing(season_id, rank, id);
@@ -0,0 +1,14 @@
+-- Scoped API keys, stored as SHA-256 hashes
+CREATE TABLE api_key (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    prefix TEXT NOT NULL,
+    key_hash TEXT UNIQUE NOT NULL, -- looked up on every request
+    scope TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    revoked_at DATETIME,
+
+    -- Check constraints
+    CHECK (length(name) > 0 AND length(name) <= 255),
+    CHECK (scope IN ('read', 'submit', 'admin'))
+);
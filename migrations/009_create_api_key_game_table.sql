@@ -0,0 +1,11 @@
+-- Per-game allow-lists for API keys; keys without rows here may access every game
+CREATE TABLE api_key_game (
+    api_key_id INTEGER NOT NULL,
+    game_hex_id TEXT NOT NULL,
+
+    PRIMARY KEY (api_key_id, game_hex_id),
+
+    -- Foreign key constraints
+    FOREIGN KEY (api_key_id) REFERENCES api_key(id),
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id)
+);
@@ -0,0 +1,16 @@
+-- Optional per-game HMAC secret; when set, score submissions must be signed
+ALTER TABLE game ADD COLUMN signing_secret TEXT;
+
+-- Nonces of accepted signed submissions, kept only long enough to reject replays
+CREATE TABLE submission_nonce (
+    game_hex_id TEXT NOT NULL,
+    nonce TEXT NOT NULL,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    PRIMARY KEY (game_hex_id, nonce),
+
+    -- Foreign key constraints
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id)
+);
+
+CREATE INDEX idx_submission_nonce_created_at ON submission_nonce(created_at);
@@ -0,0 +1,20 @@
+-- Per-game server-side validation rules for submitted scores
+ALTER TABLE game ADD COLUMN min_score_val REAL;
+ALTER TABLE game ADD COLUMN max_score_val REAL;
+ALTER TABLE game ADD COLUMN integer_only BOOLEAN NOT NULL DEFAULT 0;
+ALTER TABLE game ADD COLUMN max_submissions_per_minute INTEGER;
+ALTER TABLE game ADD COLUMN extra_schema TEXT; -- JSON Schema for the score's extra field
+
+-- Recent submissions per player, kept only long enough to enforce per-minute rate limits
+CREATE TABLE submission_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_hex_id TEXT NOT NULL,
+    user_id TEXT NOT NULL,
+    submitted_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Foreign key constraints
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id)
+);
+
+CREATE INDEX idx_submission_log_game_user ON submission_log(game_hex_id, user_id, submitted_at);
+CREATE INDEX idx_submission_log_submitted_at ON submission_log(submitted_at);
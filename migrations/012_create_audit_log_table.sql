@@ -0,0 +1,19 @@
+-- Record of sensitive admin actions, such as player data exports and erasures
+CREATE TABLE audit_log (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    action TEXT NOT NULL,
+    target TEXT NOT NULL, -- what the action was applied to, e.g. a player's user_id
+    api_key_id INTEGER, -- NULL for the LEADR_API_KEY master key
+    details TEXT, -- JSON
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Foreign key constraint
+    FOREIGN KEY (api_key_id) REFERENCES api_key(id)
+);
+
+CREATE INDEX idx_audit_log_target ON audit_log(target, created_at);
+
+-- Player data requests look scores up by user_id, deleted or not
+DROP INDEX idx_score_user_id;
+CREATE INDEX idx_score_user_id ON score(user_id);
+CREATE INDEX idx_season_standing_user_id ON season_standing(user_id);
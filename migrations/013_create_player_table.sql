@@ -0,0 +1,86 @@
+-- Player profiles keyed by user_id; the display name lives here and scores pick it up
+-- through their user_id when they are read
+CREATE TABLE player (
+    user_id TEXT PRIMARY KEY NOT NULL,
+    user_name TEXT NOT NULL,
+    avatar_url TEXT,
+    metadata TEXT, -- JSON
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Check constraints
+    CHECK (length(user_id) > 0 AND length(user_id) <= 255),
+    CHECK (length(user_name) > 0 AND length(user_name) <= 100)
+);
+
+-- Backfill one player per user_id, named after their most recent submission
+INSERT INTO player (user_id, user_name, created_at, updated_at)
+SELECT user_id, user_name, first_submitted_at, last_submitted_at
+FROM (
+    SELECT user_id, user_name,
+        MIN(submitted_at) OVER (PARTITION BY user_id) AS first_submitted_at,
+        MAX(submitted_at) OVER (PARTITION BY user_id) AS last_submitted_at,
+        ROW_NUMBER() OVER (PARTITION BY user_id ORDER BY submitted_at DESC, id DESC) AS recency
+    FROM score
+)
+WHERE recency = 1;
+
+-- Recreate score so user_id references player; SQLite can't add a foreign key in place.
+-- The game triggers refer to score, so they are dropped while the table is swapped.
+DROP TRIGGER soft_delete_game_scores;
+DROP TRIGGER restore_game_scores;
+
+CREATE TABLE score_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_hex_id TEXT NOT NULL,
+    score TEXT NOT NULL,
+    score_val REAL NOT NULL,
+    user_id TEXT NOT NULL,
+    extra TEXT,
+    submitted_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted_at DATETIME,
+
+    -- Foreign key constraints
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id),
+    FOREIGN KEY (user_id) REFERENCES player(user_id),
+
+    -- Check constraints
+    CHECK (length(user_id) > 0 AND length(user_id) <= 255)
+);
+
+-- Copy scores; their names now come from the player table
+INSERT INTO score_new (id, game_hex_id, score, score_val, user_id, extra, submitted_at, deleted_at)
+SELECT id, game_hex_id, score, score_val, user_id, extra, submitted_at, deleted_at
+FROM score;
+
+DROP TABLE score;
+
+ALTER TABLE score_new RENAME TO score;
+
+-- Recreate indexes
+CREATE INDEX idx_score_game_score_desc ON score(game_hex_id, score_val DESC, id) WHERE deleted_at IS NULL;
+CREATE INDEX idx_score_game_score_asc ON score(game_hex_id, score_val ASC, id) WHERE deleted_at IS NULL;
+CREATE INDEX idx_score_game_date_desc ON score(game_hex_id, submitted_at DESC, id) WHERE deleted_at IS NULL;
+CREATE INDEX idx_score_game_user_id ON score(game_hex_id, user_id) WHERE deleted_at IS NULL;
+CREATE INDEX idx_score_game_submitted_score ON score(game_hex_id, submitted_at, score_val) WHERE deleted_at IS NULL;
+CREATE INDEX idx_score_user_id ON score(user_id);
+CREATE INDEX idx_score_deleted_at ON score(deleted_at) WHERE deleted_at IS NULL;
+
+-- Recreate triggers
+CREATE TRIGGER soft_delete_game_scores
+AFTER UPDATE OF deleted_at ON game
+WHEN NEW.deleted_at IS NOT NULL AND OLD.deleted_at IS NULL
+BEGIN
+    UPDATE score
+    SET deleted_at = NEW.deleted_at
+    WHERE game_hex_id = NEW.hex_id AND deleted_at IS NULL;
+END;
+
+CREATE TRIGGER restore_game_scores
+AFTER UPDATE OF deleted_at ON game
+WHEN NEW.deleted_at IS NULL AND OLD.deleted_at IS NOT NULL
+BEGIN
+    UPDATE score
+    SET deleted_at = NULL
+    WHERE game_hex_id = NEW.hex_id AND deleted_at = OLD.deleted_at;
+END;
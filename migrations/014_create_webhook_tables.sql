@@ -0,0 +1,45 @@
+-- Outbound webhooks, notified of the events they subscribe to
+CREATE TABLE webhook (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    url TEXT NOT NULL,
+    events TEXT NOT NULL, -- JSON array of event names
+    game_hex_id TEXT, -- only events of this game; NULL for every game
+    secret TEXT NOT NULL,
+    active BOOLEAN NOT NULL DEFAULT 1,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Foreign key constraints
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id),
+
+    -- Check constraints
+    CHECK (length(url) > 0 AND length(url) <= 2048)
+);
+
+CREATE INDEX idx_webhook_game ON webhook(game_hex_id);
+
+-- One row per event sent to a webhook; pending rows are retried until they succeed or
+-- run out of attempts
+CREATE TABLE webhook_delivery (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    webhook_id INTEGER NOT NULL,
+    event TEXT NOT NULL,
+    game_hex_id TEXT,
+    payload TEXT NOT NULL, -- JSON
+    status TEXT NOT NULL DEFAULT 'pending',
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at DATETIME,
+    last_attempt_at DATETIME,
+    response_status INTEGER,
+    last_error TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Foreign key constraints
+    FOREIGN KEY (webhook_id) REFERENCES webhook(id) ON DELETE CASCADE,
+
+    -- Check constraints
+    CHECK (status IN ('pending', 'succeeded', 'failed'))
+);
+
+CREATE INDEX idx_webhook_delivery_due ON webhook_delivery(next_attempt_at) WHERE status = 'pending';
+CREATE INDEX idx_webhook_delivery_webhook ON webhook_delivery(webhook_id, id DESC);
@@ -0,0 +1,20 @@
+-- Responses of score submissions made with an Idempotency-Key header, replayed when a
+-- client retries the same request under the same key
+CREATE TABLE idempotency_key (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    api_key_id INTEGER, -- NULL for the LEADR_API_KEY master key
+    endpoint TEXT NOT NULL,
+    idempotency_key TEXT NOT NULL,
+    request_hash TEXT NOT NULL,
+    response_status INTEGER, -- NULL while the first request is still being processed
+    response_body TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    expires_at DATETIME NOT NULL,
+
+    -- Check constraints
+    CHECK (length(idempotency_key) > 0 AND length(idempotency_key) <= 255)
+);
+
+-- Keys are scoped to the API key that used them
+CREATE UNIQUE INDEX idx_idempotency_key_scope ON idempotency_key(ifnull(api_key_id, 0), endpoint, idempotency_key);
+CREATE INDEX idx_idempotency_key_expires_at ON idempotency_key(expires_at);
@@ -0,0 +1,75 @@
+-- Relax the hex_id check to any length from 6 to 32 lowercase alphanumeric characters and add
+-- an optional human-readable slug. SQLite can't modify a CHECK constraint in place, so the
+-- table is recreated under its own name: other tables reference game, so their foreign keys
+-- are only checked at commit, once every game has been copied back.
+PRAGMA defer_foreign_keys = ON;
+
+DROP TRIGGER soft_delete_game_scores;
+DROP TRIGGER restore_game_scores;
+
+CREATE TABLE game_old AS SELECT * FROM game;
+
+DROP TABLE game;
+
+CREATE TABLE game (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    hex_id TEXT UNIQUE NOT NULL,
+    slug TEXT,
+    name TEXT NOT NULL,
+    description TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    deleted_at DATETIME,
+    score_order TEXT NOT NULL DEFAULT 'higher_is_better',
+    default_sort_by TEXT NOT NULL DEFAULT 'score',
+    submission_policy TEXT NOT NULL DEFAULT 'keep_all',
+    signing_secret TEXT,
+    min_score_val REAL,
+    max_score_val REAL,
+    integer_only BOOLEAN NOT NULL DEFAULT 0,
+    max_submissions_per_minute INTEGER,
+    extra_schema TEXT,
+
+    -- Check constraints
+    CHECK (length(hex_id) >= 6 AND length(hex_id) <= 32 AND hex_id NOT GLOB '*[^0-9a-z]*'),
+    CHECK (slug IS NULL OR (length(slug) >= 3 AND length(slug) <= 64 AND slug NOT GLOB '*[^0-9a-z-]*')),
+    CHECK (length(name) > 0 AND length(name) <= 255),
+    CHECK (score_order IN ('higher_is_better', 'lower_is_better')),
+    CHECK (default_sort_by IN ('score', 'date', 'user_name')),
+    CHECK (submission_policy IN ('keep_all', 'keep_best', 'keep_latest'))
+);
+
+INSERT INTO game (id, hex_id, name, description, created_at, updated_at, deleted_at, score_order,
+    default_sort_by, submission_policy, signing_secret, min_score_val, max_score_val, integer_only,
+    max_submissions_per_minute, extra_schema)
+SELECT id, hex_id, name, description, created_at, updated_at, deleted_at, score_order,
+    default_sort_by, submission_policy, signing_secret, min_score_val, max_score_val, integer_only,
+    max_submissions_per_minute, extra_schema
+FROM game_old;
+
+DROP TABLE game_old;
+
+-- Recreate indexes
+CREATE UNIQUE INDEX idx_game_hex_id ON game(hex_id);
+CREATE UNIQUE INDEX idx_game_slug ON game(slug);
+CREATE INDEX idx_game_created_at_desc ON game(created_at DESC);
+CREATE INDEX idx_game_deleted_at ON game(deleted_at) WHERE deleted_at IS NULL;
+
+-- Recreate triggers
+CREATE TRIGGER soft_delete_game_scores
+AFTER UPDATE OF deleted_at ON game
+WHEN NEW.deleted_at IS NOT NULL AND OLD.deleted_at IS NULL
+BEGIN
+    UPDATE score
+    SET deleted_at = NEW.deleted_at
+    WHERE game_hex_id = NEW.hex_id AND deleted_at IS NULL;
+END;
+
+CREATE TRIGGER restore_game_scores
+AFTER UPDATE OF deleted_at ON game
+WHEN NEW.deleted_at IS NULL AND OLD.deleted_at IS NOT NULL
+BEGIN
+    UPDATE score
+    SET deleted_at = NULL
+    WHERE game_hex_id = NEW.hex_id AND deleted_at = OLD.deleted_at;
+END;
@@ -0,0 +1,40 @@
+-- Separate boards within a game, e.g. one per level, mode or difficulty
+CREATE TABLE leaderboard (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_hex_id TEXT NOT NULL,
+    slug TEXT NOT NULL,
+    name TEXT NOT NULL,
+    description TEXT,
+    created_at DATETIME NOT NULL DEFAULT CURRENT_TIMESTAMP,
+
+    -- Foreign key constraint
+    FOREIGN KEY (game_hex_id) REFERENCES game(hex_id),
+
+    -- Check constraints
+    CHECK (length(slug) > 0 AND length(slug) <= 64 AND slug NOT GLOB '*[^0-9a-z-]*'),
+    CHECK (length(name) > 0 AND length(name) <= 255)
+);
+
+CREATE UNIQUE INDEX idx_leaderboard_game_slug ON leaderboard(game_hex_id, slug);
+
+-- Every game has a default board, which takes submissions that do not name one
+INSERT INTO leaderboard (game_hex_id, slug, name)
+SELECT hex_id, 'default', 'Default' FROM game;
+
+CREATE TRIGGER create_default_leaderboard
+AFTER INSERT ON game
+BEGIN
+    INSERT INTO leaderboard (game_hex_id, slug, name)
+    VALUES (NEW.hex_id, 'default', 'Default');
+END;
+
+-- Existing scores move to their game's default board
+ALTER TABLE score ADD COLUMN leaderboard_id INTEGER REFERENCES leaderboard(id);
+
+UPDATE score
+SET leaderboard_id = (
+    SELECT id FROM leaderboard
+    WHERE leaderboard.game_hex_id = score.game_hex_id AND leaderboard.slug = 'default'
+);
+
+CREATE INDEX idx_score_leaderboard ON score(leaderboard_id, user_id, deleted_at);
@@ -0,0 +1,12 @@
+-- Seasons rank each of a game's boards separately
+ALTER TABLE season_standing ADD COLUMN leaderboard_id INTEGER REFERENCES leaderboard(id);
+
+-- Standings archived so far ranked the whole game, which was then only its default board
+UPDATE season_standing
+SET leaderboard_id = (
+    SELECT leaderboard.id FROM leaderboard
+    JOIN season ON season.game_hex_id = leaderboard.game_hex_id
+    WHERE season.id = season_standing.season_id AND leaderboard.slug = 'default'
+);
+
+CREATE INDEX idx_season_standing_leaderboard_rank ON season_standing(season_id, leaderboard_id, rank, id);
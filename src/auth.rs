@@ -1,11 +1,29 @@
 use axum::{
-    extract::Request,
+    extract::{Request, State},
     http::{HeaderMap, StatusCode},
     middleware::Next,
     response::Response,
 };
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{
+    db::{repository::ApiKeyRepository, DbPool},
+    error::ApiError,
+    models::{ApiKey, ApiKeyScope},
+};
 
 pub const API_KEY_HEADER: &str = "leadr-api-key";
+pub const SIGNATURE_TIMESTAMP_HEADER: &str = "leadr-timestamp";
+pub const SIGNATURE_NONCE_HEADER: &str = "leadr-nonce";
+pub const SIGNATURE_HEADER: &str = "leadr-signature";
+
+/// How far a signed submission's timestamp may be from the server clock, in seconds.
+pub const SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+/// Maximum length of a submission nonce.
+pub const MAX_NONCE_LEN: usize = 128;
 
 #[derive(Debug, Clone)]
 pub struct ApiKeyAuth {
@@ -51,29 +69,237 @@ impl ApiKeyAuth {
     }
 }
 
+/// The key a request was authenticated with, available to handlers as a request extension.
+#[derive(Debug, Clone)]
+pub struct AuthenticatedKey {
+    /// Database id of the key; `None` for the `LEADR_API_KEY` master key.
+    pub id: Option<i64>,
+    pub scope: ApiKeyScope,
+    /// Games the key is restricted to; empty means every game.
+    pub game_hex_ids: Vec<String>,
+}
+
+impl AuthenticatedKey {
+    /// The unrestricted admin identity of the `LEADR_API_KEY` master key.
+    #[must_use]
+    pub fn master() -> Self {
+        Self {
+            id: None,
+            scope: ApiKeyScope::Admin,
+            game_hex_ids: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn is_restricted(&self) -> bool {
+        !self.game_hex_ids.is_empty()
+    }
+
+    /// Checks that the key's scope covers `required`.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` if the key's scope is narrower than `required`.
+    pub fn check_scope(&self, required: ApiKeyScope, action: &str) -> Result<(), ApiError> {
+        if !self.scope.allows(required) {
+            return Err(ApiError::Forbidden(format!(
+                "API key needs the {} scope to {action}",
+                required.as_str()
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the key is not restricted to specific games, for actions that reach every game.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+    pub fn check_unrestricted(&self, action: &str) -> Result<(), ApiError> {
+        if self.is_restricted() {
+            return Err(ApiError::Forbidden(format!(
+                "API key is restricted to specific games and cannot {action}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks that the key may access the given game.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Forbidden` if the key is restricted to other games.
+    pub fn check_game(&self, game_hex_id: &str) -> Result<(), ApiError> {
+        if self.is_restricted() && !self.game_hex_ids.iter().any(|allowed| allowed == game_hex_id) {
+            return Err(ApiError::Forbidden(format!(
+                "API key is not allowed to access game {game_hex_id}"
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// The signature headers of a signed score submission.
+#[derive(Debug, Clone)]
+pub struct SubmissionSignature {
+    /// Unix time, in seconds, at which the submission was signed.
+    pub timestamp: i64,
+    /// Single-use value; a nonce is only accepted once per game.
+    pub nonce: String,
+    /// Hex-encoded HMAC-SHA256 of the signed message.
+    pub signature: String,
+}
+
+impl SubmissionSignature {
+    /// Extracts the timestamp, nonce and signature headers.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Unauthorized` if a header is missing or malformed.
+    pub fn from_headers(headers: &HeaderMap) -> Result<Self, ApiError> {
+        let header = |name: &str| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+                .filter(|value| !value.is_empty())
+                .ok_or_else(|| ApiError::Unauthorized(format!("Missing {name} header")))
+        };
+
+        let timestamp = header(SIGNATURE_TIMESTAMP_HEADER)?
+            .parse::<i64>()
+            .map_err(|_| {
+                ApiError::Unauthorized(format!(
+                    "{SIGNATURE_TIMESTAMP_HEADER} must be a Unix timestamp in seconds"
+                ))
+            })?;
+        let nonce = header(SIGNATURE_NONCE_HEADER)?;
+        if nonce.len() > MAX_NONCE_LEN {
+            return Err(ApiError::Unauthorized(format!(
+                "{SIGNATURE_NONCE_HEADER} cannot exceed {MAX_NONCE_LEN} characters"
+            )));
+        }
+        let signature = header(SIGNATURE_HEADER)?;
+
+        Ok(Self {
+            timestamp,
+            nonce: nonce.to_string(),
+            signature: signature.to_ascii_lowercase(),
+        })
+    }
+
+    /// Builds a signature carried in a request body rather than headers, as in score batches.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Unauthorized` if the nonce is empty or too long.
+    pub fn from_parts(timestamp: i64, nonce: String, signature: &str) -> Result<Self, ApiError> {
+        if nonce.is_empty() || nonce.len() > MAX_NONCE_LEN {
+            return Err(ApiError::Unauthorized(format!(
+                "Signature nonce must be between 1 and {MAX_NONCE_LEN} characters"
+            )));
+        }
+        Ok(Self {
+            timestamp,
+            nonce,
+            signature: signature.trim().to_ascii_lowercase(),
+        })
+    }
+
+    /// Computes the hex-encoded signature of a payload.
+    ///
+    /// The signed message is `"{timestamp}\n{nonce}\n{payload}"`, keyed with the game's secret.
+    ///
+    /// # Panics
+    /// Does not panic: HMAC accepts keys of any length.
+    #[must_use]
+    pub fn sign(secret: &str, timestamp: i64, nonce: &str, payload: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        mac.update(format!("{timestamp}\n{nonce}\n{payload}").as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Checks that the timestamp is fresh and the signature matches `payload`.
+    ///
+    /// Nonce reuse is checked separately, against the nonces already recorded.
+    ///
+    /// # Errors
+    /// Returns `ApiError::Unauthorized` if the timestamp is more than
+    /// [`SIGNATURE_TOLERANCE_SECS`] away from `now` or the signature does not match.
+    pub fn verify(&self, secret: &str, payload: &str, now: DateTime<Utc>) -> Result<(), ApiError> {
+        if (now.timestamp() - self.timestamp).abs() > SIGNATURE_TOLERANCE_SECS {
+            return Err(ApiError::Unauthorized(
+                "Submission timestamp is outside the allowed window".to_string(),
+            ));
+        }
+
+        let expected = Self::sign(secret, self.timestamp, &self.nonce, payload);
+        if !ApiKeyAuth::new(expected).validate_key(&self.signature) {
+            return Err(ApiError::Unauthorized("Invalid submission signature".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// State for [`api_key_middleware`]: where keys are looked up and the scope a route requires.
+#[derive(Debug, Clone)]
+pub struct AuthState {
+    pub pool: DbPool,
+    pub required_scope: ApiKeyScope,
+}
+
+impl AuthState {
+    #[must_use]
+    pub fn new(pool: DbPool, required_scope: ApiKeyScope) -> Self {
+        Self {
+            pool,
+            required_scope,
+        }
+    }
+}
+
+/// Resolves the key a request presents.
+///
+/// The `LEADR_API_KEY` environment variable is an unrestricted admin key; any
+/// other key is looked up by hash among the active keys in the database.
+///
+/// # Errors
+/// Returns `ApiError::DatabaseError` if the key lookup fails.
+pub async fn resolve_key(pool: &DbPool, provided_key: &str) -> Result<Option<AuthenticatedKey>, ApiError> {
+    if let Ok(master_key) = std::env::var("LEADR_API_KEY") {
+        if ApiKeyAuth::new(master_key).validate_key(provided_key) {
+            return Ok(Some(AuthenticatedKey::master()));
+        }
+    }
+
+    ApiKeyRepository::find_active_by_hash(pool, &ApiKey::hash_key(provided_key)).await
+}
+
 /// Middleware for API key authentication.
 ///
+/// On success the [`AuthenticatedKey`] is added to the request extensions.
+///
 /// # Errors
-/// Returns `StatusCode::INTERNAL_SERVER_ERROR` if the `LEADR_API_KEY` environment variable is not set.
-/// Returns `StatusCode::UNAUTHORIZED` if no API key is provided or if the key is invalid.
+/// Returns `StatusCode::UNAUTHORIZED` if no API key is provided or if the key is invalid or revoked.
+/// Returns `StatusCode::FORBIDDEN` if the key's scope does not cover the route.
+/// Returns `StatusCode::INTERNAL_SERVER_ERROR` if the key lookup fails.
 ///
 /// # Panics
 /// Does not panic under normal operation.
 pub async fn api_key_middleware(
+    State(auth_state): State<AuthState>,
     headers: HeaderMap,
-    request: Request,
+    mut request: Request,
     next: Next,
 ) -> Result<Response, StatusCode> {
-    let api_key = std::env::var("LEADR_API_KEY").map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-
-    let auth = ApiKeyAuth::new(api_key);
-
     let provided_key =
         ApiKeyAuth::extract_api_key_from_headers(&headers).ok_or(StatusCode::UNAUTHORIZED)?;
 
-    if !auth.validate_key(&provided_key) {
-        return Err(StatusCode::UNAUTHORIZED);
+    let authenticated_key = resolve_key(&auth_state.pool, &provided_key)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    if !authenticated_key.scope.allows(auth_state.required_scope) {
+        return Err(StatusCode::FORBIDDEN);
     }
 
+    request.extensions_mut().insert(authenticated_key);
     Ok(next.run(request).await)
 }
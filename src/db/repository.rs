@@ -1,26 +1,227 @@
 use chrono::Utc;
 use sqlx::{Row, SqlitePool};
 
+use crate::auth::{AuthenticatedKey, SIGNATURE_TOLERANCE_SECS};
 use crate::error::{ApiError, Result};
+use crate::events::ScoreEvents;
 use crate::models::{
-    CreateGame, CreateScore, Game, GameRow, Score, ScoreRow, UpdateGame, UpdateScore,
+    ApiKey, ApiKeyRow, ApiKeyScope, AroundAnchor, AuditAction, ErasureMode, Player, PlayerData,
+    PlayerErasure, PlayerGameStats, PlayerRow, PlayerStats, PlayerStatsSummary, UpdatePlayer, ANONYMIZED_USER_NAME, CreateApiKey, CreateGame, CreateScore,
+    CreateSeason, CreatedApiKey, DailySubmissions, Game, GameRow, GameStats, ImportGame, ImportIssueKind, ImportLeaderboard, ImportLine,
+    ImportMode, ImportRecord, ImportReport, ImportScore, PurgeReport, LeaderboardWindow, PlayerRank, RankPolicy,
+    Score, ScoreEvent, ScoreOrder, ScoreRow, ScoreSubmission, ScoreValidation, Season, SeasonRow,
+    ScoreValStats, SeasonStanding, SeasonStandingRow, SigningSecret, SubmissionOutcome, SubmissionPolicy,
+    UpdateGame, UpdateScore, API_KEY_PREFIX_LEN, TOP_SCORES_LIMIT, bucket_width, build_histogram, percentile_offset,
+    CreateLeaderboard, Leaderboard, LeaderboardRow, DEFAULT_LEADERBOARD_SLUG,
+    CreateWebhook, CreatedWebhook, DeliveryAttempt, DeliveryQueryParams, DeliveryStatus, DueDelivery,
+    UpdateWebhook, Webhook, WebhookDelivery, WebhookDeliveryRow, WebhookEvent, WebhookRow, WebhookSecret,
+    WEBHOOK_MAX_ATTEMPTS, retry_delay, BatchItemResult, BatchMode, BatchSubmission, PreparedBatchScore,
+    IdempotencyClaim, StoredResponse, IDEMPOTENCY_LOCK_SECS, hex_id_len_from_env, DEFAULT_HEX_ID_LEN,
+    HEX_ID_ATTEMPTS, HEX_ID_LEN_VAR,
 };
 use crate::utils::pagination::{
     cursor::{
         decode_game_cursor, decode_score_cursor, encode_game_cursor, encode_score_cursor,
         GameCursor, ScoreCursor,
     },
-    PaginatedResponse, PaginationParams, ScoreSortParams, SortOrder,
+    DeletedFilter, PaginatedResponse, PaginationParams, ScoreFilterParams, ScoreSortField, ScoreSortParams,
+    SortOrder,
 };
 
 pub struct GameRepository;
 pub struct ScoreRepository;
+pub struct SeasonRepository;
+pub struct LeaderboardRepository;
+pub struct ApiKeyRepository;
+pub struct ImportRepository;
+pub struct PurgeRepository;
+pub struct PlayerRepository;
+pub struct AuditLogRepository;
+pub struct WebhookRepository;
+pub struct IdempotencyRepository;
+
+/// SQL conditions restricting `column` to the filter's submission window.
+///
+/// `from_param` and `to_param` are the bind positions of the window bounds.
+fn submitted_window_conditions(
+    filter_params: &ScoreFilterParams,
+    column: &str,
+    from_param: u8,
+    to_param: u8,
+) -> String {
+    let mut conditions = String::new();
+    if filter_params.submitted_from.is_some() {
+        conditions.push_str(&format!(" AND {column} >= ?{from_param}"));
+    }
+    if filter_params.submitted_to.is_some() {
+        conditions.push_str(&format!(" AND {column} < ?{to_param}"));
+    }
+    conditions
+}
+
+/// Rank window expression that ranks live scores among themselves.
+///
+/// When the listing includes soft-deleted scores they are partitioned off and get a NULL rank.
+fn live_rank_expr(
+    rank_policy: RankPolicy,
+    partition: Option<&str>,
+    rank_order: &str,
+    deleted: DeletedFilter,
+) -> String {
+    if !deleted.includes_deleted() {
+        return rank_policy.to_sql_window(partition, rank_order);
+    }
+    let partition = match partition {
+        Some(column) => format!("{column}, deleted_at IS NULL"),
+        None => "deleted_at IS NULL".to_string(),
+    };
+    let window = rank_policy.to_sql_window(Some(&partition), rank_order);
+    format!("CASE WHEN deleted_at IS NULL THEN {window} END")
+}
+
+/// Wraps a score source query so only each player's best entry per leaderboard remains.
+///
+/// `rank_order` is the best-first `ORDER BY` term used to pick that entry.
+fn best_entry_per_user(source: &str, rank_order: &str) -> String {
+    format!(
+        r"
+        SELECT * FROM (
+            SELECT *,
+                ROW_NUMBER() OVER (PARTITION BY game_hex_id, leaderboard_id, user_id ORDER BY {rank_order}, id) AS user_entry
+            FROM ({source})
+        )
+        WHERE user_entry = 1
+        "
+    )
+}
+
+/// Create a player's profile if it does not exist yet, returning their current display name.
+///
+/// `user_name` only names new players; existing profiles are renamed with `PlayerRepository::update`.
+async fn ensure_player(
+    conn: &mut sqlx::SqliteConnection,
+    user_id: &str,
+    user_name: &str,
+    now: chrono::NaiveDateTime,
+) -> Result<String> {
+    sqlx::query!(
+        r#"
+        INSERT INTO player (user_id, user_name, created_at, updated_at)
+        VALUES (?1, ?2, ?3, ?3)
+        ON CONFLICT (user_id) DO NOTHING
+        "#,
+        user_id,
+        user_name,
+        now
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let current_name = sqlx::query_scalar!("SELECT user_name FROM player WHERE user_id = ?1", user_id)
+        .fetch_one(&mut *conn)
+        .await?;
+    Ok(current_name)
+}
+
+/// Record a signed submission's nonce, pruning expired ones; `false` if it was already used.
+async fn record_nonce(conn: &mut sqlx::SqliteConnection, game_hex_id: &str, nonce: &str) -> Result<bool> {
+    let now = Utc::now();
+    let expired_before = (now - chrono::Duration::seconds(2 * SIGNATURE_TOLERANCE_SECS)).naive_utc();
+    sqlx::query!(
+        "DELETE FROM submission_nonce WHERE created_at < ?1",
+        expired_before
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    let now_naive = now.naive_utc();
+    let rows_affected = sqlx::query!(
+        r#"
+        INSERT INTO submission_nonce (game_hex_id, nonce, created_at)
+        VALUES (?1, ?2, ?3)
+        ON CONFLICT (game_hex_id, nonce) DO NOTHING
+        "#,
+        game_hex_id,
+        nonce,
+        now_naive
+    )
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+
+    Ok(rows_affected == 1)
+}
+
+/// The `score_val` at a zero-based `offset` among a game's live scores, lowest first.
+///
+/// With a `leaderboard_id`, only that board's scores count.
+async fn score_val_at(
+    conn: &mut sqlx::SqliteConnection,
+    game_hex_id: &str,
+    leaderboard_id: Option<i64>,
+    offset: i64,
+) -> Result<f64> {
+    let score_val = sqlx::query_scalar!(
+        r#"
+        SELECT score_val
+        FROM score
+        WHERE game_hex_id = ?1 AND (?2 IS NULL OR leaderboard_id = ?2) AND deleted_at IS NULL
+        ORDER BY score_val
+        LIMIT 1 OFFSET ?3
+        "#,
+        game_hex_id,
+        leaderboard_id,
+        offset
+    )
+    .fetch_one(conn)
+    .await?;
+    Ok(score_val)
+}
+
+/// Map a failed game insert or update, reporting a slug taken in the meantime as a conflict.
+fn game_write_error(e: sqlx::Error) -> ApiError {
+    match e {
+        sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() && db_err.message().contains("game.slug") => {
+            ApiError::Conflict("Slug is already used by another game".to_string())
+        }
+        e => ApiError::Database(e),
+    }
+}
+
+/// Queue `event` for the webhooks subscribed to it.
+///
+/// Runs after the change is committed, so failures are logged rather than returned.
+async fn notify_webhooks(pool: &SqlitePool, event: WebhookEvent, game_hex_id: &str, data: &serde_json::Value) {
+    if let Err(e) = WebhookRepository::enqueue(pool, event, game_hex_id, data).await {
+        tracing::warn!("Failed to queue {} webhooks for game {game_hex_id}: {e}", event.as_str());
+    }
+}
+
+/// How a single import record was applied, or why it was not.
+type ImportRowResult<T = Applied> = std::result::Result<T, (ImportIssueKind, String)>;
+
+enum Applied {
+    Created,
+    Updated,
+}
+
+/// Turns a constraint violation on one record into a failed row; other errors abort the import.
+fn import_row_failure<T>(err: sqlx::Error) -> Result<ImportRowResult<T>> {
+    match err {
+        sqlx::Error::Database(e) => Ok(Err((ImportIssueKind::Failed, e.message().to_string()))),
+        other => Err(other.into()),
+    }
+}
 
 impl GameRepository {
-    /// Create a new game
+    /// Create a new game under a freshly generated `hex_id`
+    ///
+    /// Ids are `LEADR_GAME_ID_LENGTH` characters long. An id already used as a game's id or
+    /// slug is never handed out; up to `HEX_ID_ATTEMPTS` ids are tried before giving up.
     ///
     /// # Errors
-    /// Returns `ApiError::ValidationError` if the game name is invalid.
+    /// Returns `ApiError::ValidationError` if the game name, slug or validation rules are invalid.
+    /// Returns `ApiError::Conflict` if the slug is taken or no free id was found.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
     /// # Panics
@@ -28,37 +229,95 @@ impl GameRepository {
     pub async fn create(pool: &SqlitePool, create_data: CreateGame) -> Result<Game> {
         // Validate inputs
         Game::validate_name(&create_data.name)?;
+        if let Some(ref slug) = create_data.slug {
+            Game::validate_slug(slug)?;
+            Self::check_slug_free(pool, slug, None).await?;
+        }
 
-        let hex_id = Game::generate_hex_id();
+        let hex_id_len = hex_id_len_from_env().unwrap_or(DEFAULT_HEX_ID_LEN);
+        let score_order = create_data.score_order.unwrap_or_default().as_str();
+        let default_sort_by = create_data.default_sort_by.unwrap_or_default().as_str();
+        let submission_policy = create_data.submission_policy.unwrap_or_default().as_str();
+        let validation = create_data.validation.unwrap_or_default();
+        validation.validate()?;
+        let extra_schema = validation.extra_schema.as_ref().map(serde_json::Value::to_string);
         let now = Utc::now();
         let now_naive = now.naive_utc();
 
-        let row = sqlx::query!(
-            r#"
-            INSERT INTO game (hex_id, name, description, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5)
-            RETURNING id, hex_id, name, description, created_at, updated_at, deleted_at
-            "#,
-            hex_id,
-            create_data.name,
-            create_data.description,
-            now_naive,
-            now_naive
-        )
-        .fetch_one(pool)
-        .await?;
+        let mut attempts = 0;
+        let row = loop {
+            attempts += 1;
+            let hex_id = Game::generate_hex_id_with_len(hex_id_len);
+            let inserted = if Self::is_identifier_taken(pool, &hex_id, None).await? {
+                None
+            } else {
+                let result = sqlx::query!(
+                    r#"
+                    INSERT INTO game (hex_id, slug, name, description, score_order, default_sort_by, submission_policy,
+                        min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+                    RETURNING id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
+                    "#,
+                    hex_id,
+                    create_data.slug,
+                    create_data.name,
+                    create_data.description,
+                    score_order,
+                    default_sort_by,
+                    submission_policy,
+                    validation.min_score_val,
+                    validation.max_score_val,
+                    validation.integer_only,
+                    validation.max_submissions_per_minute,
+                    extra_schema,
+                    now_naive,
+                    now_naive
+                )
+                .fetch_one(pool)
+                .await;
+                match result {
+                    Ok(row) => Some(row),
+                    // Another game took the id since it was checked
+                    Err(sqlx::Error::Database(e))
+                        if e.is_unique_violation() && e.message().contains("game.hex_id") =>
+                    {
+                        None
+                    }
+                    Err(e) => return Err(game_write_error(e)),
+                }
+            };
+            match inserted {
+                Some(row) => break row,
+                None if attempts < HEX_ID_ATTEMPTS => tracing::warn!("Generated game id {hex_id} is taken, retrying"),
+                None => {
+                    return Err(ApiError::Conflict(format!(
+                        "Could not find a free game id after {HEX_ID_ATTEMPTS} attempts; consider raising {HEX_ID_LEN_VAR}"
+                    )))
+                }
+            }
+        };
 
         let game_row = GameRow {
             id: row.id,
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order,
+            default_sort_by: row.default_sort_by,
+            submission_policy: row.submission_policy,
+            min_score_val: row.min_score_val,
+            max_score_val: row.max_score_val,
+            integer_only: row.integer_only,
+            max_submissions_per_minute: row.max_submissions_per_minute,
+            extra_schema: row.extra_schema,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
         };
 
         let game = Game::from(game_row);
+        notify_webhooks(pool, WebhookEvent::GameCreated, &game.hex_id, &serde_json::json!({ "game": game })).await;
         Ok(game)
     }
 
@@ -66,6 +325,7 @@ impl GameRepository {
     ///
     /// # Errors
     /// Returns `ApiError::ValidationError` if the game name or hex_id is invalid.
+    /// Returns `ApiError::Conflict` if another game uses the hex_id or slug as its `hex_id` or slug.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     pub async fn create_with_hex_id(
         pool: &SqlitePool, 
@@ -76,30 +336,65 @@ impl GameRepository {
         // Validate inputs and normalize hex_id
         Game::validate_name(&create_data.name)?;
         let normalized_hex_id = Game::normalize_and_validate_hex_id(&hex_id).map_err(ApiError::InvalidParameter)?;
+        if Self::is_identifier_taken(pool, &normalized_hex_id, None).await? {
+            return Err(ApiError::Conflict(format!(
+                "Game id '{normalized_hex_id}' is already used by another game"
+            )));
+        }
+        if let Some(ref slug) = create_data.slug {
+            Game::validate_slug(slug)?;
+            Self::check_slug_free(pool, slug, None).await?;
+        }
 
+        let score_order = create_data.score_order.unwrap_or_default().as_str();
+        let default_sort_by = create_data.default_sort_by.unwrap_or_default().as_str();
+        let submission_policy = create_data.submission_policy.unwrap_or_default().as_str();
+        let validation = create_data.validation.unwrap_or_default();
+        validation.validate()?;
+        let extra_schema = validation.extra_schema.as_ref().map(serde_json::Value::to_string);
         let created_at_naive = created_at.naive_utc();
         let updated_at_naive = created_at.naive_utc();
 
         let row = sqlx::query!(
             r#"
-            INSERT INTO game (hex_id, name, description, created_at, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5)
-            RETURNING id, hex_id, name, description, created_at, updated_at, deleted_at
+            INSERT INTO game (hex_id, slug, name, description, score_order, default_sort_by, submission_policy,
+                min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+            RETURNING id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
             "#,
             normalized_hex_id,
+            create_data.slug,
             create_data.name,
             create_data.description,
+            score_order,
+            default_sort_by,
+            submission_policy,
+            validation.min_score_val,
+            validation.max_score_val,
+            validation.integer_only,
+            validation.max_submissions_per_minute,
+            extra_schema,
             created_at_naive,
             updated_at_naive
         )
         .fetch_one(pool)
-        .await?;
+        .await
+        .map_err(game_write_error)?;
 
         let game_row = GameRow {
             id: row.id,
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order,
+            default_sort_by: row.default_sort_by,
+            submission_policy: row.submission_policy,
+            min_score_val: row.min_score_val,
+            max_score_val: row.max_score_val,
+            integer_only: row.integer_only,
+            max_submissions_per_minute: row.max_submissions_per_minute,
+            extra_schema: row.extra_schema,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -109,6 +404,54 @@ impl GameRepository {
         Ok(game)
     }
 
+    /// Resolve a `/games/{id}` path segment, which may be a game's `hex_id` or its slug, to the `hex_id`
+    ///
+    /// Soft-deleted games are resolved too. Anything that is not a known slug is returned
+    /// unchanged, to be validated as a `hex_id` by whatever uses it.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn resolve_id(pool: &SqlitePool, id: &str) -> Result<String> {
+        let hex_id = sqlx::query_scalar!("SELECT hex_id FROM game WHERE slug = ?1", id)
+            .fetch_optional(pool)
+            .await?;
+        Ok(hex_id.unwrap_or_else(|| id.to_string()))
+    }
+
+    /// Whether a game other than `except_hex_id` uses `identifier` as its `hex_id` or slug
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    async fn is_identifier_taken<'c, E>(executor: E, identifier: &str, except_hex_id: Option<&str>) -> Result<bool>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let taken = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM game WHERE (hex_id = ?1 OR slug = ?1) AND hex_id IS NOT ?2
+            ) AS "taken!: bool"
+            "#,
+            identifier,
+            except_hex_id
+        )
+        .fetch_one(executor)
+        .await?;
+        Ok(taken)
+    }
+
+    /// Check that a slug is free, so every `/games/{id}` path names one game
+    ///
+    /// # Errors
+    /// Returns `ApiError::Conflict` if another game uses the slug as its `hex_id` or slug.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    async fn check_slug_free(pool: &SqlitePool, slug: &str, except_hex_id: Option<&str>) -> Result<()> {
+        if Self::is_identifier_taken(pool, slug, except_hex_id).await? {
+            return Err(ApiError::Conflict(format!("Slug '{slug}' is already used by another game")));
+        }
+        Ok(())
+    }
+
     /// Get a game by `hex_id`
     ///
     /// # Errors
@@ -123,7 +466,7 @@ impl GameRepository {
 
         let row = sqlx::query!(
             r#"
-            SELECT id, hex_id, name, description, created_at, updated_at, deleted_at
+            SELECT id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
             FROM game 
             WHERE hex_id = ?1 AND deleted_at IS NULL
             "#,
@@ -136,8 +479,17 @@ impl GameRepository {
         let game_row = GameRow {
             id: row.id.unwrap(),
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order,
+            default_sort_by: row.default_sort_by,
+            submission_policy: row.submission_policy,
+            min_score_val: row.min_score_val,
+            max_score_val: row.max_score_val,
+            integer_only: row.integer_only,
+            max_submissions_per_minute: row.max_submissions_per_minute,
+            extra_schema: row.extra_schema,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -158,7 +510,7 @@ impl GameRepository {
     pub async fn get_by_id(pool: &SqlitePool, id: i64) -> Result<Game> {
         let row = sqlx::query!(
             r#"
-            SELECT id, hex_id, name, description, created_at, updated_at, deleted_at
+            SELECT id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
             FROM game 
             WHERE id = ?1 AND deleted_at IS NULL
             "#,
@@ -171,8 +523,17 @@ impl GameRepository {
         let game_row = GameRow {
             id: row.id,
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order,
+            default_sort_by: row.default_sort_by,
+            submission_policy: row.submission_policy,
+            min_score_val: row.min_score_val,
+            max_score_val: row.max_score_val,
+            integer_only: row.integer_only,
+            max_submissions_per_minute: row.max_submissions_per_minute,
+            extra_schema: row.extra_schema,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -182,7 +543,7 @@ impl GameRepository {
         Ok(game)
     }
 
-    /// List games with pagination
+    /// List games with pagination, optionally including or only soft-deleted ones
     ///
     /// # Errors
     /// Returns `ApiError::ValidationError` if the cursor is invalid.
@@ -193,8 +554,11 @@ impl GameRepository {
     pub async fn list(
         pool: &SqlitePool,
         pagination: PaginationParams,
+        deleted: DeletedFilter,
     ) -> Result<PaginatedResponse<Game>> {
         let limit = pagination.get_limit();
+        let include_live = deleted.includes_live();
+        let include_deleted = deleted.includes_deleted();
         let fetch_limit = i64::from(limit + 1); // Fetch one extra to check for more pages
 
         let games = if let Some(cursor_str) = &pagination.cursor {
@@ -207,16 +571,18 @@ impl GameRepository {
             let cursor_created_at = cursor_datetime.naive_utc();
             let game_rows = sqlx::query!(
                 r#"
-                SELECT id, hex_id, name, description, created_at, updated_at, deleted_at
+                SELECT id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
                 FROM game 
-                WHERE deleted_at IS NULL 
+                WHERE ((deleted_at IS NULL AND ?4) OR (deleted_at IS NOT NULL AND ?5))
                 AND (created_at, hex_id) < (?1, ?2)
                 ORDER BY created_at DESC, hex_id DESC
                 LIMIT ?3
                 "#,
                 cursor_created_at,
                 cursor.hex_id,
-                fetch_limit
+                fetch_limit,
+                include_live,
+                include_deleted
             )
             .fetch_all(pool)
             .await?;
@@ -227,8 +593,17 @@ impl GameRepository {
                     Game::from(GameRow {
                         id: row.id.unwrap(),
                         hex_id: row.hex_id,
+                        slug: row.slug,
                         name: row.name,
                         description: row.description,
+                        score_order: row.score_order,
+                        default_sort_by: row.default_sort_by,
+                        submission_policy: row.submission_policy,
+                        min_score_val: row.min_score_val,
+                        max_score_val: row.max_score_val,
+                        integer_only: row.integer_only,
+                        max_submissions_per_minute: row.max_submissions_per_minute,
+                        extra_schema: row.extra_schema,
                         created_at: row.created_at,
                         updated_at: row.updated_at,
                         deleted_at: row.deleted_at,
@@ -238,13 +613,15 @@ impl GameRepository {
         } else {
             let game_rows = sqlx::query!(
                 r#"
-                SELECT id, hex_id, name, description, created_at, updated_at, deleted_at
+                SELECT id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
                 FROM game 
-                WHERE deleted_at IS NULL
+                WHERE (deleted_at IS NULL AND ?2) OR (deleted_at IS NOT NULL AND ?3)
                 ORDER BY created_at DESC, hex_id DESC
                 LIMIT ?1
                 "#,
-                fetch_limit
+                fetch_limit,
+                include_live,
+                include_deleted
             )
             .fetch_all(pool)
             .await?;
@@ -255,8 +632,17 @@ impl GameRepository {
                     Game::from(GameRow {
                         id: row.id.unwrap(),
                         hex_id: row.hex_id,
+                        slug: row.slug,
                         name: row.name,
                         description: row.description,
+                        score_order: row.score_order,
+                        default_sort_by: row.default_sort_by,
+                        submission_policy: row.submission_policy,
+                        min_score_val: row.min_score_val,
+                        max_score_val: row.max_score_val,
+                        integer_only: row.integer_only,
+                        max_submissions_per_minute: row.max_submissions_per_minute,
+                        extra_schema: row.extra_schema,
                         created_at: row.created_at,
                         updated_at: row.updated_at,
                         deleted_at: row.deleted_at,
@@ -274,10 +660,39 @@ impl GameRepository {
         Ok(response)
     }
 
+    /// Fill in `score_count` and `player_count` on each game, counting live scores only
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn add_counts(pool: &SqlitePool, games: &mut [Game]) -> Result<()> {
+        let hex_ids = serde_json::json!(games.iter().map(|game| &game.hex_id).collect::<Vec<_>>()).to_string();
+        let rows = sqlx::query!(
+            r#"
+            SELECT game_hex_id AS "game_hex_id!", COUNT(*) AS "score_count!: i64",
+                COUNT(DISTINCT user_id) AS "player_count!: i64"
+            FROM score
+            WHERE deleted_at IS NULL AND game_hex_id IN (SELECT value FROM json_each(?1))
+            GROUP BY game_hex_id
+            "#,
+            hex_ids
+        )
+        .fetch_all(pool)
+        .await?;
+
+        for game in games.iter_mut() {
+            let counts = rows.iter().find(|row| row.game_hex_id == game.hex_id);
+            game.score_count = Some(counts.map_or(0, |row| row.score_count));
+            game.player_count = Some(counts.map_or(0, |row| row.player_count));
+        }
+        Ok(())
+    }
+
     /// Update a game
     ///
     /// # Errors
     /// Returns `ApiError::InvalidParameter` if the `hex_id` or name is invalid.
+    /// Returns `ApiError::ValidationError` if the slug is invalid.
+    /// Returns `ApiError::Conflict` if the slug is used by another game.
     /// Returns `ApiError::NotFound` if no game exists with the given `hex_id`.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
@@ -289,7 +704,28 @@ impl GameRepository {
         if let Some(ref name) = update_data.name {
             Game::validate_name(name)?;
         }
+        let replace_slug = update_data.slug.is_some();
+        let slug = update_data.slug.as_deref().filter(|slug| !slug.is_empty());
+        if let Some(slug) = slug {
+            Game::validate_slug(slug)?;
+            Self::check_slug_free(pool, slug, Some(hex_id)).await?;
+        }
 
+        let score_order = update_data.score_order.map(ScoreOrder::as_str);
+        let default_sort_by = update_data.default_sort_by.as_ref().map(ScoreSortField::as_str);
+        let submission_policy = update_data.submission_policy.map(SubmissionPolicy::as_str);
+        if let Some(ref validation) = update_data.validation {
+            validation.validate()?;
+        }
+        let validation = update_data.validation.as_ref();
+        let extra_schema = validation
+            .and_then(|v| v.extra_schema.as_ref())
+            .map(serde_json::Value::to_string);
+        let replace_validation = validation.is_some();
+        let min_score_val = validation.and_then(|v| v.min_score_val);
+        let max_score_val = validation.and_then(|v| v.max_score_val);
+        let integer_only = validation.is_some_and(|v| v.integer_only);
+        let max_submissions_per_minute = validation.and_then(|v| v.max_submissions_per_minute);
         let now = Utc::now();
         let now_naive = now.naive_utc();
 
@@ -298,30 +734,61 @@ impl GameRepository {
             UPDATE game 
             SET name = COALESCE(?1, name),
                 description = COALESCE(?2, description),
-                updated_at = ?3
-            WHERE hex_id = ?4 AND deleted_at IS NULL
-            RETURNING id, hex_id, name, description, created_at, updated_at, deleted_at
+                score_order = COALESCE(?3, score_order),
+                default_sort_by = COALESCE(?4, default_sort_by),
+                submission_policy = COALESCE(?5, submission_policy),
+                min_score_val = CASE WHEN ?6 THEN ?7 ELSE min_score_val END,
+                max_score_val = CASE WHEN ?6 THEN ?8 ELSE max_score_val END,
+                integer_only = CASE WHEN ?6 THEN ?9 ELSE integer_only END,
+                max_submissions_per_minute = CASE WHEN ?6 THEN ?10 ELSE max_submissions_per_minute END,
+                extra_schema = CASE WHEN ?6 THEN ?11 ELSE extra_schema END,
+                slug = CASE WHEN ?14 THEN ?15 ELSE slug END,
+                updated_at = ?12
+            WHERE hex_id = ?13 AND deleted_at IS NULL
+            RETURNING id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
             "#,
             update_data.name,
             update_data.description,
+            score_order,
+            default_sort_by,
+            submission_policy,
+            replace_validation,
+            min_score_val,
+            max_score_val,
+            integer_only,
+            max_submissions_per_minute,
+            extra_schema,
             now_naive,
-            hex_id
+            hex_id,
+            replace_slug,
+            slug
         )
         .fetch_optional(pool)
-        .await?
+        .await
+        .map_err(game_write_error)?
         .ok_or(ApiError::NotFound)?;
 
         let game_row = GameRow {
             id: row.id.unwrap(),
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order,
+            default_sort_by: row.default_sort_by,
+            submission_policy: row.submission_policy,
+            min_score_val: row.min_score_val,
+            max_score_val: row.max_score_val,
+            integer_only: row.integer_only,
+            max_submissions_per_minute: row.max_submissions_per_minute,
+            extra_schema: row.extra_schema,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
         };
 
         let game = Game::from(game_row);
+        notify_webhooks(pool, WebhookEvent::GameUpdated, &game.hex_id, &serde_json::json!({ "game": game })).await;
         Ok(game)
     }
 
@@ -352,6 +819,7 @@ impl GameRepository {
             return Err(ApiError::NotFound);
         }
 
+        notify_webhooks(pool, WebhookEvent::GameDeleted, hex_id, &serde_json::json!({ "game_hex_id": hex_id })).await;
         Ok(())
     }
 
@@ -374,7 +842,7 @@ impl GameRepository {
             UPDATE game 
             SET deleted_at = NULL, updated_at = ?1
             WHERE hex_id = ?2 AND deleted_at IS NOT NULL
-            RETURNING id, hex_id, name, description, created_at, updated_at, deleted_at
+            RETURNING id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy, min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema, created_at, updated_at, deleted_at
             "#,
             now_naive,
             hex_id
@@ -386,8 +854,17 @@ impl GameRepository {
         let game_row = GameRow {
             id: row.id.unwrap(),
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order,
+            default_sort_by: row.default_sort_by,
+            submission_policy: row.submission_policy,
+            min_score_val: row.min_score_val,
+            max_score_val: row.max_score_val,
+            integer_only: row.integer_only,
+            max_submissions_per_minute: row.max_submissions_per_minute,
+            extra_schema: row.extra_schema,
             created_at: row.created_at,
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
@@ -396,83 +873,268 @@ impl GameRepository {
         let game = Game::from(game_row);
         Ok(game)
     }
+
+    /// Get a game's score signing secret, if signing is enabled
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if no game exists with the given `hex_id`.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn get_signing_secret(pool: &SqlitePool, hex_id: &str) -> Result<Option<String>> {
+        Game::validate_hex_id(hex_id).map_err(ApiError::InvalidParameter)?;
+
+        let row = sqlx::query!(
+            "SELECT signing_secret FROM game WHERE hex_id = ?1 AND deleted_at IS NULL",
+            hex_id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+        Ok(row.signing_secret)
+    }
+
+    /// Generate a new score signing secret for a game, replacing any existing one
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if no game exists with the given `hex_id`.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn rotate_signing_secret(pool: &SqlitePool, hex_id: &str) -> Result<SigningSecret> {
+        Game::validate_hex_id(hex_id).map_err(ApiError::InvalidParameter)?;
+
+        let signing_secret = Game::generate_signing_secret();
+        let now_naive = Utc::now().naive_utc();
+        let rows_affected = sqlx::query!(
+            "UPDATE game SET signing_secret = ?1, updated_at = ?2 WHERE hex_id = ?3 AND deleted_at IS NULL",
+            signing_secret,
+            now_naive,
+            hex_id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        Ok(SigningSecret {
+            game_hex_id: hex_id.to_string(),
+            signing_secret,
+        })
+    }
+
+    /// Remove a game's score signing secret so submissions no longer need signing
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if no game exists with the given `hex_id`.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn clear_signing_secret(pool: &SqlitePool, hex_id: &str) -> Result<()> {
+        Game::validate_hex_id(hex_id).map_err(ApiError::InvalidParameter)?;
+
+        let now_naive = Utc::now().naive_utc();
+        let rows_affected = sqlx::query!(
+            "UPDATE game SET signing_secret = NULL, updated_at = ?1 WHERE hex_id = ?2 AND deleted_at IS NULL",
+            now_naive,
+            hex_id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        Ok(())
+    }
 }
 
 impl ScoreRepository {
-    /// Create a new score
+    /// Submit a score to a game, applying the game's submission policy
+    ///
+    /// The score goes on the leaderboard it names, or the game's default board. Under
+    /// `keep_all` every submission is appended. Under `keep_best` and `keep_latest` the
+    /// player's existing entry on that board is updated in place instead. A player's first submission
+    /// creates their profile; later ones are shown under the profile's name. New and
+    /// replaced entries, and changes to the top of the board, are published to `events`.
+    ///
+    /// A signed submission's `nonce` is recorded in the same transaction, so it is only
+    /// used up if the score is saved.
     ///
     /// # Errors
-    /// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid.
+    /// Returns `ApiError::Unauthorized` if the nonce has already been used.
+    /// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid,
+    /// the score breaks the game's validation rules or the player is over its rate limit.
+    /// Returns `ApiError::NotFound` if the game has no leaderboard with the given slug.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
     /// # Panics
     /// Panics if `serde_json::to_string` fails on valid JSON data, which should never happen.
-    pub async fn create(pool: &SqlitePool, create_data: CreateScore) -> Result<Score> {
-        // Validate inputs
-        Score::validate_user_name(&create_data.user_name)?;
-        Score::validate_user_id(&create_data.user_id)?;
-
-        // Validate JSON if provided
-        if let Some(ref extra) = create_data.extra {
-            serde_json::to_string(extra).map_err(|e| {
-                ApiError::ValidationError(format!("Invalid JSON in extra field: {e}"))
-            })?;
+    pub async fn create(
+        pool: &SqlitePool,
+        events: &ScoreEvents,
+        game: &Game,
+        create_data: CreateScore,
+        nonce: Option<&str>,
+    ) -> Result<ScoreSubmission> {
+        let now = Utc::now();
+        let mut tx = pool.begin().await?;
+        if let Some(nonce) = nonce {
+            if !record_nonce(&mut tx, &game.hex_id, nonce).await? {
+                return Err(ApiError::Unauthorized(
+                    "Submission nonce has already been used".to_string(),
+                ));
+            }
         }
+        let leaderboard_id = Self::submission_leaderboard_id(&mut tx, &game.hex_id, &create_data).await?;
+        let top_before = match leaderboard_id {
+            Some(leaderboard_id) => Self::watch_top(&mut tx, events, &game.hex_id, leaderboard_id).await?,
+            None => None,
+        };
+        let submission = Self::submit(&mut tx, game, create_data, now, now).await?;
+        tx.commit().await?;
 
-        // Parse score_val from score if not provided
-        let score_val = create_data
-            .score_val
-            .unwrap_or_else(|| create_data.score.parse::<f64>().unwrap_or(0.0));
+        Self::publish_submission(pool, events, &submission).await;
+        Self::publish_top_change(pool, events, &game.hex_id, submission.score.leaderboard_id, top_before).await;
+        Ok(submission)
+    }
 
+    /// Submit a batch of scores in one transaction, each under its game's submission policy
+    ///
+    /// `items` hold either a score that passed the request-level checks or the error that
+    /// failed it. Every score is applied in order within its own savepoint, so a failing item
+    /// leaves the others intact. Under `all_or_nothing`, any failure rolls the whole batch
+    /// back; under `partial`, the items that succeeded are committed. Committed entries, and
+    /// changes to the top of each game's board, are published to `events`.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails for a reason other
+    /// than a constraint on one item.
+    pub async fn create_batch(
+        pool: &SqlitePool,
+        events: &ScoreEvents,
+        mode: BatchMode,
+        items: Vec<Result<PreparedBatchScore>>,
+    ) -> Result<BatchSubmission> {
         let now = Utc::now();
-        let now_naive = now.naive_utc();
-        let extra_json = create_data
-            .extra
-            .map(|v| serde_json::to_string(&v).unwrap());
+        let mut tx = pool.begin().await?;
 
-        let row = sqlx::query!(
-            r#"
-            INSERT INTO score (game_hex_id, score, score_val, user_name, user_id, extra, submitted_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-            RETURNING id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
-            "#,
-            create_data.game_hex_id,
-            create_data.score,
-            score_val,
-            create_data.user_name,
-            create_data.user_id,
-            extra_json,
-            now_naive
-        )
-        .fetch_one(pool)
-        .await?;
+        let mut boards = Vec::new();
+        for prepared in items.iter().filter_map(|item| item.as_ref().ok()) {
+            let game_hex_id = &prepared.game.hex_id;
+            if let Some(leaderboard_id) =
+                Self::submission_leaderboard_id(&mut tx, game_hex_id, &prepared.score).await?
+            {
+                boards.push((game_hex_id.clone(), leaderboard_id));
+            }
+        }
+        boards.sort();
+        boards.dedup();
+        let mut tops_before = Vec::with_capacity(boards.len());
+        for (game_hex_id, leaderboard_id) in boards {
+            let top_before = Self::watch_top(&mut tx, events, &game_hex_id, leaderboard_id).await?;
+            tops_before.push((game_hex_id, leaderboard_id, top_before));
+        }
 
-        let score_row = ScoreRow {
-            id: row.id,
-            game_hex_id: row.game_hex_id,
-            score: row.score,
-            score_val: row.score_val,
-            user_name: row.user_name,
-            user_id: row.user_id,
-            extra: row.extra,
-            submitted_at: row.submitted_at,
-            deleted_at: row.deleted_at,
-        };
+        let mut results = Vec::with_capacity(items.len());
+        for (index, item) in items.into_iter().enumerate() {
+            let submitted = match item {
+                Ok(prepared) => {
+                    let mut savepoint = sqlx::Connection::begin(&mut *tx).await?;
+                    let submitted = Self::submit_prepared(&mut savepoint, prepared, now).await;
+                    if submitted.is_ok() {
+                        savepoint.commit().await?;
+                    } else {
+                        savepoint.rollback().await?;
+                    }
+                    submitted
+                }
+                Err(e) => Err(e),
+            };
+            let submitted = match submitted {
+                // A constraint violation only fails its item, as in imports
+                Err(ApiError::Database(sqlx::Error::Database(e))) => {
+                    Err(ApiError::ValidationError(e.message().to_string()))
+                }
+                Err(e @ ApiError::Database(_)) => return Err(e),
+                other => other,
+            };
+            results.push(match submitted {
+                Ok(submission) => BatchItemResult::succeeded(index, submission),
+                Err(e) => {
+                    let (status, message) = e.status_and_message();
+                    BatchItemResult::failed(index, status.as_u16(), message.to_string())
+                }
+            });
+        }
 
-        let score = Score::from(score_row);
-        Ok(score)
+        let committed = mode == BatchMode::Partial || results.iter().all(|result| result.error.is_none());
+        if !committed {
+            tx.rollback().await?;
+            for result in &mut results {
+                result.score = None;
+            }
+            return Ok(BatchSubmission::new(mode, committed, results));
+        }
+        tx.commit().await?;
+
+        for result in &results {
+            let (Some(outcome), Some(score)) = (result.outcome, result.score.clone()) else {
+                continue;
+            };
+            match outcome {
+                SubmissionOutcome::Created => Self::publish(pool, events, ScoreEvent::ScoreCreated { score }).await,
+                SubmissionOutcome::Replaced => Self::publish(pool, events, ScoreEvent::ScoreUpdated { score }).await,
+                SubmissionOutcome::Unchanged => {}
+            }
+        }
+        for (game_hex_id, leaderboard_id, top_before) in tops_before {
+            Self::publish_top_change(pool, events, &game_hex_id, leaderboard_id, top_before).await;
+        }
+
+        Ok(BatchSubmission::new(mode, committed, results))
     }
 
-    /// Create a new score with a specific timestamp (for seeding)
+    /// Record a batch item's signature nonce, if any, and submit its score
+    async fn submit_prepared(
+        conn: &mut sqlx::SqliteConnection,
+        prepared: PreparedBatchScore,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<ScoreSubmission> {
+        if let Some(ref nonce) = prepared.nonce {
+            if !record_nonce(&mut *conn, &prepared.game.hex_id, nonce).await? {
+                return Err(ApiError::Unauthorized(
+                    "Submission nonce has already been used".to_string(),
+                ));
+            }
+        }
+        Self::submit(conn, &prepared.game, prepared.score, prepared.submitted_at, now).await
+    }
+
+    /// Publish a committed submission's new or replaced entry
+    async fn publish_submission(pool: &SqlitePool, events: &ScoreEvents, submission: &ScoreSubmission) {
+        let score = submission.score.clone();
+        match submission.outcome {
+            SubmissionOutcome::Created => Self::publish(pool, events, ScoreEvent::ScoreCreated { score }).await,
+            SubmissionOutcome::Replaced => Self::publish(pool, events, ScoreEvent::ScoreUpdated { score }).await,
+            SubmissionOutcome::Unchanged => {}
+        }
+    }
+
+    /// Apply one submission within `conn`, without committing or publishing it
     ///
-    /// # Errors
-    /// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid.
-    /// Returns `ApiError::DatabaseError` if the database operation fails.
-    pub async fn create_with_timestamp(
-        pool: &SqlitePool, 
+    /// The entry is dated `submitted_at`, while rate limits count submissions at `now`.
+    /// Under `keep_latest`, an existing entry dated after `submitted_at` is kept.
+    async fn submit(
+        conn: &mut sqlx::SqliteConnection,
+        game: &Game,
         create_data: CreateScore,
-        submitted_at: chrono::DateTime<Utc>
-    ) -> Result<Score> {
+        submitted_at: chrono::DateTime<Utc>,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<ScoreSubmission> {
         // Validate inputs
         Score::validate_user_name(&create_data.user_name)?;
         Score::validate_user_id(&create_data.user_id)?;
@@ -485,38 +1147,413 @@ impl ScoreRepository {
         }
 
         // Parse score_val from score if not provided
-        let score_val = create_data
-            .score_val
-            .unwrap_or_else(|| create_data.score.parse::<f64>().unwrap_or(0.0));
+        let score_val = Score::parse_score_val(&create_data.score, create_data.score_val)?;
+        game.validation.check_score(score_val, create_data.extra.as_ref())?;
+        let leaderboard_slug = create_data
+            .leaderboard
+            .as_deref()
+            .unwrap_or(DEFAULT_LEADERBOARD_SLUG);
+        let leaderboard_id =
+            LeaderboardRepository::id_by_slug(&mut *conn, &game.hex_id, leaderboard_slug).await?;
 
+        let now_naive = now.naive_utc();
         let submitted_at_naive = submitted_at.naive_utc();
         let extra_json = create_data
             .extra
             .map(|v| serde_json::to_string(&v).unwrap());
 
-        let row = sqlx::query!(
+        // Entries carry the player's profile name, not necessarily the submitted one
+        let user_name =
+            ensure_player(&mut *conn, &create_data.user_id, &create_data.user_name, now_naive).await?;
+
+        if let Some(limit) = game.validation.max_submissions_per_minute {
+            let window_start = (now - chrono::Duration::minutes(1)).naive_utc();
+            sqlx::query!(
+                "DELETE FROM submission_log WHERE submitted_at < ?1",
+                window_start
+            )
+            .execute(&mut *conn)
+            .await?;
+
+            let recent = sqlx::query_scalar!(
+                r#"
+                SELECT COUNT(*) AS "count!: i64" FROM submission_log
+                WHERE game_hex_id = ?1 AND user_id = ?2 AND submitted_at >= ?3
+                "#,
+                game.hex_id,
+                create_data.user_id,
+                window_start
+            )
+            .fetch_one(&mut *conn)
+            .await?;
+
+            if recent >= limit {
+                return Err(ApiError::ValidationError(format!(
+                    "Too many submissions: at most {limit} per minute are allowed for this game"
+                )));
+            }
+
+            sqlx::query!(
+                "INSERT INTO submission_log (game_hex_id, user_id, submitted_at) VALUES (?1, ?2, ?3)",
+                game.hex_id,
+                create_data.user_id,
+                now_naive
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        let bests = sqlx::query!(
             r#"
-            INSERT INTO score (game_hex_id, score, score_val, user_name, user_id, extra, submitted_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
-            RETURNING id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
+            SELECT MAX(score_val) AS "best_max: f64", MIN(score_val) AS "best_min: f64"
+            FROM score
+            WHERE leaderboard_id = ?1 AND user_id = ?2 AND deleted_at IS NULL
             "#,
-            create_data.game_hex_id,
-            create_data.score,
-            score_val,
-            create_data.user_name,
-            create_data.user_id,
-            extra_json,
-            submitted_at_naive
+            leaderboard_id,
+            create_data.user_id
         )
-        .fetch_one(pool)
+        .fetch_one(&mut *conn)
         .await?;
 
-        let score_row = ScoreRow {
-            id: row.id,
-            game_hex_id: row.game_hex_id,
-            score: row.score,
+        let previous_best = match game.score_order {
+            ScoreOrder::HigherIsBetter => bests.best_max,
+            ScoreOrder::LowerIsBetter => bests.best_min,
+        };
+        let is_personal_best =
+            previous_best.is_none_or(|best| game.score_order.is_better(score_val, best));
+
+        // The entry to overwrite if the policy keeps one entry per player, and whether
+        // the policy keeps that entry as it is instead
+        let existing = match game.submission_policy {
+            SubmissionPolicy::KeepAll => None,
+            SubmissionPolicy::KeepBest => match previous_best {
+                Some(best) => sqlx::query_scalar!(
+                    r#"
+                    SELECT id AS "id!" FROM score
+                    WHERE leaderboard_id = ?1 AND user_id = ?2 AND deleted_at IS NULL AND score_val = ?3
+                    ORDER BY id
+                    LIMIT 1
+                    "#,
+                    leaderboard_id,
+                    create_data.user_id,
+                    best
+                )
+                .fetch_optional(&mut *conn)
+                .await?
+                .map(|id| (id, !is_personal_best)),
+                None => None,
+            },
+            SubmissionPolicy::KeepLatest => sqlx::query!(
+                r#"
+                SELECT id AS "id!", submitted_at FROM score
+                WHERE leaderboard_id = ?1 AND user_id = ?2 AND deleted_at IS NULL
+                ORDER BY submitted_at DESC, id DESC
+                LIMIT 1
+                "#,
+                leaderboard_id,
+                create_data.user_id
+            )
+            .fetch_optional(&mut *conn)
+            .await?
+            .map(|row| (row.id, row.submitted_at > submitted_at_naive)),
+        };
+
+        let (score_row, outcome) = match existing {
+            Some((id, true)) => {
+                let row = sqlx::query!(
+                    r#"
+                    SELECT score.id, score.game_hex_id, score.leaderboard_id AS "leaderboard_id!", score.score, score.score_val,
+                        player.user_name, score.user_id, score.extra, score.submitted_at, score.deleted_at
+                    FROM score
+                    JOIN player ON player.user_id = score.user_id
+                    WHERE score.id = ?1
+                    "#,
+                    id
+                )
+                .fetch_one(&mut *conn)
+                .await?;
+
+                let score_row = ScoreRow {
+                    id: row.id,
+                    game_hex_id: row.game_hex_id,
+                    leaderboard_id: row.leaderboard_id,
+                    score: row.score,
+                    score_val: row.score_val,
+                    user_name: row.user_name,
+                    user_id: row.user_id,
+                    extra: row.extra,
+                    submitted_at: row.submitted_at,
+                    deleted_at: row.deleted_at,
+                };
+                (score_row, SubmissionOutcome::Unchanged)
+            }
+            Some((id, false)) => {
+                let row = sqlx::query!(
+                    r#"
+                    UPDATE score
+                    SET score = ?1, score_val = ?2, extra = ?3, submitted_at = ?4
+                    WHERE id = ?5
+                    RETURNING id AS "id!", game_hex_id, leaderboard_id AS "leaderboard_id!", score, score_val, user_id, extra, submitted_at,
+                        deleted_at AS "deleted_at?"
+                    "#,
+                    create_data.score,
+                    score_val,
+                    extra_json,
+                    submitted_at_naive,
+                    id
+                )
+                .fetch_one(&mut *conn)
+                .await?;
+
+                let score_row = ScoreRow {
+                    id: row.id,
+                    game_hex_id: row.game_hex_id,
+                    leaderboard_id: row.leaderboard_id,
+                    score: row.score,
+                    score_val: row.score_val,
+                    user_name,
+                    user_id: row.user_id,
+                    extra: row.extra,
+                    submitted_at: row.submitted_at,
+                    deleted_at: row.deleted_at,
+                };
+                (score_row, SubmissionOutcome::Replaced)
+            }
+            None => {
+                let row = sqlx::query!(
+                    r#"
+                    INSERT INTO score (game_hex_id, leaderboard_id, score, score_val, user_id, extra, submitted_at)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                    RETURNING id, game_hex_id, leaderboard_id AS "leaderboard_id!", score, score_val, user_id, extra, submitted_at, deleted_at
+                    "#,
+                    game.hex_id,
+                    leaderboard_id,
+                    create_data.score,
+                    score_val,
+                    create_data.user_id,
+                    extra_json,
+                    submitted_at_naive
+                )
+                .fetch_one(&mut *conn)
+                .await?;
+
+                let score_row = ScoreRow {
+                    id: row.id,
+                    game_hex_id: row.game_hex_id,
+                    leaderboard_id: row.leaderboard_id,
+                    score: row.score,
+                    score_val: row.score_val,
+                    user_name,
+                    user_id: row.user_id,
+                    extra: row.extra,
+                    submitted_at: row.submitted_at,
+                    deleted_at: row.deleted_at,
+                };
+                (score_row, SubmissionOutcome::Created)
+            }
+        };
+
+        Ok(ScoreSubmission {
+            score: Score::from(score_row),
+            outcome,
+            is_personal_best,
+        })
+    }
+
+    /// The top entries of one of a game's boards, best first, with competition ranks
+    async fn top_scores<'c, E>(executor: E, leaderboard_id: i64) -> Result<Vec<Score>>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let rank_order =
+            "CASE game_score_order WHEN 'lower_is_better' THEN -score_val ELSE score_val END DESC";
+        let rank_expr = RankPolicy::Competition.to_sql_window(None, rank_order);
+        let query = format!(
+            r"
+            SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                {rank_expr} AS rank
+            FROM (
+                SELECT score.id, score.game_hex_id, score.leaderboard_id, score.score, score.score_val, player.user_name,
+                    score.user_id, score.extra, score.submitted_at, score.deleted_at,
+                    game.score_order AS game_score_order
+                FROM score
+                JOIN game ON game.hex_id = score.game_hex_id
+                JOIN player ON player.user_id = score.user_id
+                WHERE score.leaderboard_id = ?1 AND score.deleted_at IS NULL
+            )
+            ORDER BY rank, id
+            LIMIT ?2
+            "
+        );
+
+        let rows = sqlx::query(&query)
+            .bind(leaderboard_id)
+            .bind(TOP_SCORES_LIMIT)
+            .fetch_all(executor)
+            .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                Score::from(ScoreRow {
+                    id: row.get("id"),
+                    game_hex_id: row.get("game_hex_id"),
+                    leaderboard_id: row.get("leaderboard_id"),
+                    score: row.get("score"),
+                    score_val: row.get("score_val"),
+                    user_name: row.get("user_name"),
+                    user_id: row.get("user_id"),
+                    extra: row.get("extra"),
+                    submitted_at: row.get("submitted_at"),
+                    deleted_at: row.get("deleted_at"),
+                })
+                .with_rank(row.get("rank"))
+            })
+            .collect())
+    }
+
+    /// The top of a game's board before a change, if a stream or webhook is watching it
+    async fn watch_top(
+        conn: &mut sqlx::SqliteConnection,
+        events: &ScoreEvents,
+        game_hex_id: &str,
+        leaderboard_id: i64,
+    ) -> Result<Option<Vec<Score>>> {
+        if !events.has_subscribers()
+            && !WebhookRepository::is_watched(&mut *conn, game_hex_id, WebhookEvent::ScoreTopN).await?
+        {
+            return Ok(None);
+        }
+        Ok(Some(Self::top_scores(conn, leaderboard_id).await?))
+    }
+
+    /// The id of the board a submission names, or `None` if the game has no such board
+    ///
+    /// Submitting to an unknown board fails on its own, so there is no top to watch.
+    async fn submission_leaderboard_id(
+        conn: &mut sqlx::SqliteConnection,
+        game_hex_id: &str,
+        create_data: &CreateScore,
+    ) -> Result<Option<i64>> {
+        let leaderboard_slug = create_data
+            .leaderboard
+            .as_deref()
+            .unwrap_or(DEFAULT_LEADERBOARD_SLUG);
+        match LeaderboardRepository::id_by_slug(conn, game_hex_id, leaderboard_slug).await {
+            Ok(leaderboard_id) => Ok(Some(leaderboard_id)),
+            Err(ApiError::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Send an event to stream subscribers and queue it for subscribed webhooks
+    async fn publish(pool: &SqlitePool, events: &ScoreEvents, event: ScoreEvent) {
+        let data = serde_json::to_value(&event).unwrap_or_default();
+        notify_webhooks(pool, WebhookEvent::from(&event), event.game_hex_id(), &data).await;
+        events.publish(event);
+    }
+
+    /// Publish `top_changed` if a board's top entries differ from `before`
+    ///
+    /// Runs after the change is committed, so failures are logged rather than returned.
+    async fn publish_top_change(
+        pool: &SqlitePool,
+        events: &ScoreEvents,
+        game_hex_id: &str,
+        leaderboard_id: i64,
+        before: Option<Vec<Score>>,
+    ) {
+        let Some(before) = before else {
+            return;
+        };
+        match Self::top_scores(pool, leaderboard_id).await {
+            Ok(scores) if scores != before => {
+                let event = ScoreEvent::TopChanged {
+                    game_hex_id: game_hex_id.to_string(),
+                    leaderboard_id,
+                    scores,
+                };
+                Self::publish(pool, events, event).await;
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(
+                "Failed to check the top scores of leaderboard {leaderboard_id} of game {game_hex_id}: {e}"
+            ),
+        }
+    }
+
+    /// Create a new score with a specific timestamp (for seeding)
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid,
+    /// or `score` is not a number and no `score_val` is given.
+    /// Returns `ApiError::NotFound` if the game has no leaderboard with the given slug.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn create_with_timestamp(
+        pool: &SqlitePool, 
+        create_data: CreateScore,
+        submitted_at: chrono::DateTime<Utc>
+    ) -> Result<Score> {
+        // Validate inputs
+        Score::validate_user_name(&create_data.user_name)?;
+        Score::validate_user_id(&create_data.user_id)?;
+
+        // Validate JSON if provided
+        if let Some(ref extra) = create_data.extra {
+            serde_json::to_string(extra).map_err(|e| {
+                ApiError::ValidationError(format!("Invalid JSON in extra field: {e}"))
+            })?;
+        }
+
+        // Parse score_val from score if not provided
+        let score_val = Score::parse_score_val(&create_data.score, create_data.score_val)?;
+
+        let submitted_at_naive = submitted_at.naive_utc();
+        let extra_json = create_data
+            .extra
+            .map(|v| serde_json::to_string(&v).unwrap());
+
+        let mut tx = pool.begin().await?;
+        let leaderboard_slug = create_data
+            .leaderboard
+            .as_deref()
+            .unwrap_or(DEFAULT_LEADERBOARD_SLUG);
+        let leaderboard_id =
+            LeaderboardRepository::id_by_slug(&mut *tx, &create_data.game_hex_id, leaderboard_slug)
+                .await?;
+        let user_name = ensure_player(
+            &mut tx,
+            &create_data.user_id,
+            &create_data.user_name,
+            Utc::now().naive_utc(),
+        )
+        .await?;
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO score (game_hex_id, leaderboard_id, score, score_val, user_id, extra, submitted_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+            RETURNING id, game_hex_id, leaderboard_id AS "leaderboard_id!", score, score_val, user_id, extra, submitted_at, deleted_at
+            "#,
+            create_data.game_hex_id,
+            leaderboard_id,
+            create_data.score,
+            score_val,
+            create_data.user_id,
+            extra_json,
+            submitted_at_naive
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        let score_row = ScoreRow {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            leaderboard_id: row.leaderboard_id,
+            score: row.score,
             score_val: row.score_val,
-            user_name: row.user_name,
+            user_name,
             user_id: row.user_id,
             extra: row.extra,
             submitted_at: row.submitted_at,
@@ -538,9 +1575,11 @@ impl ScoreRepository {
     pub async fn get_by_id(pool: &SqlitePool, id: i64) -> Result<Score> {
         let row = sqlx::query!(
             r#"
-            SELECT id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
-            FROM score 
-            WHERE id = ?1 AND deleted_at IS NULL
+            SELECT score.id, score.game_hex_id, score.leaderboard_id AS "leaderboard_id!", score.score, score.score_val,
+                player.user_name, score.user_id, score.extra, score.submitted_at, score.deleted_at
+            FROM score
+            JOIN player ON player.user_id = score.user_id
+            WHERE score.id = ?1 AND score.deleted_at IS NULL
             "#,
             id
         )
@@ -551,6 +1590,7 @@ impl ScoreRepository {
         let score_row = ScoreRow {
             id: row.id,
             game_hex_id: row.game_hex_id,
+            leaderboard_id: row.leaderboard_id,
             score: row.score,
             score_val: row.score_val,
             user_name: row.user_name,
@@ -564,10 +1604,32 @@ impl ScoreRepository {
         Ok(score)
     }
 
-    /// List scores for a game with pagination and sorting
+    /// Get the hex ID of the game a score belongs to, including soft-deleted scores
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if no score exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn game_hex_id(pool: &SqlitePool, id: i64) -> Result<String> {
+        let game_hex_id = sqlx::query_scalar!("SELECT game_hex_id FROM score WHERE id = ?1", id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or(ApiError::NotFound)?;
+        Ok(game_hex_id)
+    }
+
+    /// List scores for a game with pagination and sorting.
+    ///
+    /// Each score carries its rank on the game's board under `rank_policy`,
+    /// regardless of the requested sort order. Sort settings missing from
+    /// `sort_params` fall back to the game's configuration. With `distinct_users`,
+    /// only each player's best entry is listed and ranked. A submission window in
+    /// `filter_params` restricts both the listing and the ranks to that period, and a
+    /// `leaderboard_id` to that board; without one, all of the game's boards rank together.
+    /// Soft-deleted scores listed through `filter_params.deleted` are left unranked.
     ///
     /// # Errors
     /// Returns `ApiError::ValidationError` if the game `hex_id` or cursor is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
     /// # Panics
@@ -577,17 +1639,47 @@ impl ScoreRepository {
         game_hex_id: &str,
         pagination: PaginationParams,
         sort_params: ScoreSortParams,
+        filter_params: ScoreFilterParams,
+        rank_policy: RankPolicy,
     ) -> Result<PaginatedResponse<Score>> {
         Game::validate_hex_id(game_hex_id)?;
+        let cursor = pagination
+            .cursor
+            .as_deref()
+            .map(decode_score_cursor)
+            .transpose()
+            .map_err(|e| ApiError::ValidationError(format!("Invalid cursor: {e}")))?;
+
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+        let sort_params = sort_params.with_game_defaults(&game);
 
         let limit = pagination.get_limit();
         let fetch_limit = i64::from(limit + 1);
         let sort_field = sort_params.get_cursor_field();
+        let rank_order = game.score_order.to_sql_rank_order();
+        let rank_expr = live_rank_expr(rank_policy, None, rank_order, filter_params.deleted);
+        let deleted_condition = filter_params.deleted.to_sql_condition("deleted_at");
+        let window_conditions = submitted_window_conditions(&filter_params, "submitted_at", 2, 3);
+        let leaderboard_condition = if filter_params.leaderboard_id.is_some() {
+            " AND leaderboard_id = ?4"
+        } else {
+            ""
+        };
+        let mut source = format!(
+            r"
+            SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
+            FROM score
+            JOIN player USING (user_id)
+            WHERE {deleted_condition} AND game_hex_id = ?1{window_conditions}{leaderboard_condition}
+            "
+        );
+        if filter_params.distinct_users {
+            source = best_entry_per_user(&source, rank_order);
+        }
+        let submitted_from = filter_params.submitted_from.map(|from| from.naive_utc());
+        let submitted_to = filter_params.submitted_to.map(|to| to.naive_utc());
 
-        let scores = if let Some(cursor_str) = &pagination.cursor {
-            let cursor = decode_score_cursor(cursor_str)
-                .map_err(|e| ApiError::ValidationError(format!("Invalid cursor: {e}")))?;
-
+        let scores = if let Some(cursor) = cursor {
             // Build dynamic query based on sort parameters
             let order_clause = sort_params.to_sql_order_clause();
             let comparison_op = match sort_params.get_sort_order() {
@@ -597,19 +1689,24 @@ impl ScoreRepository {
 
             let query = format!(
                 r"
-                SELECT id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
-                FROM score 
-                WHERE deleted_at IS NULL 
-                AND game_hex_id = ?1
-                AND ({sort_field} {comparison_op} ?2 OR ({sort_field} = ?2 AND id > ?3))
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at, rank
+                FROM (
+                    SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                        {rank_expr} AS rank
+                    FROM ({source})
+                )
+                WHERE ({sort_field} {comparison_op} ?5 OR ({sort_field} = ?5 AND id > ?6))
                 ORDER BY {order_clause}, id
-                LIMIT ?4
+                LIMIT ?7
                 "
             );
 
             let score_rows = sqlx::query(&query)
                 .bind(game_hex_id)
-                .bind(&cursor.sort_value)
+                .bind(submitted_from)
+                .bind(submitted_to)
+                .bind(filter_params.leaderboard_id)
+                .bind(cursor.to_sql_value(sort_field))
                 .bind(cursor.id)
                 .bind(fetch_limit)
                 .fetch_all(pool)
@@ -621,6 +1718,7 @@ impl ScoreRepository {
                     Score::from(ScoreRow {
                         id: row.get("id"),
                         game_hex_id: row.get("game_hex_id"),
+                        leaderboard_id: row.get("leaderboard_id"),
                         score: row.get("score"),
                         score_val: row.get("score_val"),
                         user_name: row.get("user_name"),
@@ -629,22 +1727,26 @@ impl ScoreRepository {
                         submitted_at: row.get("submitted_at"),
                         deleted_at: row.get("deleted_at"),
                     })
+                    .with_rank(row.get("rank"))
                 })
                 .collect()
         } else {
             let order_clause = sort_params.to_sql_order_clause();
             let query = format!(
                 r"
-                SELECT id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
-                FROM score 
-                WHERE deleted_at IS NULL AND game_hex_id = ?1
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                    {rank_expr} AS rank
+                FROM ({source})
                 ORDER BY {order_clause}, id
-                LIMIT ?2
+                LIMIT ?5
                 "
             );
 
             let score_rows = sqlx::query(&query)
                 .bind(game_hex_id)
+                .bind(submitted_from)
+                .bind(submitted_to)
+                .bind(filter_params.leaderboard_id)
                 .bind(fetch_limit)
                 .fetch_all(pool)
                 .await?;
@@ -655,6 +1757,7 @@ impl ScoreRepository {
                     Score::from(ScoreRow {
                         id: row.get("id"),
                         game_hex_id: row.get("game_hex_id"),
+                        leaderboard_id: row.get("leaderboard_id"),
                         score: row.get("score"),
                         score_val: row.get("score_val"),
                         user_name: row.get("user_name"),
@@ -663,6 +1766,7 @@ impl ScoreRepository {
                         submitted_at: row.get("submitted_at"),
                         deleted_at: row.get("deleted_at"),
                     })
+                    .with_rank(row.get("rank"))
                 })
                 .collect()
         };
@@ -677,7 +1781,12 @@ impl ScoreRepository {
         Ok(response)
     }
 
-    /// List all scores across all games with pagination and sorting
+    /// List all scores across all games with pagination and sorting.
+    ///
+    /// Ranks are computed per leaderboard, so each score's rank is its position on its own board.
+    /// With `distinct_users`, only each player's best entry per board is listed, and a
+    /// submission window in `filter_params` restricts the listing to that period.
+    /// Soft-deleted scores listed through `filter_params.deleted` are left unranked.
     ///
     /// # Errors
     /// Returns `ApiError::ValidationError` if the cursor is invalid.
@@ -689,10 +1798,40 @@ impl ScoreRepository {
         pool: &SqlitePool,
         pagination: PaginationParams,
         sort_params: ScoreSortParams,
+        filter_params: ScoreFilterParams,
+        rank_policy: RankPolicy,
     ) -> Result<PaginatedResponse<Score>> {
         let limit = pagination.get_limit();
         let fetch_limit = i64::from(limit + 1);
         let sort_field = sort_params.get_cursor_field();
+        // Each game ranks in its own direction, so flip lower-is-better scores
+        let rank_order =
+            "CASE game_score_order WHEN 'lower_is_better' THEN -score_val ELSE score_val END DESC";
+        let rank_expr = live_rank_expr(
+            rank_policy,
+            Some("game_hex_id, leaderboard_id"),
+            rank_order,
+            filter_params.deleted,
+        );
+        let deleted_condition = filter_params.deleted.to_sql_condition("score.deleted_at");
+        let window_conditions =
+            submitted_window_conditions(&filter_params, "score.submitted_at", 1, 2);
+        let mut source = format!(
+            r"
+            SELECT score.id, score.game_hex_id, score.leaderboard_id, score.score, score.score_val, player.user_name,
+                score.user_id, score.extra, score.submitted_at, score.deleted_at,
+                game.score_order AS game_score_order
+            FROM score
+            JOIN game ON game.hex_id = score.game_hex_id
+            JOIN player ON player.user_id = score.user_id
+            WHERE {deleted_condition}{window_conditions}
+            "
+        );
+        if filter_params.distinct_users {
+            source = best_entry_per_user(&source, rank_order);
+        }
+        let submitted_from = filter_params.submitted_from.map(|from| from.naive_utc());
+        let submitted_to = filter_params.submitted_to.map(|to| to.naive_utc());
 
         let scores = if let Some(cursor_str) = &pagination.cursor {
             let cursor = decode_score_cursor(cursor_str)
@@ -707,17 +1846,22 @@ impl ScoreRepository {
 
             let query = format!(
                 r"
-                SELECT id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
-                FROM score 
-                WHERE deleted_at IS NULL 
-                AND ({sort_field} {comparison_op} ?1 OR ({sort_field} = ?1 AND id > ?2))
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at, rank
+                FROM (
+                    SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                        {rank_expr} AS rank
+                    FROM ({source})
+                )
+                WHERE ({sort_field} {comparison_op} ?3 OR ({sort_field} = ?3 AND id > ?4))
                 ORDER BY {order_clause}, id
-                LIMIT ?3
+                LIMIT ?5
                 "
             );
 
             let score_rows = sqlx::query(&query)
-                .bind(&cursor.sort_value)
+                .bind(submitted_from)
+                .bind(submitted_to)
+                .bind(cursor.to_sql_value(sort_field))
                 .bind(cursor.id)
                 .bind(fetch_limit)
                 .fetch_all(pool)
@@ -729,6 +1873,7 @@ impl ScoreRepository {
                     Score::from(ScoreRow {
                         id: row.get("id"),
                         game_hex_id: row.get("game_hex_id"),
+                        leaderboard_id: row.get("leaderboard_id"),
                         score: row.get("score"),
                         score_val: row.get("score_val"),
                         user_name: row.get("user_name"),
@@ -737,21 +1882,24 @@ impl ScoreRepository {
                         submitted_at: row.get("submitted_at"),
                         deleted_at: row.get("deleted_at"),
                     })
+                    .with_rank(row.get("rank"))
                 })
                 .collect()
         } else {
             let order_clause = sort_params.to_sql_order_clause();
             let query = format!(
                 r"
-                SELECT id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
-                FROM score 
-                WHERE deleted_at IS NULL
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                    {rank_expr} AS rank
+                FROM ({source})
                 ORDER BY {order_clause}, id
-                LIMIT ?1
+                LIMIT ?3
                 "
             );
 
             let score_rows = sqlx::query(&query)
+                .bind(submitted_from)
+                .bind(submitted_to)
                 .bind(fetch_limit)
                 .fetch_all(pool)
                 .await?;
@@ -762,6 +1910,7 @@ impl ScoreRepository {
                     Score::from(ScoreRow {
                         id: row.get("id"),
                         game_hex_id: row.get("game_hex_id"),
+                        leaderboard_id: row.get("leaderboard_id"),
                         score: row.get("score"),
                         score_val: row.get("score_val"),
                         user_name: row.get("user_name"),
@@ -770,6 +1919,7 @@ impl ScoreRepository {
                         submitted_at: row.get("submitted_at"),
                         deleted_at: row.get("deleted_at"),
                     })
+                    .with_rank(row.get("rank"))
                 })
                 .collect()
         };
@@ -783,16 +1933,451 @@ impl ScoreRepository {
         Ok(response)
     }
 
+    /// Get a player's best score on a game along with its rank and percentile
+    ///
+    /// "Best" follows the game's `score_order`. With a `leaderboard_id` the player is ranked
+    /// on that board only; without one, all of the game's boards are ranked together.
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the game `hex_id` or user ID is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist or the player has no scores on it.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    ///
+    /// # Panics
+    /// Does not panic under normal operation.
+    pub async fn get_player_rank(
+        pool: &SqlitePool,
+        game_hex_id: &str,
+        leaderboard_id: Option<i64>,
+        user_id: &str,
+        rank_policy: RankPolicy,
+    ) -> Result<PlayerRank> {
+        Score::validate_user_id(user_id)?;
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+
+        let rank_order = game.score_order.to_sql_rank_order();
+        let rank_expr = rank_policy.to_sql_window(None, rank_order);
+        let query = format!(
+            r"
+            SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                rank, percent_rank, total_scores
+            FROM (
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                    {rank_expr} AS rank,
+                    PERCENT_RANK() OVER (ORDER BY {rank_order}) AS percent_rank,
+                    COUNT(*) OVER () AS total_scores
+                FROM score
+                JOIN player USING (user_id)
+                WHERE deleted_at IS NULL AND game_hex_id = ?1 AND (?3 IS NULL OR leaderboard_id = ?3)
+            )
+            WHERE user_id = ?2
+            ORDER BY rank, id
+            LIMIT 1
+            "
+        );
+
+        let row = sqlx::query(&query)
+            .bind(game_hex_id)
+            .bind(user_id)
+            .bind(leaderboard_id)
+            .fetch_optional(pool)
+            .await?
+            .ok_or(ApiError::NotFound)?;
+
+        let rank: i64 = row.get("rank");
+        let percent_rank: f64 = row.get("percent_rank");
+        let best_score = Score::from(ScoreRow {
+            id: row.get("id"),
+            game_hex_id: row.get("game_hex_id"),
+            leaderboard_id: row.get("leaderboard_id"),
+            score: row.get("score"),
+            score_val: row.get("score_val"),
+            user_name: row.get("user_name"),
+            user_id: row.get("user_id"),
+            extra: row.get("extra"),
+            submitted_at: row.get("submitted_at"),
+            deleted_at: row.get("deleted_at"),
+        })
+        .with_rank(Some(rank));
+
+        Ok(PlayerRank {
+            game_hex_id: best_score.game_hex_id.clone(),
+            user_id: best_score.user_id.clone(),
+            rank,
+            percentile: (1.0 - percent_rank) * 100.0,
+            total_scores: row.get("total_scores"),
+            best_score,
+        })
+    }
+
+    /// Get aggregate statistics and the `score_val` distribution of a game's live scores
+    ///
+    /// The histogram splits the range between the lowest and highest `score_val` into
+    /// `buckets` equal-width buckets. Submissions per day are grouped by UTC date. With a
+    /// `leaderboard_id` only that board's scores count; without one, all of the game's do.
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the game `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn get_game_stats(
+        pool: &SqlitePool,
+        game_hex_id: &str,
+        leaderboard_id: Option<i64>,
+        buckets: u32,
+    ) -> Result<GameStats> {
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+
+        // Read everything from one snapshot so the figures agree with each other
+        let mut tx = pool.begin().await?;
+        let totals = sqlx::query!(
+            r#"
+            SELECT COUNT(*) AS "total_scores!: i64", COUNT(DISTINCT user_id) AS "unique_players!: i64",
+                MIN(score_val) AS "min: f64", MAX(score_val) AS "max: f64", AVG(score_val) AS "mean: f64"
+            FROM score
+            WHERE game_hex_id = ?1 AND (?2 IS NULL OR leaderboard_id = ?2) AND deleted_at IS NULL
+            "#,
+            game.hex_id,
+            leaderboard_id
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let (score_val, histogram) = match (totals.min, totals.max, totals.mean) {
+            (Some(min), Some(max), Some(mean)) => {
+                let count = totals.total_scores;
+                let median = if count % 2 == 0 {
+                    let lower = score_val_at(&mut tx, &game.hex_id, leaderboard_id, count / 2 - 1).await?;
+                    let upper = score_val_at(&mut tx, &game.hex_id, leaderboard_id, count / 2).await?;
+                    (lower + upper) / 2.0
+                } else {
+                    score_val_at(&mut tx, &game.hex_id, leaderboard_id, count / 2).await?
+                };
+                let stats = ScoreValStats {
+                    min,
+                    max,
+                    mean,
+                    median,
+                    p50: score_val_at(&mut tx, &game.hex_id, leaderboard_id, percentile_offset(count, 50.0)).await?,
+                    p90: score_val_at(&mut tx, &game.hex_id, leaderboard_id, percentile_offset(count, 90.0)).await?,
+                    p99: score_val_at(&mut tx, &game.hex_id, leaderboard_id, percentile_offset(count, 99.0)).await?,
+                };
+
+                let width = bucket_width(min, max, buckets);
+                let last_bucket = i64::from(buckets) - 1;
+                let counts: Vec<(i64, i64)> = if width == 0.0 {
+                    vec![(0, count)]
+                } else {
+                    sqlx::query!(
+                        r#"
+                        SELECT MIN(CAST((score_val - ?3) / ?4 AS INTEGER), ?5) AS "bucket!: i64",
+                            COUNT(*) AS "count!: i64"
+                        FROM score
+                        WHERE game_hex_id = ?1 AND (?2 IS NULL OR leaderboard_id = ?2) AND deleted_at IS NULL
+                        GROUP BY 1
+                        "#,
+                        game.hex_id,
+                        leaderboard_id,
+                        min,
+                        width,
+                        last_bucket
+                    )
+                    .fetch_all(&mut *tx)
+                    .await?
+                    .into_iter()
+                    .map(|row| (row.bucket, row.count))
+                    .collect()
+                };
+                (Some(stats), build_histogram(min, max, buckets, &counts))
+            }
+            _ => (None, Vec::new()),
+        };
+
+        let submissions_per_day = sqlx::query!(
+            r#"
+            SELECT date(submitted_at) AS "date!: chrono::NaiveDate", COUNT(*) AS "submissions!: i64"
+            FROM score
+            WHERE game_hex_id = ?1 AND (?2 IS NULL OR leaderboard_id = ?2) AND deleted_at IS NULL
+            GROUP BY 1
+            ORDER BY 1
+            "#,
+            game.hex_id,
+            leaderboard_id
+        )
+        .fetch_all(&mut *tx)
+        .await?
+        .into_iter()
+        .map(|row| DailySubmissions {
+            date: row.date,
+            submissions: row.submissions,
+        })
+        .collect();
+        tx.commit().await?;
+
+        Ok(GameStats {
+            game_hex_id: game.hex_id,
+            total_scores: totals.total_scores,
+            unique_players: totals.unique_players,
+            score_val,
+            submissions_per_day,
+            histogram,
+        })
+    }
+
+    /// Get a player's stats on every game they have live scores on, with a summary across games
+    ///
+    /// Each game reports the player's best entry (ranked under `rank_policy` on its own
+    /// leaderboard of the game), latest entry, submission count, average `score_val` and first/last play
+    /// times. `game_hex_ids` restricts the stats to those games; empty means every game.
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the user ID is invalid.
+    /// Returns `ApiError::NotFound` if the player has no profile and no scores.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    ///
+    /// # Panics
+    /// Does not panic under normal operation.
+    pub async fn get_player_stats(
+        pool: &SqlitePool,
+        user_id: &str,
+        rank_policy: RankPolicy,
+        game_hex_ids: &[String],
+    ) -> Result<PlayerStats> {
+        Score::validate_user_id(user_id)?;
+
+        // Each game ranks in its own direction, so flip lower-is-better scores
+        let rank_order =
+            "CASE game_score_order WHEN 'lower_is_better' THEN -score_val ELSE score_val END DESC";
+        let rank_expr = rank_policy.to_sql_window(Some("game_hex_id, leaderboard_id"), rank_order);
+        let query = format!(
+            r"
+            WITH board AS (
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                    game_name, {rank_expr} AS rank
+                FROM (
+                    SELECT score.id, score.game_hex_id, score.leaderboard_id, score.score, score.score_val, player.user_name,
+                        score.user_id, score.extra, score.submitted_at, score.deleted_at,
+                        game.name AS game_name, game.score_order AS game_score_order
+                    FROM score
+                    JOIN game ON game.hex_id = score.game_hex_id
+                    JOIN player ON player.user_id = score.user_id
+                    WHERE score.deleted_at IS NULL AND score.game_hex_id IN (
+                        SELECT game_hex_id FROM score WHERE user_id = ?1 AND deleted_at IS NULL
+                    )
+                )
+            ),
+            entries AS (
+                SELECT board.*,
+                    ROW_NUMBER() OVER (PARTITION BY game_hex_id ORDER BY rank, id) AS best_position,
+                    ROW_NUMBER() OVER (PARTITION BY game_hex_id ORDER BY submitted_at DESC, id DESC) AS recency,
+                    COUNT(*) OVER (PARTITION BY game_hex_id) AS submissions,
+                    AVG(score_val) OVER (PARTITION BY game_hex_id) AS average_score_val,
+                    MIN(submitted_at) OVER (PARTITION BY game_hex_id) AS first_played_at,
+                    MAX(submitted_at) OVER (PARTITION BY game_hex_id) AS last_played_at
+                FROM board
+                WHERE user_id = ?1
+            )
+            SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                game_name, rank, recency, submissions, average_score_val,
+                first_played_at, last_played_at
+            FROM entries
+            WHERE best_position = 1 OR recency = 1
+            ORDER BY last_played_at DESC, game_hex_id, best_position
+            "
+        );
+
+        let rows = sqlx::query(&query).bind(user_id).fetch_all(pool).await?;
+
+        // Each game yields its best entry and its latest entry, which may be the same row
+        let mut games: Vec<PlayerGameStats> = Vec::new();
+        for row in rows {
+            let game_hex_id: String = row.get("game_hex_id");
+            if !game_hex_ids.is_empty() && !game_hex_ids.contains(&game_hex_id) {
+                continue;
+            }
+
+            let rank: i64 = row.get("rank");
+            let recency: i64 = row.get("recency");
+            let first_played_at: chrono::NaiveDateTime = row.get("first_played_at");
+            let last_played_at: chrono::NaiveDateTime = row.get("last_played_at");
+            let game_name: String = row.get("game_name");
+            let submissions: i64 = row.get("submissions");
+            let average_score_val: f64 = row.get("average_score_val");
+            let score = Score::from(ScoreRow {
+                id: row.get("id"),
+                game_hex_id: game_hex_id.clone(),
+                leaderboard_id: row.get("leaderboard_id"),
+                score: row.get("score"),
+                score_val: row.get("score_val"),
+                user_name: row.get("user_name"),
+                user_id: row.get("user_id"),
+                extra: row.get("extra"),
+                submitted_at: row.get("submitted_at"),
+                deleted_at: row.get("deleted_at"),
+            })
+            .with_rank(Some(rank));
+
+            match games.last_mut() {
+                Some(stats) if stats.game_hex_id == game_hex_id => {
+                    if recency == 1 {
+                        stats.latest_score = score;
+                    }
+                }
+                _ => games.push(PlayerGameStats {
+                    game_hex_id,
+                    game_name,
+                    submissions,
+                    latest_score: score.clone(),
+                    best_score: score,
+                    rank,
+                    first_played_at: chrono::DateTime::from_naive_utc_and_offset(first_played_at, Utc),
+                    last_played_at: chrono::DateTime::from_naive_utc_and_offset(last_played_at, Utc),
+                    average_score_val,
+                }),
+            }
+        }
+
+        if games.is_empty() {
+            // A player whose scores are all deleted still has (empty) stats
+            PlayerRepository::get(pool, user_id).await?;
+        }
+
+        Ok(PlayerStats {
+            user_id: user_id.to_string(),
+            summary: PlayerStatsSummary::from_games(&games),
+            games,
+        })
+    }
+
+    /// List the entries surrounding a player's entry (or a specific score) on a game's board
+    ///
+    /// The window follows the requested sort (falling back to the game's configuration),
+    /// includes up to `radius` entries on each side of the anchor, and every entry carries
+    /// its rank under `rank_policy`. With a `leaderboard_id` the window covers that board
+    /// only; without one, all of the game's boards are ranked together.
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the game `hex_id` or user ID is invalid.
+    /// Returns `ApiError::NotFound` if the game or the anchor entry does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    ///
+    /// # Panics
+    /// Does not panic under normal operation.
+    pub async fn list_around(
+        pool: &SqlitePool,
+        game_hex_id: &str,
+        leaderboard_id: Option<i64>,
+        anchor: AroundAnchor,
+        radius: u32,
+        sort_params: ScoreSortParams,
+        rank_policy: RankPolicy,
+    ) -> Result<LeaderboardWindow> {
+        if let AroundAnchor::UserId(ref user_id) = anchor {
+            Score::validate_user_id(user_id)?;
+        }
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+        let sort_params = sort_params.with_game_defaults(&game);
+
+        let rank_expr = rank_policy.to_sql_window(None, game.score_order.to_sql_rank_order());
+        let order_clause = sort_params.to_sql_order_clause();
+        let center_clause = match anchor {
+            AroundAnchor::UserId(_) => "WHERE user_id = ?2 ORDER BY rank, id LIMIT 1",
+            AroundAnchor::ScoreId(_) => "WHERE id = ?2",
+        };
+
+        let query = format!(
+            r"
+            WITH board AS (
+                SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at,
+                    {rank_expr} AS rank,
+                    ROW_NUMBER() OVER (ORDER BY {order_clause}, id) AS position,
+                    COUNT(*) OVER () AS total
+                FROM score
+                JOIN player USING (user_id)
+                WHERE deleted_at IS NULL AND game_hex_id = ?1 AND (?4 IS NULL OR leaderboard_id = ?4)
+            ),
+            center AS (
+                SELECT id, position FROM board {center_clause}
+            )
+            SELECT board.id, board.game_hex_id, board.leaderboard_id, board.score, board.score_val, board.user_name,
+                board.user_id, board.extra, board.submitted_at, board.deleted_at,
+                board.rank, board.position, board.total, center.id AS center_id
+            FROM board, center
+            WHERE board.position BETWEEN center.position - ?3 AND center.position + ?3
+            ORDER BY board.position
+            "
+        );
+
+        let query = sqlx::query(&query).bind(game_hex_id);
+        let query = match anchor {
+            AroundAnchor::UserId(user_id) => query.bind(user_id),
+            AroundAnchor::ScoreId(score_id) => query.bind(score_id),
+        };
+        let rows = query
+            .bind(i64::from(radius))
+            .bind(leaderboard_id)
+            .fetch_all(pool)
+            .await?;
+
+        let (first, last) = match (rows.first(), rows.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return Err(ApiError::NotFound),
+        };
+        let center_score_id: i64 = first.get("center_id");
+        let has_more_above = first.get::<i64, _>("position") > 1;
+        let has_more_below = last.get::<i64, _>("position") < last.get::<i64, _>("total");
+
+        let data = rows
+            .iter()
+            .map(|row| {
+                Score::from(ScoreRow {
+                    id: row.get("id"),
+                    game_hex_id: row.get("game_hex_id"),
+                    leaderboard_id: row.get("leaderboard_id"),
+                    score: row.get("score"),
+                    score_val: row.get("score_val"),
+                    user_name: row.get("user_name"),
+                    user_id: row.get("user_id"),
+                    extra: row.get("extra"),
+                    submitted_at: row.get("submitted_at"),
+                    deleted_at: row.get("deleted_at"),
+                })
+                .with_rank(row.get("rank"))
+            })
+            .collect();
+
+        Ok(LeaderboardWindow {
+            game_hex_id: game_hex_id.to_string(),
+            center_score_id,
+            radius,
+            data,
+            has_more_above,
+            has_more_below,
+        })
+    }
+
     /// Update a score
     ///
+    /// A new `user_id` moves the score to that player, creating their profile if needed.
+    /// Scores are named after their player, so players are renamed through
+    /// `PlayerRepository::update` rather than here.
+    /// The updated entry, and any change to the top of the board, is published to `events`.
+    ///
     /// # Errors
-    /// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid.
+    /// Returns `ApiError::ValidationError` if a `user_name` is given, the user ID or JSON data
+    /// is invalid, `score` is not a number and no `score_val` is given, or the updated value or
+    /// `extra` breaks the game's validation rules.
     /// Returns `ApiError::NotFound` if no score exists with the given id.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
     /// # Panics
     /// Panics if the database returns a NULL id, which should never happen.
-    pub async fn update(pool: &SqlitePool, id: i64, update_data: UpdateScore) -> Result<Score> {
+    pub async fn update(
+        pool: &SqlitePool,
+        events: &ScoreEvents,
+        id: i64,
+        update_data: UpdateScore,
+    ) -> Result<Score> {
         if let Some(ref user_name) = update_data.user_name {
             Score::validate_user_name(user_name)?;
         }
@@ -810,44 +2395,77 @@ impl ScoreRepository {
         };
 
         // Calculate score_val from score if needed
-        let score_val = if let Some(ref score) = update_data.score {
-            Some(
-                update_data
-                    .score_val
-                    .unwrap_or_else(|| score.parse::<f64>().unwrap_or(0.0)),
-            )
-        } else {
-            update_data.score_val
+        let score_val = match update_data.score {
+            Some(ref score) => Some(Score::parse_score_val(score, update_data.score_val)?),
+            None => update_data.score_val,
         };
 
+        // The updated entry must still pass the game's validation rules
+        if score_val.is_some() || update_data.extra.is_some() {
+            let existing = Self::get_by_id(pool, id).await?;
+            let game = GameRepository::get_by_hex_id(pool, &existing.game_hex_id).await?;
+            game.validation.check_score(
+                score_val.unwrap_or(existing.score_val),
+                update_data.extra.as_ref().or(existing.extra.as_ref()),
+            )?;
+        }
+
+        let now_naive = Utc::now().naive_utc();
+        let mut tx = pool.begin().await?;
+
+        let current = sqlx::query!(
+            r#"
+            SELECT score.game_hex_id, score.leaderboard_id AS "leaderboard_id!", score.user_id, player.user_name
+            FROM score
+            JOIN player ON player.user_id = score.user_id
+            WHERE score.id = ?1 AND score.deleted_at IS NULL
+            "#,
+            id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+        if update_data.user_name.is_some() {
+            return Err(ApiError::ValidationError(
+                "A score's user_name comes from its player; rename the player with PUT /players/{user_id}"
+                    .to_string(),
+            ));
+        }
+        let top_before =
+            Self::watch_top(&mut tx, events, &current.game_hex_id, current.leaderboard_id).await?;
+
+        let user_id = update_data.user_id.unwrap_or(current.user_id);
+        let user_name = ensure_player(&mut tx, &user_id, &current.user_name, now_naive).await?;
+
         let row = sqlx::query!(
             r#"
             UPDATE score 
             SET score = COALESCE(?1, score),
                 score_val = COALESCE(?2, score_val),
-                user_name = COALESCE(?3, user_name),
-                user_id = COALESCE(?4, user_id),
-                extra = COALESCE(?5, extra)
-            WHERE id = ?6 AND deleted_at IS NULL
-            RETURNING id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
+                user_id = ?3,
+                extra = COALESCE(?4, extra)
+            WHERE id = ?5 AND deleted_at IS NULL
+            RETURNING id AS "id!", game_hex_id, leaderboard_id AS "leaderboard_id!", score, score_val, user_id, extra, submitted_at,
+                deleted_at AS "deleted_at?"
             "#,
             update_data.score,
             score_val,
-            update_data.user_name,
-            update_data.user_id,
+            user_id,
             extra_json,
             id
         )
-        .fetch_optional(pool)
+        .fetch_optional(&mut *tx)
         .await?
         .ok_or(ApiError::NotFound)?;
+        tx.commit().await?;
 
         let score_row = ScoreRow {
-            id: row.id.unwrap(),
+            id: row.id,
             game_hex_id: row.game_hex_id,
+            leaderboard_id: row.leaderboard_id,
             score: row.score,
             score_val: row.score_val,
-            user_name: row.user_name,
+            user_name,
             user_id: row.user_id,
             extra: row.extra,
             submitted_at: row.submitted_at,
@@ -855,33 +2473,59 @@ impl ScoreRepository {
         };
 
         let score = Score::from(score_row);
+        Self::publish(pool, events, ScoreEvent::ScoreUpdated { score: score.clone() }).await;
+        Self::publish_top_change(pool, events, &score.game_hex_id, score.leaderboard_id, top_before).await;
         Ok(score)
     }
 
     /// Soft delete a score
     ///
+    /// The deletion, and any change to the top of the board, is published to `events`.
+    ///
     /// # Errors
     /// Returns `ApiError::NotFound` if no score exists with the given id.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
     /// # Panics
     /// Does not panic under normal operation.
-    pub async fn soft_delete(pool: &SqlitePool, id: i64) -> Result<()> {
+    pub async fn soft_delete(pool: &SqlitePool, events: &ScoreEvents, id: i64) -> Result<()> {
         let now = Utc::now();
         let now_naive = now.naive_utc();
-        let rows_affected = sqlx::query!(
-            "UPDATE score SET deleted_at = ?1 WHERE id = ?2 AND deleted_at IS NULL",
-            now_naive,
+        let mut tx = pool.begin().await?;
+
+        let current = sqlx::query!(
+            r#"
+            SELECT game_hex_id, leaderboard_id AS "leaderboard_id!"
+            FROM score
+            WHERE id = ?1 AND deleted_at IS NULL
+            "#,
             id
         )
-        .execute(pool)
+        .fetch_optional(&mut *tx)
         .await?
-        .rows_affected();
+        .ok_or(ApiError::NotFound)?;
+        let game_hex_id = current.game_hex_id;
+        let top_before = Self::watch_top(&mut tx, events, &game_hex_id, current.leaderboard_id).await?;
 
-        if rows_affected == 0 {
-            return Err(ApiError::NotFound);
-        }
+        sqlx::query!(
+            "UPDATE score SET deleted_at = ?1 WHERE id = ?2 AND deleted_at IS NULL",
+            now_naive,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
 
+        Self::publish(
+            pool,
+            events,
+            ScoreEvent::ScoreDeleted {
+                game_hex_id: game_hex_id.clone(),
+                score_id: id,
+            },
+        )
+        .await;
+        Self::publish_top_change(pool, events, &game_hex_id, current.leaderboard_id, top_before).await;
         Ok(())
     }
 
@@ -889,17 +2533,39 @@ impl ScoreRepository {
     ///
     /// # Errors
     /// Returns `ApiError::NotFound` if no score exists with the given id or it's not deleted.
+    /// Returns `ApiError::ValidationError` if the score's game is itself soft-deleted.
     /// Returns `ApiError::DatabaseError` if the database operation fails.
     ///
     /// # Panics
     /// Panics if the database returns a NULL id, which should never happen.
     pub async fn restore(pool: &SqlitePool, id: i64) -> Result<Score> {
+        let game = sqlx::query!(
+            r#"
+            SELECT game.hex_id, game.deleted_at
+            FROM score
+            JOIN game ON game.hex_id = score.game_hex_id
+            WHERE score.id = ?1 AND score.deleted_at IS NOT NULL
+            "#,
+            id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+        if game.deleted_at.is_some() {
+            return Err(ApiError::ValidationError(format!(
+                "Game {} is deleted; restore the game to restore its scores",
+                game.hex_id
+            )));
+        }
+
         let row = sqlx::query!(
             r#"
             UPDATE score 
             SET deleted_at = NULL
             WHERE id = ?1 AND deleted_at IS NOT NULL
-            RETURNING id, game_hex_id, score, score_val, user_name, user_id, extra, submitted_at, deleted_at
+            RETURNING id AS "id!", game_hex_id, leaderboard_id AS "leaderboard_id!", score, score_val,
+                (SELECT user_name FROM player WHERE player.user_id = score.user_id) AS "user_name!: String",
+                user_id, extra, submitted_at, deleted_at
             "#,
             id
         )
@@ -908,8 +2574,9 @@ impl ScoreRepository {
         .ok_or(ApiError::NotFound)?;
 
         let score_row = ScoreRow {
-            id: row.id.unwrap(),
+            id: row.id,
             game_hex_id: row.game_hex_id,
+            leaderboard_id: row.leaderboard_id,
             score: row.score,
             score_val: row.score_val,
             user_name: row.user_name,
@@ -923,3 +2590,2024 @@ impl ScoreRepository {
         Ok(score)
     }
 }
+
+impl SeasonRepository {
+    /// Schedule a new season on a game
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist.
+    /// Returns `ApiError::ValidationError` if the name or schedule is invalid.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn create(
+        pool: &SqlitePool,
+        game_hex_id: &str,
+        create_data: CreateSeason,
+    ) -> Result<Season> {
+        Season::validate_name(&create_data.name)?;
+        Season::validate_schedule(create_data.starts_at, create_data.ends_at)?;
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+
+        let starts_at = create_data.starts_at.naive_utc();
+        let ends_at = create_data.ends_at.naive_utc();
+        let now_naive = Utc::now().naive_utc();
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO season (game_hex_id, name, starts_at, ends_at, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            RETURNING id, game_hex_id, name, starts_at, ends_at, closed_at, created_at
+            "#,
+            game.hex_id,
+            create_data.name,
+            starts_at,
+            ends_at,
+            now_naive
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let season_row = SeasonRow {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            name: row.name,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            closed_at: row.closed_at,
+            created_at: row.created_at,
+        };
+
+        Ok(Season::from(season_row))
+    }
+
+    /// List a game's seasons, most recent first
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn list_by_game(pool: &SqlitePool, game_hex_id: &str) -> Result<Vec<Season>> {
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", game_hex_id, name, starts_at, ends_at, closed_at, created_at
+            FROM season
+            WHERE game_hex_id = ?1
+            ORDER BY starts_at DESC, id DESC
+            "#,
+            game.hex_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let seasons = rows
+            .into_iter()
+            .map(|row| {
+                Season::from(SeasonRow {
+                    id: row.id,
+                    game_hex_id: row.game_hex_id,
+                    name: row.name,
+                    starts_at: row.starts_at,
+                    ends_at: row.ends_at,
+                    closed_at: row.closed_at,
+                    created_at: row.created_at,
+                })
+            })
+            .collect();
+
+        Ok(seasons)
+    }
+
+    /// Get a season by id within a game
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game or season does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn get(pool: &SqlitePool, game_hex_id: &str, season_id: i64) -> Result<Season> {
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+
+        let row = sqlx::query!(
+            r#"
+            SELECT id AS "id!", game_hex_id, name, starts_at, ends_at, closed_at, created_at
+            FROM season
+            WHERE id = ?1 AND game_hex_id = ?2
+            "#,
+            season_id,
+            game.hex_id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+        let season_row = SeasonRow {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            name: row.name,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            closed_at: row.closed_at,
+            created_at: row.created_at,
+        };
+
+        Ok(Season::from(season_row))
+    }
+
+    /// Close a season now and archive its final standings
+    ///
+    /// Closing an already closed season returns it unchanged.
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game or season does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn close(pool: &SqlitePool, game_hex_id: &str, season_id: i64) -> Result<Season> {
+        let season = Self::get(pool, game_hex_id, season_id).await?;
+        if season.is_closed() {
+            return Ok(season);
+        }
+
+        let game = GameRepository::get_by_hex_id(pool, &season.game_hex_id).await?;
+        match Self::archive(pool, &game, season.id).await? {
+            Some(closed) => Ok(closed),
+            // Closed concurrently, e.g. by the background task
+            None => Self::get(pool, game_hex_id, season_id).await,
+        }
+    }
+
+    /// Close every open season whose end time has passed, archiving their standings
+    ///
+    /// Seasons on deleted games are left open.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn close_expired(pool: &SqlitePool) -> Result<Vec<Season>> {
+        let now_naive = Utc::now().naive_utc();
+
+        let expired = sqlx::query!(
+            r#"
+            SELECT season.id AS "id!", season.game_hex_id
+            FROM season
+            JOIN game ON game.hex_id = season.game_hex_id
+            WHERE season.closed_at IS NULL AND season.ends_at <= ?1 AND game.deleted_at IS NULL
+            ORDER BY season.ends_at, season.id
+            "#,
+            now_naive
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut closed = Vec::new();
+        for row in expired {
+            let game = GameRepository::get_by_hex_id(pool, &row.game_hex_id).await?;
+            if let Some(season) = Self::archive(pool, &game, row.id).await? {
+                closed.push(season);
+            }
+        }
+
+        Ok(closed)
+    }
+
+    /// Mark a season closed and freeze each player's best in-season score on each of the
+    /// game's leaderboards as its standings, ranked per leaderboard.
+    ///
+    /// Returns `None` if the season was already closed.
+    async fn archive(pool: &SqlitePool, game: &Game, season_id: i64) -> Result<Option<Season>> {
+        let now_naive = Utc::now().naive_utc();
+        let mut tx = pool.begin().await?;
+
+        // Claiming the season first keeps concurrent closes from archiving twice
+        let Some(row) = sqlx::query!(
+            r#"
+            UPDATE season
+            SET closed_at = ?1
+            WHERE id = ?2 AND closed_at IS NULL
+            RETURNING id AS "id!", game_hex_id, name, starts_at, ends_at, closed_at, created_at
+            "#,
+            now_naive,
+            season_id
+        )
+        .fetch_optional(&mut *tx)
+        .await?
+        else {
+            return Ok(None);
+        };
+
+        let season = Season::from(SeasonRow {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            name: row.name,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            closed_at: row.closed_at,
+            created_at: row.created_at,
+        });
+
+        let rank_order = game.score_order.to_sql_rank_order();
+        let rank_expr = RankPolicy::Competition.to_sql_window(Some("leaderboard_id"), rank_order);
+        let best_entries = best_entry_per_user(
+            r"
+            SELECT id, game_hex_id, leaderboard_id, score, score_val, user_name, user_id, submitted_at
+            FROM score
+            JOIN player USING (user_id)
+            WHERE deleted_at IS NULL AND game_hex_id = ?2 AND submitted_at >= ?3 AND submitted_at < ?4
+            ",
+            rank_order,
+        );
+        let query = format!(
+            r"
+            INSERT INTO season_standing (season_id, leaderboard_id, rank, user_id, user_name, score, score_val, submitted_at)
+            SELECT ?1, leaderboard_id, {rank_expr} AS rank, user_id, user_name, score, score_val, submitted_at
+            FROM ({best_entries})
+            ORDER BY leaderboard_id, rank, id
+            "
+        );
+
+        sqlx::query(&query)
+            .bind(season.id)
+            .bind(&season.game_hex_id)
+            .bind(season.starts_at.naive_utc())
+            .bind(season.effective_end().naive_utc())
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(Some(season))
+    }
+
+    /// List a closed season's archived standings in rank order with cursor pagination
+    ///
+    /// With a `leaderboard_id` only that board's standings are listed; without one, every
+    /// board's standings are listed together by rank.
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game or season does not exist.
+    /// Returns `ApiError::BadRequest` if the season has not closed yet.
+    /// Returns `ApiError::ValidationError` if the cursor is invalid.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn list_standings(
+        pool: &SqlitePool,
+        game_hex_id: &str,
+        season_id: i64,
+        leaderboard_id: Option<i64>,
+        pagination: PaginationParams,
+    ) -> Result<PaginatedResponse<SeasonStanding>> {
+        let season = Self::get(pool, game_hex_id, season_id).await?;
+        if !season.is_closed() {
+            return Err(ApiError::BadRequest(
+                "Season is still open; standings are archived when it closes".to_string(),
+            ));
+        }
+
+        let limit = pagination.get_limit();
+        let fetch_limit = i64::from(limit + 1);
+        let (after_rank, after_id) = match &pagination.cursor {
+            Some(cursor_str) => {
+                let cursor = decode_score_cursor(cursor_str)
+                    .map_err(|e| ApiError::ValidationError(format!("Invalid cursor: {e}")))?;
+                let rank = cursor.sort_value.parse::<i64>().map_err(|_| {
+                    ApiError::ValidationError("Invalid cursor: rank is not a number".to_string())
+                })?;
+                (rank, cursor.id)
+            }
+            None => (0, 0),
+        };
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", season_id, leaderboard_id AS "leaderboard_id!", rank, user_id, user_name, score,
+                score_val, submitted_at
+            FROM season_standing
+            WHERE season_id = ?1 AND (?2 IS NULL OR leaderboard_id = ?2) AND (rank > ?3 OR (rank = ?3 AND id > ?4))
+            ORDER BY rank, id
+            LIMIT ?5
+            "#,
+            season.id,
+            leaderboard_id,
+            after_rank,
+            after_id,
+            fetch_limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let standings = rows
+            .into_iter()
+            .map(|row| {
+                SeasonStanding::from(SeasonStandingRow {
+                    id: row.id,
+                    season_id: row.season_id,
+                    leaderboard_id: row.leaderboard_id,
+                    rank: row.rank,
+                    user_id: row.user_id,
+                    user_name: row.user_name,
+                    score: row.score,
+                    score_val: row.score_val,
+                    submitted_at: row.submitted_at,
+                })
+            })
+            .collect();
+
+        let response =
+            PaginatedResponse::from_query_results(standings, limit, pagination.cursor, |standing| {
+                let cursor = ScoreCursor {
+                    id: standing.id,
+                    sort_value: standing.rank.to_string(),
+                };
+                encode_score_cursor(&cursor).ok()
+            });
+
+        Ok(response)
+    }
+}
+
+impl LeaderboardRepository {
+    /// Add a board to a game
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist.
+    /// Returns `ApiError::ValidationError` if the slug or name is invalid.
+    /// Returns `ApiError::Conflict` if the game already has a board with the slug.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn create(
+        pool: &SqlitePool,
+        game_hex_id: &str,
+        create_data: CreateLeaderboard,
+    ) -> Result<Leaderboard> {
+        Leaderboard::validate_slug(&create_data.slug)?;
+        Leaderboard::validate_name(&create_data.name)?;
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+        let now_naive = Utc::now().naive_utc();
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO leaderboard (game_hex_id, slug, name, description, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            RETURNING id, game_hex_id, slug, name, description, created_at
+            "#,
+            game.hex_id,
+            create_data.slug,
+            create_data.name,
+            create_data.description,
+            now_naive
+        )
+        .fetch_one(pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::Database(ref db_err) if db_err.is_unique_violation() => ApiError::Conflict(
+                format!("Game already has a leaderboard with slug '{}'", create_data.slug),
+            ),
+            e => ApiError::Database(e),
+        })?;
+
+        let leaderboard_row = LeaderboardRow {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            slug: row.slug,
+            name: row.name,
+            description: row.description,
+            score_count: 0,
+            created_at: row.created_at,
+        };
+
+        Ok(Leaderboard::from(leaderboard_row))
+    }
+
+    /// List a game's boards in the order they were added, with their live score counts
+    ///
+    /// # Errors
+    /// Returns `ApiError::InvalidParameter` if the `hex_id` is invalid.
+    /// Returns `ApiError::NotFound` if the game does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn list_by_game(pool: &SqlitePool, game_hex_id: &str) -> Result<Vec<Leaderboard>> {
+        let game = GameRepository::get_by_hex_id(pool, game_hex_id).await?;
+
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", game_hex_id, slug, name, description, created_at,
+                (
+                    SELECT COUNT(*) FROM score
+                    WHERE score.leaderboard_id = leaderboard.id AND score.deleted_at IS NULL
+                ) AS "score_count!: i64"
+            FROM leaderboard
+            WHERE game_hex_id = ?1
+            ORDER BY id
+            "#,
+            game.hex_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let leaderboards = rows
+            .into_iter()
+            .map(|row| {
+                Leaderboard::from(LeaderboardRow {
+                    id: row.id,
+                    game_hex_id: row.game_hex_id,
+                    slug: row.slug,
+                    name: row.name,
+                    description: row.description,
+                    score_count: row.score_count,
+                    created_at: row.created_at,
+                })
+            })
+            .collect();
+
+        Ok(leaderboards)
+    }
+
+    /// The id of a game's board, by slug
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if the game has no board with the slug.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn id_by_slug<'c, E>(executor: E, game_hex_id: &str, slug: &str) -> Result<i64>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        sqlx::query_scalar!(
+            r#"SELECT id AS "id!" FROM leaderboard WHERE game_hex_id = ?1 AND slug = ?2"#,
+            game_hex_id,
+            slug
+        )
+        .fetch_optional(executor)
+        .await?
+        .ok_or(ApiError::NotFound)
+    }
+}
+
+impl ApiKeyRepository {
+    /// Create a new API key, returning its plaintext once
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the name is invalid or an allowed game does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn create(pool: &SqlitePool, create_data: CreateApiKey) -> Result<CreatedApiKey> {
+        ApiKey::validate_name(&create_data.name)?;
+
+        let mut game_hex_ids = create_data.game_hex_ids;
+        game_hex_ids.sort();
+        game_hex_ids.dedup();
+        for game_hex_id in &game_hex_ids {
+            GameRepository::get_by_hex_id(pool, game_hex_id)
+                .await
+                .map_err(|_| ApiError::ValidationError(format!("Unknown game: {game_hex_id}")))?;
+        }
+
+        let key = ApiKey::generate_key();
+        let key_hash = ApiKey::hash_key(&key);
+        let prefix = key[..API_KEY_PREFIX_LEN].to_string();
+        let scope = create_data.scope.as_str();
+        let now_naive = Utc::now().naive_utc();
+
+        let mut tx = pool.begin().await?;
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO api_key (name, prefix, key_hash, scope, created_at)
+            VALUES (?1, ?2, ?3, ?4, ?5)
+            RETURNING id, name, prefix, scope, created_at, revoked_at
+            "#,
+            create_data.name,
+            prefix,
+            key_hash,
+            scope,
+            now_naive
+        )
+        .fetch_one(&mut *tx)
+        .await?;
+
+        for game_hex_id in &game_hex_ids {
+            sqlx::query!(
+                r#"
+                INSERT INTO api_key_game (api_key_id, game_hex_id)
+                VALUES (?1, ?2)
+                "#,
+                row.id,
+                game_hex_id
+            )
+            .execute(&mut *tx)
+            .await?;
+        }
+
+        tx.commit().await?;
+
+        let mut api_key = ApiKey::try_from(ApiKeyRow {
+            id: row.id,
+            name: row.name,
+            prefix: row.prefix,
+            scope: row.scope,
+            created_at: row.created_at,
+            revoked_at: row.revoked_at,
+        })
+        .map_err(|e| {
+            tracing::error!("Created API key {} has an invalid row: {e}", row.id);
+            ApiError::InternalServerError
+        })?;
+        api_key.game_hex_ids = game_hex_ids;
+
+        Ok(CreatedApiKey { api_key, key })
+    }
+
+    /// List all API keys, including revoked ones, newest first
+    ///
+    /// Rows with an unknown scope are logged and left out.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<ApiKey>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", name, prefix, scope, created_at, revoked_at
+            FROM api_key
+            ORDER BY created_at DESC, id DESC
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let allowed_games = sqlx::query!(
+            r#"
+            SELECT api_key_id, game_hex_id
+            FROM api_key_game
+            ORDER BY game_hex_id
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let api_keys = rows
+            .into_iter()
+            .filter_map(|row| {
+                let id = row.id;
+                let mut api_key = match ApiKey::try_from(ApiKeyRow {
+                    id: row.id,
+                    name: row.name,
+                    prefix: row.prefix,
+                    scope: row.scope,
+                    created_at: row.created_at,
+                    revoked_at: row.revoked_at,
+                }) {
+                    Ok(api_key) => api_key,
+                    Err(e) => {
+                        tracing::warn!("Skipping API key {id}: {e}");
+                        return None;
+                    }
+                };
+                api_key.game_hex_ids = allowed_games
+                    .iter()
+                    .filter(|allowed| allowed.api_key_id == api_key.id)
+                    .map(|allowed| allowed.game_hex_id.clone())
+                    .collect();
+                Some(api_key)
+            })
+            .collect();
+
+        Ok(api_keys)
+    }
+
+    /// Revoke an API key so it can no longer authenticate
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if no active key exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn revoke(pool: &SqlitePool, id: i64) -> Result<()> {
+        let now_naive = Utc::now().naive_utc();
+
+        let rows_affected = sqlx::query!(
+            r#"
+            UPDATE api_key
+            SET revoked_at = ?1
+            WHERE id = ?2 AND revoked_at IS NULL
+            "#,
+            now_naive,
+            id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+
+        if rows_affected == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    /// Look up an active key by the hash of its plaintext
+    ///
+    /// Only hashes are compared, so the lookup reveals nothing about the plaintext of
+    /// stored keys. A key whose stored scope is unknown is logged and does not authenticate.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn find_active_by_hash(
+        pool: &SqlitePool,
+        key_hash: &str,
+    ) -> Result<Option<AuthenticatedKey>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id AS "id!", scope
+            FROM api_key
+            WHERE key_hash = ?1 AND revoked_at IS NULL
+            "#,
+            key_hash
+        )
+        .fetch_optional(pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let scope = match row.scope.parse::<ApiKeyScope>() {
+            Ok(scope) => scope,
+            Err(e) => {
+                tracing::warn!("Rejecting API key {}: {e}", row.id);
+                return Ok(None);
+            }
+        };
+
+        let game_hex_ids = sqlx::query_scalar!(
+            r#"
+            SELECT game_hex_id
+            FROM api_key_game
+            WHERE api_key_id = ?1
+            "#,
+            row.id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(Some(AuthenticatedKey {
+            id: Some(row.id),
+            scope,
+            game_hex_ids,
+        }))
+    }
+}
+
+impl ImportRepository {
+    /// Import parsed records in a single transaction, rolled back on a dry run
+    ///
+    /// Games are matched by `hex_id`, leaderboards by game and slug, and scores by id;
+    /// original score ids, timestamps and soft-delete markers are kept. Records that cannot be applied are reported and the
+    /// rest of the import carries on.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn import(
+        pool: &SqlitePool,
+        lines: Vec<ImportLine>,
+        mode: ImportMode,
+        dry_run: bool,
+    ) -> Result<ImportReport> {
+        let mut report = ImportReport::new(mode, dry_run);
+        let mut imported_games = std::collections::HashSet::new();
+        let mut created_games = std::collections::HashSet::new();
+        let mut imported_scores = std::collections::HashSet::new();
+
+        let mut tx = pool.begin().await?;
+
+        for ImportLine { line, record } in lines {
+            let record = match record {
+                Ok(record) => record,
+                Err(reason) => {
+                    report.record_issue(line, None, None, ImportIssueKind::Failed, reason);
+                    continue;
+                }
+            };
+            let kind = record.kind();
+            let mut key = record.key();
+
+            let result = match record {
+                ImportRecord::Game(game) => {
+                    let result = Self::import_game(&mut tx, game, mode).await?;
+                    if let (Ok(applied), Some(ref hex_id)) = (&result, &key) {
+                        imported_games.insert(hex_id.to_lowercase());
+                        if matches!(applied, Applied::Created) {
+                            created_games.insert(hex_id.to_lowercase());
+                        }
+                    }
+                    result
+                }
+                ImportRecord::Leaderboard(leaderboard) => {
+                    let game_is_new = created_games.contains(&leaderboard.game_hex_id.to_lowercase());
+                    Self::import_leaderboard(&mut tx, leaderboard, mode, game_is_new).await?
+                }
+                ImportRecord::Score(score) => {
+                    let result = Self::import_score(&mut tx, score, mode).await?;
+                    result.map(|(applied, id)| {
+                        imported_scores.insert(id);
+                        key = Some(id.to_string());
+                        applied
+                    })
+                }
+            };
+
+            match result {
+                Ok(Applied::Created) => report.record_created(kind),
+                Ok(Applied::Updated) => report.record_updated(kind),
+                Err((outcome, reason)) => report.record_issue(line, Some(kind), key, outcome, reason),
+            }
+        }
+
+        if mode == ImportMode::Replace {
+            let now_naive = Utc::now().naive_utc();
+
+            let live_scores = sqlx::query_scalar!(r#"SELECT id AS "id!" FROM score WHERE deleted_at IS NULL"#)
+                .fetch_all(&mut *tx)
+                .await?;
+            for id in live_scores.into_iter().filter(|id| !imported_scores.contains(id)) {
+                sqlx::query!("UPDATE score SET deleted_at = ?1 WHERE id = ?2", now_naive, id)
+                    .execute(&mut *tx)
+                    .await?;
+                report.scores.deleted += 1;
+            }
+
+            let live_games = sqlx::query_scalar!("SELECT hex_id FROM game WHERE deleted_at IS NULL")
+                .fetch_all(&mut *tx)
+                .await?;
+            for hex_id in live_games.into_iter().filter(|hex_id| !imported_games.contains(hex_id)) {
+                sqlx::query!(
+                    "UPDATE game SET deleted_at = ?1, updated_at = ?1 WHERE hex_id = ?2",
+                    now_naive,
+                    hex_id
+                )
+                .execute(&mut *tx)
+                .await?;
+                report.games.deleted += 1;
+            }
+        }
+
+        if dry_run {
+            tx.rollback().await?;
+        } else {
+            tx.commit().await?;
+        }
+
+        Ok(report)
+    }
+
+    async fn import_game(
+        conn: &mut sqlx::SqliteConnection,
+        game: ImportGame,
+        mode: ImportMode,
+    ) -> Result<ImportRowResult> {
+        let hex_id = match Game::normalize_and_validate_hex_id(&game.hex_id) {
+            Ok(hex_id) => hex_id,
+            Err(reason) => return Ok(Err((ImportIssueKind::Failed, reason))),
+        };
+        if let Err(reason) = Game::validate_name(&game.name) {
+            return Ok(Err((ImportIssueKind::Failed, reason)));
+        }
+        if let Err(reason) = game.slug.as_deref().map_or(Ok(()), Game::validate_slug) {
+            return Ok(Err((ImportIssueKind::Failed, reason)));
+        }
+        if let Err(reason) = game.validation.as_ref().map_or(Ok(()), ScoreValidation::validate) {
+            return Ok(Err((ImportIssueKind::Failed, reason)));
+        }
+        // Every `/games/{id}` path must keep naming one game
+        if GameRepository::is_identifier_taken(&mut *conn, &hex_id, Some(&hex_id)).await? {
+            return Ok(Err((
+                ImportIssueKind::Failed,
+                format!("Game id '{hex_id}' is already another game's slug"),
+            )));
+        }
+        if let Some(ref slug) = game.slug {
+            if GameRepository::is_identifier_taken(&mut *conn, slug, Some(&hex_id)).await? {
+                return Ok(Err((
+                    ImportIssueKind::Failed,
+                    format!("Slug '{slug}' is already used by another game"),
+                )));
+            }
+        }
+
+        let existing = sqlx::query_scalar!(r#"SELECT id AS "id!" FROM game WHERE hex_id = ?1"#, hex_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+
+        let created_at = game.created_at.naive_utc();
+        let updated_at = game.updated_at.unwrap_or(game.created_at).naive_utc();
+        let deleted_at = game.deleted_at.map(|dt| dt.naive_utc());
+        let score_order = game.score_order.map(ScoreOrder::as_str);
+        let default_sort_by = game.default_sort_by.as_ref().map(ScoreSortField::as_str);
+        let submission_policy = game.submission_policy.map(SubmissionPolicy::as_str);
+        let validation = game.validation.as_ref();
+        let replace_validation = validation.is_some();
+        let min_score_val = validation.and_then(|v| v.min_score_val);
+        let max_score_val = validation.and_then(|v| v.max_score_val);
+        let integer_only = validation.is_some_and(|v| v.integer_only);
+        let max_submissions_per_minute = validation.and_then(|v| v.max_submissions_per_minute);
+        let extra_schema = validation
+            .and_then(|v| v.extra_schema.as_ref())
+            .map(serde_json::Value::to_string);
+
+        let result = match (existing, mode) {
+            (Some(_), ImportMode::Append) => {
+                return Ok(Err((ImportIssueKind::Skipped, "Game already exists".to_string())));
+            }
+            (Some(_), ImportMode::Merge | ImportMode::Replace) => sqlx::query!(
+                r#"
+                UPDATE game
+                SET name = ?1,
+                    description = ?2,
+                    score_order = COALESCE(?3, score_order),
+                    default_sort_by = COALESCE(?4, default_sort_by),
+                    submission_policy = COALESCE(?5, submission_policy),
+                    min_score_val = CASE WHEN ?6 THEN ?7 ELSE min_score_val END,
+                    max_score_val = CASE WHEN ?6 THEN ?8 ELSE max_score_val END,
+                    integer_only = CASE WHEN ?6 THEN ?9 ELSE integer_only END,
+                    max_submissions_per_minute = CASE WHEN ?6 THEN ?10 ELSE max_submissions_per_minute END,
+                    extra_schema = CASE WHEN ?6 THEN ?11 ELSE extra_schema END,
+                    created_at = ?12,
+                    updated_at = ?13,
+                    deleted_at = ?14,
+                    slug = COALESCE(?16, slug)
+                WHERE hex_id = ?15
+                "#,
+                game.name,
+                game.description,
+                score_order,
+                default_sort_by,
+                submission_policy,
+                replace_validation,
+                min_score_val,
+                max_score_val,
+                integer_only,
+                max_submissions_per_minute,
+                extra_schema,
+                created_at,
+                updated_at,
+                deleted_at,
+                hex_id,
+                game.slug
+            )
+            .execute(&mut *conn)
+            .await
+            .map(|_| Applied::Updated),
+            (None, _) => {
+                let score_order = score_order.unwrap_or(ScoreOrder::default().as_str());
+                let default_sort_by = default_sort_by.unwrap_or(ScoreSortField::default().as_str());
+                let submission_policy =
+                    submission_policy.unwrap_or(SubmissionPolicy::default().as_str());
+                sqlx::query!(
+                    r#"
+                    INSERT INTO game (id, hex_id, name, description, score_order, default_sort_by, submission_policy,
+                        min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema,
+                        created_at, updated_at, deleted_at, slug)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+                    "#,
+                    game.id,
+                    hex_id,
+                    game.name,
+                    game.description,
+                    score_order,
+                    default_sort_by,
+                    submission_policy,
+                    min_score_val,
+                    max_score_val,
+                    integer_only,
+                    max_submissions_per_minute,
+                    extra_schema,
+                    created_at,
+                    updated_at,
+                    deleted_at,
+                    game.slug
+                )
+                .execute(&mut *conn)
+                .await
+                .map(|_| Applied::Created)
+            }
+        };
+
+        match result {
+            Ok(applied) => Ok(Ok(applied)),
+            Err(e) => import_row_failure(e),
+        }
+    }
+
+    /// Applies one leaderboard, matched to the game's boards by slug.
+    ///
+    /// The default board of a game created earlier in the same import already exists, so it
+    /// is updated and reported as created, even when appending.
+    async fn import_leaderboard(
+        conn: &mut sqlx::SqliteConnection,
+        leaderboard: ImportLeaderboard,
+        mode: ImportMode,
+        game_is_new: bool,
+    ) -> Result<ImportRowResult> {
+        let game_hex_id = leaderboard.game_hex_id.to_lowercase();
+        if let Err(reason) = Leaderboard::validate_slug(&leaderboard.slug)
+            .and_then(|()| Leaderboard::validate_name(&leaderboard.name))
+        {
+            return Ok(Err((ImportIssueKind::Failed, reason)));
+        }
+
+        let game_exists = sqlx::query_scalar!(r#"SELECT id AS "id!" FROM game WHERE hex_id = ?1"#, game_hex_id)
+            .fetch_optional(&mut *conn)
+            .await?
+            .is_some();
+        if !game_exists {
+            return Ok(Err((ImportIssueKind::Failed, format!("Unknown game: {game_hex_id}"))));
+        }
+
+        let existing = sqlx::query_scalar!(
+            r#"SELECT id AS "id!" FROM leaderboard WHERE game_hex_id = ?1 AND slug = ?2"#,
+            game_hex_id,
+            leaderboard.slug
+        )
+        .fetch_optional(&mut *conn)
+        .await?;
+        let created_at = leaderboard.created_at.map(|dt| dt.naive_utc());
+
+        let result = match existing {
+            Some(_) if mode == ImportMode::Append && !game_is_new => {
+                return Ok(Err((ImportIssueKind::Skipped, "Leaderboard already exists".to_string())));
+            }
+            Some(id) => sqlx::query!(
+                r#"
+                UPDATE leaderboard
+                SET name = ?1, description = ?2, created_at = COALESCE(?3, created_at)
+                WHERE id = ?4
+                "#,
+                leaderboard.name,
+                leaderboard.description,
+                created_at,
+                id
+            )
+            .execute(&mut *conn)
+            .await
+            .map(|_| if game_is_new { Applied::Created } else { Applied::Updated }),
+            None => sqlx::query!(
+                r#"
+                INSERT INTO leaderboard (game_hex_id, slug, name, description, created_at)
+                VALUES (?1, ?2, ?3, ?4, COALESCE(?5, CURRENT_TIMESTAMP))
+                "#,
+                game_hex_id,
+                leaderboard.slug,
+                leaderboard.name,
+                leaderboard.description,
+                created_at
+            )
+            .execute(&mut *conn)
+            .await
+            .map(|_| Applied::Created),
+        };
+
+        match result {
+            Ok(applied) => Ok(Ok(applied)),
+            Err(e) => import_row_failure(e),
+        }
+    }
+
+    /// Applies one score, returning how it was applied and the id it is stored under.
+    ///
+    /// Scores naming a board go on it. Otherwise new scores go on their game's default board,
+    /// and an existing score keeps its board unless it moves to another game. Scores are
+    /// listed under their player's current profile name.
+    async fn import_score(
+        conn: &mut sqlx::SqliteConnection,
+        score: ImportScore,
+        mode: ImportMode,
+    ) -> Result<ImportRowResult<(Applied, i64)>> {
+        let game_hex_id = score.game_hex_id.to_lowercase();
+        if let Err(reason) = Score::validate_user_name(&score.user_name)
+            .and_then(|()| Score::validate_user_id(&score.user_id))
+        {
+            return Ok(Err((ImportIssueKind::Failed, reason)));
+        }
+        let score_val = match score.score_val.or_else(|| score.score.trim().parse().ok()) {
+            Some(score_val) if f64::is_finite(score_val) => score_val,
+            _ => {
+                return Ok(Err((
+                    ImportIssueKind::Failed,
+                    "score_val is required when score is not a number".to_string(),
+                )));
+            }
+        };
+
+        let game_exists = sqlx::query_scalar!(r#"SELECT id AS "id!" FROM game WHERE hex_id = ?1"#, game_hex_id)
+            .fetch_optional(&mut *conn)
+            .await?
+            .is_some();
+        if !game_exists {
+            return Ok(Err((ImportIssueKind::Failed, format!("Unknown game: {game_hex_id}"))));
+        }
+
+        let leaderboard_id = match score.leaderboard {
+            Some(ref slug) => match LeaderboardRepository::id_by_slug(&mut *conn, &game_hex_id, slug).await {
+                Ok(id) => Some(id),
+                Err(ApiError::NotFound) => {
+                    return Ok(Err((
+                        ImportIssueKind::Failed,
+                        format!("Unknown leaderboard: {game_hex_id}/{slug}"),
+                    )));
+                }
+                Err(e) => return Err(e),
+            },
+            None => None,
+        };
+
+        let existing = match score.id {
+            Some(id) => sqlx::query_scalar!(r#"SELECT id AS "id!" FROM score WHERE id = ?1"#, id)
+                .fetch_optional(&mut *conn)
+                .await?,
+            None => None,
+        };
+
+        if existing.is_some() && mode == ImportMode::Append {
+            return Ok(Err((ImportIssueKind::Skipped, "Score already exists".to_string())));
+        }
+
+        // Imported names only name new players; existing profiles keep their current name
+        ensure_player(conn, &score.user_id, &score.user_name, Utc::now().naive_utc()).await?;
+
+        let extra = score.extra.as_ref().map(serde_json::Value::to_string);
+        let submitted_at = score.submitted_at.naive_utc();
+        let deleted_at = score.deleted_at.map(|dt| dt.naive_utc());
+
+        let result = match existing {
+            Some(id) => sqlx::query!(
+                r#"
+                UPDATE score
+                SET game_hex_id = ?1, score = ?2, score_val = ?3, user_id = ?4,
+                    extra = ?5, submitted_at = ?6, deleted_at = ?7,
+                    leaderboard_id = COALESCE(?9, CASE WHEN game_hex_id = ?1 THEN leaderboard_id ELSE (
+                        SELECT id FROM leaderboard WHERE game_hex_id = ?1 AND slug = 'default'
+                    ) END)
+                WHERE id = ?8
+                "#,
+                game_hex_id,
+                score.score,
+                score_val,
+                score.user_id,
+                extra,
+                submitted_at,
+                deleted_at,
+                id,
+                leaderboard_id
+            )
+            .execute(&mut *conn)
+            .await
+            .map(|_| (Applied::Updated, id)),
+            None => sqlx::query_scalar!(
+                r#"
+                INSERT INTO score (id, game_hex_id, leaderboard_id, score, score_val, user_id, extra,
+                    submitted_at, deleted_at)
+                VALUES (?1, ?2, COALESCE(?9, (SELECT id FROM leaderboard WHERE game_hex_id = ?2 AND slug = 'default')),
+                    ?3, ?4, ?5, ?6, ?7, ?8)
+                RETURNING id AS "id!"
+                "#,
+                score.id,
+                game_hex_id,
+                score.score,
+                score_val,
+                score.user_id,
+                extra,
+                submitted_at,
+                deleted_at,
+                leaderboard_id
+            )
+            .fetch_one(&mut *conn)
+            .await
+            .map(|id| (Applied::Created, id)),
+        };
+
+        match result {
+            Ok(applied) => Ok(Ok(applied)),
+            Err(e) => import_row_failure(e),
+        }
+    }
+}
+
+impl PurgeRepository {
+    /// Permanently delete games and scores soft-deleted before `before`.
+    ///
+    /// A purged game takes all of its boards, scores, seasons, archived standings, submission
+    /// records, revoked keys' allow-list entries and the webhooks filtered to it with it. Games still named by an
+    /// active API key's allow-list are kept, since dropping them would widen that key.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn purge(pool: &SqlitePool, before: chrono::DateTime<Utc>) -> Result<PurgeReport> {
+        let before_naive = before.naive_utc();
+        let mut tx = pool.begin().await?;
+
+        let candidates = sqlx::query!(
+            r#"
+            SELECT hex_id,
+                EXISTS (
+                    SELECT 1 FROM api_key_game
+                    JOIN api_key ON api_key.id = api_key_game.api_key_id
+                    WHERE api_key_game.game_hex_id = game.hex_id AND api_key.revoked_at IS NULL
+                ) AS "in_use!: bool"
+            FROM game
+            WHERE deleted_at IS NOT NULL AND deleted_at < ?1
+            "#,
+            before_naive
+        )
+        .fetch_all(&mut *tx)
+        .await?;
+
+        let mut report = PurgeReport {
+            before,
+            games: 0,
+            scores: 0,
+            seasons: 0,
+            games_kept: 0,
+        };
+        for candidate in candidates {
+            if candidate.in_use {
+                report.games_kept += 1;
+                continue;
+            }
+            let hex_id = candidate.hex_id;
+
+            sqlx::query!(
+                "DELETE FROM season_standing WHERE season_id IN (SELECT id FROM season WHERE game_hex_id = ?1)",
+                hex_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            report.seasons += sqlx::query!("DELETE FROM season WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            report.scores += sqlx::query!("DELETE FROM score WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+            sqlx::query!("DELETE FROM leaderboard WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query!("DELETE FROM submission_nonce WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query!("DELETE FROM submission_log WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query!("DELETE FROM api_key_game WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query!(
+                "DELETE FROM webhook_delivery WHERE webhook_id IN (SELECT id FROM webhook WHERE game_hex_id = ?1)",
+                hex_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query!("DELETE FROM webhook WHERE game_hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?;
+            report.games += sqlx::query!("DELETE FROM game WHERE hex_id = ?1", hex_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+        }
+
+        report.scores += sqlx::query!(
+            "DELETE FROM score WHERE deleted_at IS NOT NULL AND deleted_at < ?1",
+            before_naive
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        tx.commit().await?;
+        Ok(report)
+    }
+}
+
+impl PlayerRepository {
+    /// Get a player's profile
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the user ID is invalid.
+    /// Returns `ApiError::NotFound` if the player has no profile.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn get(pool: &SqlitePool, user_id: &str) -> Result<Player> {
+        Score::validate_user_id(user_id)?;
+        Self::find(pool, user_id).await?.ok_or(ApiError::NotFound)
+    }
+
+    async fn find<'c, E>(executor: E, user_id: &str) -> Result<Option<Player>>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let row = sqlx::query!(
+            r#"
+            SELECT user_id AS "user_id!", user_name, avatar_url, metadata, created_at, updated_at
+            FROM player
+            WHERE user_id = ?1
+            "#,
+            user_id
+        )
+        .fetch_optional(executor)
+        .await?;
+
+        Ok(row.map(|row| {
+            Player::from(PlayerRow {
+                user_id: row.user_id,
+                user_name: row.user_name,
+                avatar_url: row.avatar_url,
+                metadata: row.metadata,
+                created_at: row.created_at,
+                updated_at: row.updated_at,
+            })
+        }))
+    }
+
+    /// Update a player's profile
+    ///
+    /// A new `user_name` shows on all of the player's scores at once. Archived season
+    /// standings keep the name the player had when the season closed.
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the user ID, name or avatar URL is invalid.
+    /// Returns `ApiError::NotFound` if the player has no profile.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn update(pool: &SqlitePool, user_id: &str, update_data: UpdatePlayer) -> Result<Player> {
+        Score::validate_user_id(user_id)?;
+        update_data.validate()?;
+
+        let metadata = update_data.metadata.as_ref().map(serde_json::Value::to_string);
+        let now_naive = Utc::now().naive_utc();
+        let mut tx = pool.begin().await?;
+
+        let rows_affected = sqlx::query!(
+            r#"
+            UPDATE player
+            SET user_name = COALESCE(?1, user_name),
+                avatar_url = COALESCE(?2, avatar_url),
+                metadata = COALESCE(?3, metadata),
+                updated_at = ?4
+            WHERE user_id = ?5
+            "#,
+            update_data.user_name,
+            update_data.avatar_url,
+            metadata,
+            now_naive,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+        if rows_affected == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        let player = Self::find(&mut *tx, user_id).await?.ok_or(ApiError::NotFound)?;
+        tx.commit().await?;
+        Ok(player)
+    }
+
+    /// Collect every score and season standing stored for a player, deleted or not
+    ///
+    /// The export is recorded in the audit log against `auth_key`.
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the user ID is invalid.
+    /// Returns `ApiError::NotFound` if nothing is stored for the player.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn export_data(
+        pool: &SqlitePool,
+        user_id: &str,
+        auth_key: &AuthenticatedKey,
+    ) -> Result<PlayerData> {
+        Score::validate_user_id(user_id)?;
+
+        let score_rows = sqlx::query!(
+            r#"
+            SELECT score.id AS "id!", score.game_hex_id, score.leaderboard_id AS "leaderboard_id!", score.score, score.score_val,
+                player.user_name, score.user_id, score.extra, score.submitted_at, score.deleted_at
+            FROM score
+            JOIN player ON player.user_id = score.user_id
+            WHERE score.user_id = ?1
+            ORDER BY score.submitted_at, score.id
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let scores: Vec<Score> = score_rows
+            .into_iter()
+            .map(|row| {
+                Score::from(ScoreRow {
+                    id: row.id,
+                    game_hex_id: row.game_hex_id,
+                    leaderboard_id: row.leaderboard_id,
+                    score: row.score,
+                    score_val: row.score_val,
+                    user_name: row.user_name,
+                    user_id: row.user_id,
+                    extra: row.extra,
+                    submitted_at: row.submitted_at,
+                    deleted_at: row.deleted_at,
+                })
+            })
+            .collect();
+
+        let standing_rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", season_id, leaderboard_id AS "leaderboard_id!", rank, user_id, user_name, score,
+                score_val, submitted_at
+            FROM season_standing
+            WHERE user_id = ?1
+            ORDER BY season_id, id
+            "#,
+            user_id
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let season_standings: Vec<SeasonStanding> = standing_rows
+            .into_iter()
+            .map(|row| {
+                SeasonStanding::from(SeasonStandingRow {
+                    id: row.id,
+                    season_id: row.season_id,
+                    leaderboard_id: row.leaderboard_id,
+                    rank: row.rank,
+                    user_id: row.user_id,
+                    user_name: row.user_name,
+                    score: row.score,
+                    score_val: row.score_val,
+                    submitted_at: row.submitted_at,
+                })
+            })
+            .collect();
+
+        let player = Self::find(pool, user_id).await?;
+        if player.is_none() && scores.is_empty() && season_standings.is_empty() {
+            return Err(ApiError::NotFound);
+        }
+
+        let details = serde_json::json!({
+            "scores": scores.len(),
+            "season_standings": season_standings.len(),
+        });
+        AuditLogRepository::record(pool, AuditAction::PlayerDataExport, user_id, auth_key, &details)
+            .await?;
+
+        Ok(PlayerData {
+            user_id: user_id.to_string(),
+            exported_at: Utc::now(),
+            player,
+            scores,
+            season_standings,
+        })
+    }
+
+    /// Erase a player's profile and scores, and detach them from their archived season standings
+    ///
+    /// Standings are anonymised in either mode so archived ranks stay intact. The
+    /// player's rate-limit history is dropped, and the erasure is recorded in the
+    /// audit log against `auth_key`, all in one transaction.
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the user ID is invalid.
+    /// Returns `ApiError::NotFound` if nothing is stored for the player.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn erase(
+        pool: &SqlitePool,
+        user_id: &str,
+        mode: ErasureMode,
+        auth_key: &AuthenticatedKey,
+    ) -> Result<PlayerErasure> {
+        Score::validate_user_id(user_id)?;
+
+        // One replacement id per erasure keeps the player's entries grouped on the boards
+        let anonymous_id = format!("anonymous-{}", uuid::Uuid::new_v4().simple());
+        let now_naive = Utc::now().naive_utc();
+        let mut tx = pool.begin().await?;
+
+        let season_standings = sqlx::query!(
+            "UPDATE season_standing SET user_id = ?1, user_name = ?2 WHERE user_id = ?3",
+            anonymous_id,
+            ANONYMIZED_USER_NAME,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+
+        let scores = match mode {
+            ErasureMode::Anonymize => {
+                ensure_player(&mut tx, &anonymous_id, ANONYMIZED_USER_NAME, now_naive).await?;
+                sqlx::query!(
+                "UPDATE score SET user_id = ?1, extra = NULL WHERE user_id = ?2",
+                anonymous_id,
+                user_id
+                )
+                .execute(&mut *tx)
+                .await?
+                .rows_affected()
+            }
+            ErasureMode::Delete => sqlx::query!("DELETE FROM score WHERE user_id = ?1", user_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected(),
+        };
+
+        let player_deleted = sqlx::query!("DELETE FROM player WHERE user_id = ?1", user_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected()
+            > 0;
+
+        if !player_deleted && scores == 0 && season_standings == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        sqlx::query!("DELETE FROM submission_log WHERE user_id = ?1", user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let details = serde_json::json!({
+            "mode": mode.as_str(),
+            "scores": scores,
+            "season_standings": season_standings,
+            "player_deleted": player_deleted,
+        });
+        AuditLogRepository::record(&mut *tx, AuditAction::PlayerErasure, user_id, auth_key, &details)
+            .await?;
+
+        tx.commit().await?;
+        Ok(PlayerErasure {
+            user_id: user_id.to_string(),
+            mode,
+            scores,
+            season_standings,
+            player_deleted,
+        })
+    }
+}
+
+impl AuditLogRepository {
+    /// Append an entry to the audit log
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn record<'c, E>(
+        executor: E,
+        action: AuditAction,
+        target: &str,
+        auth_key: &AuthenticatedKey,
+        details: &serde_json::Value,
+    ) -> Result<()>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let action = action.as_str();
+        let details = details.to_string();
+        let now_naive = Utc::now().naive_utc();
+
+        sqlx::query!(
+            "INSERT INTO audit_log (action, target, api_key_id, details, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            action,
+            target,
+            auth_key.id,
+            details,
+            now_naive
+        )
+        .execute(executor)
+        .await?;
+
+        tracing::info!(
+            "Audit: {action} on {target} by {}",
+            auth_key.id.map_or_else(|| "master key".to_string(), |id| format!("API key {id}"))
+        );
+        Ok(())
+    }
+}
+
+impl WebhookRepository {
+    /// Register a webhook with a newly generated signing secret, returned once
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the URL or event filter is invalid, or the game does not exist.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn create(pool: &SqlitePool, create_data: CreateWebhook) -> Result<CreatedWebhook> {
+        Webhook::validate_url(&create_data.url)?;
+        let events = Webhook::events_json(&Webhook::normalize_events(create_data.events)?);
+        if let Some(ref game_hex_id) = create_data.game_hex_id {
+            GameRepository::get_by_hex_id(pool, game_hex_id)
+                .await
+                .map_err(|_| ApiError::ValidationError(format!("Unknown game: {game_hex_id}")))?;
+        }
+
+        let secret = Game::generate_signing_secret();
+        let now_naive = Utc::now().naive_utc();
+
+        let row = sqlx::query!(
+            r#"
+            INSERT INTO webhook (url, events, game_hex_id, secret, created_at, updated_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?5)
+            RETURNING id, url, events, game_hex_id, active, created_at, updated_at
+            "#,
+            create_data.url,
+            events,
+            create_data.game_hex_id,
+            secret,
+            now_naive
+        )
+        .fetch_one(pool)
+        .await?;
+
+        let webhook = Webhook::from(WebhookRow {
+            id: row.id,
+            url: row.url,
+            events: row.events,
+            game_hex_id: row.game_hex_id,
+            active: row.active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        });
+        Ok(CreatedWebhook { webhook, secret })
+    }
+
+    /// List all webhooks, newest first
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn list(pool: &SqlitePool) -> Result<Vec<Webhook>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", url, events, game_hex_id, active, created_at, updated_at
+            FROM webhook
+            ORDER BY created_at DESC, id DESC
+            "#
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                Webhook::from(WebhookRow {
+                    id: row.id,
+                    url: row.url,
+                    events: row.events,
+                    game_hex_id: row.game_hex_id,
+                    active: row.active,
+                    created_at: row.created_at,
+                    updated_at: row.updated_at,
+                })
+            })
+            .collect())
+    }
+
+    /// Get a webhook by id
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if no webhook exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn get(pool: &SqlitePool, id: i64) -> Result<Webhook> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id AS "id!", url, events, game_hex_id, active, created_at, updated_at
+            FROM webhook
+            WHERE id = ?1
+            "#,
+            id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+        Ok(Webhook::from(WebhookRow {
+            id: row.id,
+            url: row.url,
+            events: row.events,
+            game_hex_id: row.game_hex_id,
+            active: row.active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Update a webhook's URL, event filter or active flag
+    ///
+    /// # Errors
+    /// Returns `ApiError::ValidationError` if the URL or event filter is invalid.
+    /// Returns `ApiError::NotFound` if no webhook exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn update(pool: &SqlitePool, id: i64, update_data: UpdateWebhook) -> Result<Webhook> {
+        if let Some(ref url) = update_data.url {
+            Webhook::validate_url(url)?;
+        }
+        let events = update_data
+            .events
+            .map(Webhook::normalize_events)
+            .transpose()?
+            .map(|events| Webhook::events_json(&events));
+        let now_naive = Utc::now().naive_utc();
+
+        let row = sqlx::query!(
+            r#"
+            UPDATE webhook
+            SET url = COALESCE(?1, url),
+                events = COALESCE(?2, events),
+                active = COALESCE(?3, active),
+                updated_at = ?4
+            WHERE id = ?5
+            RETURNING id AS "id!", url, events, game_hex_id, active, created_at, updated_at
+            "#,
+            update_data.url,
+            events,
+            update_data.active,
+            now_naive,
+            id
+        )
+        .fetch_optional(pool)
+        .await?
+        .ok_or(ApiError::NotFound)?;
+
+        Ok(Webhook::from(WebhookRow {
+            id: row.id,
+            url: row.url,
+            events: row.events,
+            game_hex_id: row.game_hex_id,
+            active: row.active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }))
+    }
+
+    /// Delete a webhook along with its delivery log
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if no webhook exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn delete(pool: &SqlitePool, id: i64) -> Result<()> {
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!("DELETE FROM webhook_delivery WHERE webhook_id = ?1", id)
+            .execute(&mut *tx)
+            .await?;
+        let rows_affected = sqlx::query!("DELETE FROM webhook WHERE id = ?1", id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+        if rows_affected == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Generate a new signing secret for a webhook, replacing the old one
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if no webhook exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn rotate_secret(pool: &SqlitePool, id: i64) -> Result<WebhookSecret> {
+        let secret = Game::generate_signing_secret();
+        let now_naive = Utc::now().naive_utc();
+
+        let rows_affected = sqlx::query!(
+            "UPDATE webhook SET secret = ?1, updated_at = ?2 WHERE id = ?3",
+            secret,
+            now_naive,
+            id
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+        if rows_affected == 0 {
+            return Err(ApiError::NotFound);
+        }
+
+        Ok(WebhookSecret {
+            webhook_id: id,
+            secret,
+        })
+    }
+
+    /// List a webhook's most recent deliveries, newest first
+    ///
+    /// # Errors
+    /// Returns `ApiError::NotFound` if no webhook exists with the given id.
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn list_deliveries(
+        pool: &SqlitePool,
+        webhook_id: i64,
+        params: &DeliveryQueryParams,
+    ) -> Result<Vec<WebhookDelivery>> {
+        Self::get(pool, webhook_id).await?;
+
+        let status = params.status.map(DeliveryStatus::as_str);
+        let limit = params.get_limit();
+        let rows = sqlx::query!(
+            r#"
+            SELECT id AS "id!", webhook_id, event, game_hex_id, payload, status, attempts,
+                next_attempt_at, last_attempt_at, response_status, last_error, created_at
+            FROM webhook_delivery
+            WHERE webhook_id = ?1 AND (?2 IS NULL OR status = ?2)
+            ORDER BY id DESC
+            LIMIT ?3
+            "#,
+            webhook_id,
+            status,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                WebhookDelivery::from(WebhookDeliveryRow {
+                    id: row.id,
+                    webhook_id: row.webhook_id,
+                    event: row.event,
+                    game_hex_id: row.game_hex_id,
+                    payload: row.payload,
+                    status: row.status,
+                    attempts: row.attempts,
+                    next_attempt_at: row.next_attempt_at,
+                    last_attempt_at: row.last_attempt_at,
+                    response_status: row.response_status,
+                    last_error: row.last_error,
+                    created_at: row.created_at,
+                })
+            })
+            .collect())
+    }
+
+    /// Whether any active webhook subscribes to `event` for a game
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn is_watched<'c, E>(executor: E, game_hex_id: &str, event: WebhookEvent) -> Result<bool>
+    where
+        E: sqlx::Executor<'c, Database = sqlx::Sqlite>,
+    {
+        let event = event.as_str();
+        let watched = sqlx::query_scalar!(
+            r#"
+            SELECT EXISTS (
+                SELECT 1 FROM webhook
+                WHERE active AND (game_hex_id IS NULL OR game_hex_id = ?1)
+                    AND EXISTS (SELECT 1 FROM json_each(webhook.events) WHERE value = ?2)
+            ) AS "watched!: bool"
+            "#,
+            game_hex_id,
+            event
+        )
+        .fetch_one(executor)
+        .await?;
+        Ok(watched)
+    }
+
+    /// Queue a delivery of `event` to every active webhook subscribed to it, returning how many
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn enqueue(
+        pool: &SqlitePool,
+        event: WebhookEvent,
+        game_hex_id: &str,
+        data: &serde_json::Value,
+    ) -> Result<u64> {
+        let event = event.as_str();
+        let payload = data.to_string();
+        let now_naive = Utc::now().naive_utc();
+
+        let queued = sqlx::query!(
+            r#"
+            INSERT INTO webhook_delivery (webhook_id, event, game_hex_id, payload, next_attempt_at, created_at)
+            SELECT id, ?1, ?2, ?3, ?4, ?4
+            FROM webhook
+            WHERE active AND (game_hex_id IS NULL OR game_hex_id = ?2)
+                AND EXISTS (SELECT 1 FROM json_each(webhook.events) WHERE value = ?1)
+            "#,
+            event,
+            game_hex_id,
+            payload,
+            now_naive
+        )
+        .execute(pool)
+        .await?
+        .rows_affected();
+        Ok(queued)
+    }
+
+    /// Pending deliveries of active webhooks due by `now`, oldest first
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn due_deliveries(
+        pool: &SqlitePool,
+        now: chrono::DateTime<Utc>,
+        limit: u32,
+    ) -> Result<Vec<DueDelivery>> {
+        let now_naive = now.naive_utc();
+        let rows = sqlx::query!(
+            r#"
+            SELECT webhook_delivery.id AS "id!", webhook_delivery.webhook_id, webhook_delivery.event,
+                webhook_delivery.game_hex_id, webhook_delivery.payload, webhook_delivery.status,
+                webhook_delivery.attempts, webhook_delivery.next_attempt_at,
+                webhook_delivery.last_attempt_at, webhook_delivery.response_status,
+                webhook_delivery.last_error, webhook_delivery.created_at,
+                webhook.url, webhook.secret
+            FROM webhook_delivery
+            JOIN webhook ON webhook.id = webhook_delivery.webhook_id
+            WHERE webhook_delivery.status = 'pending' AND webhook_delivery.next_attempt_at <= ?1
+                AND webhook.active
+            ORDER BY webhook_delivery.next_attempt_at, webhook_delivery.id
+            LIMIT ?2
+            "#,
+            now_naive,
+            limit
+        )
+        .fetch_all(pool)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| DueDelivery {
+                delivery: WebhookDelivery::from(WebhookDeliveryRow {
+                    id: row.id,
+                    webhook_id: row.webhook_id,
+                    event: row.event,
+                    game_hex_id: row.game_hex_id,
+                    payload: row.payload,
+                    status: row.status,
+                    attempts: row.attempts,
+                    next_attempt_at: row.next_attempt_at,
+                    last_attempt_at: row.last_attempt_at,
+                    response_status: row.response_status,
+                    last_error: row.last_error,
+                    created_at: row.created_at,
+                }),
+                url: row.url,
+                secret: row.secret,
+            })
+            .collect())
+    }
+
+    /// Record an attempt at a delivery, scheduling a retry with exponential backoff if it failed
+    ///
+    /// A delivery that fails `WEBHOOK_MAX_ATTEMPTS` times is marked failed and not retried.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn record_attempt(
+        pool: &SqlitePool,
+        delivery: &WebhookDelivery,
+        attempt: &DeliveryAttempt,
+        now: chrono::DateTime<Utc>,
+    ) -> Result<()> {
+        let attempts = delivery.attempts + 1;
+        let (status, next_attempt_at) = if attempt.succeeded() {
+            (DeliveryStatus::Succeeded, None)
+        } else if attempts >= WEBHOOK_MAX_ATTEMPTS {
+            (DeliveryStatus::Failed, None)
+        } else {
+            (DeliveryStatus::Pending, Some((now + retry_delay(attempts)).naive_utc()))
+        };
+        let status = status.as_str();
+        let response_status = attempt.response_status.map(i64::from);
+        let now_naive = now.naive_utc();
+
+        sqlx::query!(
+            r#"
+            UPDATE webhook_delivery
+            SET status = ?1, attempts = ?2, next_attempt_at = ?3, last_attempt_at = ?4,
+                response_status = ?5, last_error = ?6
+            WHERE id = ?7
+            "#,
+            status,
+            attempts,
+            next_attempt_at,
+            now_naive,
+            response_status,
+            attempt.error,
+            delivery.id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+}
+
+impl IdempotencyRepository {
+    /// Claim an idempotency key for a request, or find what became of an earlier request
+    /// that used it.
+    ///
+    /// Expired keys are pruned first, so a key can be reused once its TTL has passed. A key
+    /// held longer than `IDEMPOTENCY_LOCK_SECS` by a request that never completed is taken over.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn claim(
+        pool: &SqlitePool,
+        api_key_id: Option<i64>,
+        endpoint: &str,
+        idempotency_key: &str,
+        request_hash: &str,
+        now: chrono::DateTime<Utc>,
+        ttl: chrono::Duration,
+    ) -> Result<IdempotencyClaim> {
+        let now_naive = now.naive_utc();
+        let expires_at = (now + ttl).naive_utc();
+        let mut tx = pool.begin().await?;
+
+        sqlx::query!("DELETE FROM idempotency_key WHERE expires_at <= ?1", now_naive)
+            .execute(&mut *tx)
+            .await?;
+
+        let claimed = sqlx::query!(
+            r#"
+            INSERT INTO idempotency_key (api_key_id, endpoint, idempotency_key, request_hash, created_at, expires_at)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+            ON CONFLICT DO NOTHING
+            RETURNING id AS "id!"
+            "#,
+            api_key_id,
+            endpoint,
+            idempotency_key,
+            request_hash,
+            now_naive,
+            expires_at
+        )
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let claim = if let Some(row) = claimed {
+            IdempotencyClaim::Claimed(row.id)
+        } else {
+            let existing = sqlx::query!(
+                r#"
+                SELECT id AS "id!", request_hash, response_status, response_body,
+                       created_at AS "created_at: chrono::NaiveDateTime"
+                FROM idempotency_key
+                WHERE api_key_id IS ?1 AND endpoint = ?2 AND idempotency_key = ?3
+                "#,
+                api_key_id,
+                endpoint,
+                idempotency_key
+            )
+            .fetch_one(&mut *tx)
+            .await?;
+
+            match (existing.response_status, existing.response_body) {
+                _ if existing.request_hash != request_hash => IdempotencyClaim::Mismatch,
+                (Some(status), Some(body)) => IdempotencyClaim::Replay(StoredResponse {
+                    status: u16::try_from(status).map_err(|_| ApiError::InternalServerError)?,
+                    body,
+                }),
+                _ if existing.created_at
+                    < now_naive - chrono::Duration::seconds(IDEMPOTENCY_LOCK_SECS) =>
+                {
+                    sqlx::query!(
+                        "UPDATE idempotency_key SET created_at = ?1, expires_at = ?2 WHERE id = ?3",
+                        now_naive,
+                        expires_at,
+                        existing.id
+                    )
+                    .execute(&mut *tx)
+                    .await?;
+                    IdempotencyClaim::Claimed(existing.id)
+                }
+                _ => IdempotencyClaim::InProgress,
+            }
+        };
+
+        tx.commit().await?;
+        Ok(claim)
+    }
+
+    /// Save the response of the request holding a claimed key, so retries replay it.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn complete(pool: &SqlitePool, id: i64, response: &StoredResponse) -> Result<()> {
+        let status = i64::from(response.status);
+        sqlx::query!(
+            "UPDATE idempotency_key SET response_status = ?1, response_body = ?2 WHERE id = ?3",
+            status,
+            response.body,
+            id
+        )
+        .execute(pool)
+        .await?;
+        Ok(())
+    }
+
+    /// Give up a claimed key without saving a response, so a retry runs the request again.
+    ///
+    /// # Errors
+    /// Returns `ApiError::DatabaseError` if the database operation fails.
+    pub async fn release(pool: &SqlitePool, id: i64) -> Result<()> {
+        sqlx::query!("DELETE FROM idempotency_key WHERE id = ?1", id)
+            .execute(pool)
+            .await?;
+        Ok(())
+    }
+}
@@ -14,12 +14,21 @@ pub enum ApiError {
     #[error("Not found")]
     NotFound,
 
+    #[error("Unauthorized: {0}")]
+    Unauthorized(String),
+
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
+
     #[error("Bad request: {0}")]
     BadRequest(String),
 
     #[error("Internal server error")]
     InternalServerError,
 
+    #[error("Conflict: {0}")]
+    Conflict(String),
+
     #[error("Validation error: {0}")]
     ValidationError(String),
 
@@ -27,21 +36,31 @@ pub enum ApiError {
     InvalidParameter(String),
 }
 
-impl IntoResponse for ApiError {
-    fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            ApiError::Database(err) => {
-                tracing::error!("Database error: {:?}", err);
+impl ApiError {
+    /// The HTTP status and client-facing message the error is answered with.
+    #[must_use]
+    pub fn status_and_message(&self) -> (StatusCode, &str) {
+        match self {
+            ApiError::Database(_) | ApiError::InternalServerError => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
             }
             ApiError::NotFound => (StatusCode::NOT_FOUND, "Not found"),
-            ApiError::BadRequest(ref msg) => (StatusCode::BAD_REQUEST, msg.as_str()),
-            ApiError::InternalServerError => {
-                (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
-            }
-            ApiError::ValidationError(ref msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.as_str()),
-            ApiError::InvalidParameter(ref msg) => (StatusCode::BAD_REQUEST, msg.as_str()),
-        };
+            ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg.as_str()),
+            ApiError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg.as_str()),
+            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg.as_str()),
+            ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg.as_str()),
+            ApiError::ValidationError(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg.as_str()),
+            ApiError::InvalidParameter(msg) => (StatusCode::BAD_REQUEST, msg.as_str()),
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        if let ApiError::Database(ref err) = self {
+            tracing::error!("Database error: {:?}", err);
+        }
+        let (status, error_message) = self.status_and_message();
 
         let body = Json(json!({
             "error": error_message
@@ -0,0 +1,44 @@
+use tokio::sync::broadcast;
+
+use crate::models::ScoreEvent;
+
+/// Events buffered per subscriber before the slowest ones start missing events.
+pub const EVENT_CHANNEL_CAPACITY: usize = 256;
+
+/// In-process fan-out of score events to stream subscribers.
+///
+/// Cloning is cheap and every clone publishes to the same subscribers.
+#[derive(Debug, Clone)]
+pub struct ScoreEvents {
+    sender: broadcast::Sender<ScoreEvent>,
+}
+
+impl ScoreEvents {
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    #[must_use]
+    pub fn subscribe(&self) -> broadcast::Receiver<ScoreEvent> {
+        self.sender.subscribe()
+    }
+
+    /// Whether anyone is listening; publishers skip extra work when nobody is.
+    #[must_use]
+    pub fn has_subscribers(&self) -> bool {
+        self.sender.receiver_count() > 0
+    }
+
+    /// Sends an event to every current subscriber. Events published with none are dropped.
+    pub fn publish(&self, event: ScoreEvent) {
+        let _ = self.sender.send(event);
+    }
+}
+
+impl Default for ScoreEvents {
+    fn default() -> Self {
+        Self::new(EVENT_CHANNEL_CAPACITY)
+    }
+}
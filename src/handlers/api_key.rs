@@ -0,0 +1,109 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{repository::ApiKeyRepository, DbPool},
+    error::ApiError,
+    models::api_key::CreateApiKey,
+};
+
+/// Creates a new scoped API key.
+///
+/// The plaintext key is only returned in this response; only its hash is stored.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if the key name is invalid.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/api-keys",
+    request_body = CreateApiKey,
+    responses(
+        (status = 201, description = "API key created successfully", body = CreatedApiKey),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 422, description = "Invalid API key data"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "API Keys"
+)]
+pub async fn create_api_key(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Json(create_data): Json<CreateApiKey>,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_key.check_unrestricted("manage API keys")?;
+    let created = ApiKeyRepository::create(&pool, create_data).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// Lists all API keys, including revoked ones.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/api-keys",
+    responses(
+        (status = 200, description = "List of API keys", body = Vec<ApiKey>),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "API Keys"
+)]
+pub async fn list_api_keys(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_key.check_unrestricted("manage API keys")?;
+    let api_keys = ApiKeyRepository::list(&pool).await?;
+    Ok(Json(api_keys))
+}
+
+/// Revokes an API key so it can no longer authenticate.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::NotFound` if no active key exists with the given ID.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    delete,
+    path = "/api-keys/{id}",
+    params(
+        ("id" = i64, Path, description = "API key ID")
+    ),
+    responses(
+        (status = 204, description = "API key revoked successfully"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 404, description = "API key not found or already revoked"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "API Keys"
+)]
+pub async fn revoke_api_key(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_key.check_unrestricted("manage API keys")?;
+    ApiKeyRepository::revoke(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
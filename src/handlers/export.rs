@@ -1,12 +1,25 @@
 use axum::{
-    extract::State,
+    body::Body,
+    extract::{Extension, RawQuery, State},
     http::{header, HeaderMap, HeaderValue, StatusCode},
     response::IntoResponse,
 };
-use serde::Serialize;
-use sqlx::Row;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{sqlite::SqliteRow, FromRow, Row};
+use tokio::{io::AsyncReadExt, sync::mpsc};
+use tokio_stream::{wrappers::ReceiverStream, StreamExt};
+use utoipa::{IntoParams, ToSchema};
 
-use crate::{db::DbPool, error::ApiError};
+use crate::{
+    auth::AuthenticatedKey,
+    db::DbPool,
+    error::ApiError,
+    models::{Game, GameRow, Leaderboard, LeaderboardRow, Score, ScoreRow},
+};
+
+/// Buffered CSV bytes are sent to the client once they pass this size.
+const EXPORT_CHUNK_BYTES: usize = 64 * 1024;
 
 #[derive(Debug, Serialize)]
 struct ExportRow {
@@ -17,117 +30,459 @@ struct ExportRow {
     game_created_at: String,
     game_updated_at: String,
     game_deleted_at: Option<String>,
-    
-    // Score fields  
-    score_id: i64,
-    score_value: String,
-    score_val: f64,
-    user_name: String,
-    user_id: String,
-    extra: String, // JSON as string
-    score_submitted_at: String,
-    score_updated_at: String,
+
+    // Leaderboard fields
+    leaderboard_slug: Option<String>,
+    leaderboard_name: Option<String>,
+    leaderboard_description: Option<String>,
+    leaderboard_created_at: Option<String>,
+
+    // Score fields - empty for boards without scores
+    score_id: Option<i64>,
+    score_value: Option<String>,
+    score_val: Option<f64>,
+    user_name: Option<String>,
+    user_id: Option<String>,
+    extra: Option<String>, // JSON as string
+    score_submitted_at: Option<String>,
     score_deleted_at: Option<String>,
 }
 
-/// Exports all game and score data as a CSV file for backup purposes.
-/// Returns denormalized data with one row per score, including all game information.
-/// 
-/// # Errors
-/// Returns `ApiError::Database` if the database query fails.
-/// Returns `ApiError::ValidationError` if CSV serialization fails.
-#[utoipa::path(
-    get,
-    path = "/export",
-    responses(
-        (status = 200, description = "CSV file with all data", content_type = "text/csv"),
-        (status = 401, description = "Missing or invalid API key"),
-        (status = 500, description = "Internal server error")
-    ),
-    security(
-        ("api_key" = [])
-    ),
-    tag = "Export"
-)]
-pub async fn export_data(State(pool): State<DbPool>) -> Result<impl IntoResponse, ApiError> {
-    // Query to get denormalized game-score data (including soft-deleted records for complete backup)
-    let rows = sqlx::query(
+/// Header row of the CSV export, matching the fields of `ExportRow`.
+const EXPORT_COLUMNS: [&str; 18] = [
+    "game_hex_id",
+    "game_name",
+    "game_description",
+    "game_created_at",
+    "game_updated_at",
+    "game_deleted_at",
+    "leaderboard_slug",
+    "leaderboard_name",
+    "leaderboard_description",
+    "leaderboard_created_at",
+    "score_id",
+    "score_value",
+    "score_val",
+    "user_name",
+    "user_id",
+    "extra",
+    "score_submitted_at",
+    "score_deleted_at",
+];
+
+/// File format of an export.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum ExportFormat {
+    /// One denormalized row per score, and per leaderboard without scores.
+    #[serde(rename = "csv")]
+    #[default]
+    Csv,
+    /// One typed JSON object per line: every game, then every leaderboard, then every score.
+    #[serde(rename = "jsonl")]
+    Jsonl,
+    /// A consistent copy of the whole database file.
+    #[serde(rename = "sqlite")]
+    Sqlite,
+}
+
+impl ExportFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "text/csv",
+            ExportFormat::Jsonl => "application/x-ndjson",
+            ExportFormat::Sqlite => "application/vnd.sqlite3",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Csv => "csv",
+            ExportFormat::Jsonl => "jsonl",
+            ExportFormat::Sqlite => "db",
+        }
+    }
+}
+
+/// A line of a JSON Lines export.
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ExportRecord {
+    Game(Game),
+    Leaderboard(Leaderboard),
+    Score(ExportScore),
+}
+
+/// A score line of a JSON Lines export. Boards are named by slug, as their ids are not
+/// kept by an import.
+#[derive(Debug, Serialize)]
+struct ExportScore {
+    #[serde(flatten)]
+    score: Score,
+    leaderboard: String,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportQueryParams {
+    /// Export format (default: csv). `sqlite` always snapshots the whole database.
+    pub format: Option<ExportFormat>,
+    /// Only export this game and its scores.
+    pub game_hex_id: Option<String>,
+    /// Only export scores submitted at or after this time, plus games updated since then.
+    pub since: Option<DateTime<Utc>>,
+    /// Include soft-deleted games and scores (default: true, for complete backups).
+    pub include_deleted: Option<bool>,
+}
+
+fn to_rfc3339(naive: NaiveDateTime) -> String {
+    DateTime::<Utc>::from_naive_utc_and_offset(naive, Utc).to_rfc3339()
+}
+
+impl ExportRow {
+    fn from_row(row: &SqliteRow) -> Result<Self, sqlx::Error> {
+        Ok(Self {
+            game_hex_id: row.try_get("game_hex_id")?,
+            game_name: row.try_get("game_name")?,
+            game_description: row.try_get("game_description")?,
+            game_created_at: to_rfc3339(row.try_get("game_created_at")?),
+            game_updated_at: to_rfc3339(row.try_get("game_updated_at")?),
+            game_deleted_at: row
+                .try_get::<Option<NaiveDateTime>, _>("game_deleted_at")?
+                .map(to_rfc3339),
+            leaderboard_slug: row.try_get("leaderboard_slug")?,
+            leaderboard_name: row.try_get("leaderboard_name")?,
+            leaderboard_description: row.try_get("leaderboard_description")?,
+            leaderboard_created_at: row
+                .try_get::<Option<NaiveDateTime>, _>("leaderboard_created_at")?
+                .map(to_rfc3339),
+            score_id: row.try_get("score_id")?,
+            score_value: row.try_get("score_value")?,
+            score_val: row.try_get("score_val")?,
+            user_name: row.try_get("user_name")?,
+            user_id: row.try_get("user_id")?,
+            extra: row.try_get("extra")?,
+            score_submitted_at: row
+                .try_get::<Option<NaiveDateTime>, _>("score_submitted_at")?
+                .map(to_rfc3339),
+            score_deleted_at: row
+                .try_get::<Option<NaiveDateTime>, _>("score_deleted_at")?
+                .map(to_rfc3339),
+        })
+    }
+}
+
+/// A header-less CSV writer buffering one chunk of the export.
+fn csv_chunk_writer() -> csv::Writer<Vec<u8>> {
+    csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(Vec::with_capacity(EXPORT_CHUNK_BYTES))
+}
+
+/// Runs the export query and sends the CSV to `tx` in chunks as rows arrive.
+async fn stream_csv(
+    pool: DbPool,
+    params: ExportQueryParams,
+    tx: mpsc::Sender<Result<Vec<u8>, std::io::Error>>,
+) -> Result<(), std::io::Error> {
+    let include_deleted = params.include_deleted.unwrap_or(true);
+    let since = params.since.map(|dt| dt.naive_utc());
+
+    let mut rows = sqlx::query(
         r#"
-        SELECT 
+        SELECT
             g.hex_id as game_hex_id,
             g.name as game_name,
             g.description as game_description,
             g.created_at as game_created_at,
             g.updated_at as game_updated_at,
             g.deleted_at as game_deleted_at,
+            l.slug as leaderboard_slug,
+            l.name as leaderboard_name,
+            l.description as leaderboard_description,
+            l.created_at as leaderboard_created_at,
             s.id as score_id,
             s.score as score_value,
             s.score_val,
-            s.user_name,
+            p.user_name,
             s.user_id,
             s.extra,
             s.submitted_at as score_submitted_at,
-            s.updated_at as score_updated_at,
             s.deleted_at as score_deleted_at
-        FROM games g
-        LEFT JOIN scores s ON g.hex_id = s.game_hex_id
-        ORDER BY g.created_at, s.submitted_at
-        "#
+        FROM game g
+        LEFT JOIN leaderboard l ON g.hex_id = l.game_hex_id
+        LEFT JOIN score s ON l.id = s.leaderboard_id
+            AND (?2 IS NULL OR s.submitted_at >= ?2)
+            AND (?3 OR s.deleted_at IS NULL)
+        LEFT JOIN player p ON p.user_id = s.user_id
+        WHERE (?1 IS NULL OR g.hex_id = ?1)
+            AND (?3 OR g.deleted_at IS NULL)
+            AND (?2 IS NULL OR s.id IS NOT NULL OR g.updated_at >= ?2)
+        ORDER BY g.created_at, g.hex_id, l.id, s.submitted_at, s.id
+        "#,
     )
-    .fetch_all(&pool)
-    .await?;
-
-    // Convert to CSV
-    let mut csv_output = Vec::new();
-    let mut writer = csv::Writer::from_writer(&mut csv_output);
-
-    // Write all rows
-    for row in rows {
-        let export_row = ExportRow {
-            game_hex_id: row.get("game_hex_id"),
-            game_name: row.get("game_name"),
-            game_description: row.get("game_description"),
-            game_created_at: row.get::<chrono::DateTime<chrono::Utc>, _>("game_created_at").to_rfc3339(),
-            game_updated_at: row.get::<chrono::DateTime<chrono::Utc>, _>("game_updated_at").to_rfc3339(),
-            game_deleted_at: row.get::<Option<chrono::DateTime<chrono::Utc>>, _>("game_deleted_at").map(|dt| dt.to_rfc3339()),
-            score_id: row.get::<Option<i64>, _>("score_id").unwrap_or(0),
-            score_value: row.get::<Option<String>, _>("score_value").unwrap_or_default(),
-            score_val: row.get::<Option<f64>, _>("score_val").unwrap_or(0.0),
-            user_name: row.get::<Option<String>, _>("user_name").unwrap_or_default(),
-            user_id: row.get::<Option<String>, _>("user_id").unwrap_or_default(),
-            extra: row.get::<Option<String>, _>("extra").unwrap_or_default(),
-            score_submitted_at: row.get::<Option<chrono::DateTime<chrono::Utc>>, _>("score_submitted_at").map(|dt| dt.to_rfc3339()).unwrap_or_default(),
-            score_updated_at: row.get::<Option<chrono::DateTime<chrono::Utc>>, _>("score_updated_at").map(|dt| dt.to_rfc3339()).unwrap_or_default(),
-            score_deleted_at: row.get::<Option<chrono::DateTime<chrono::Utc>>, _>("score_deleted_at").map(|dt| dt.to_rfc3339()),
-        };
-        
-        writer.serialize(&export_row).map_err(|e| {
-            ApiError::ValidationError(format!("Failed to serialize CSV row: {e}"))
-        })?;
+    .bind(params.game_hex_id)
+    .bind(since)
+    .bind(include_deleted)
+    .fetch(&pool);
+
+    let mut writer = csv_chunk_writer();
+    writer.write_record(EXPORT_COLUMNS)?;
+
+    while let Some(row) = rows.next().await {
+        let row = row.map_err(std::io::Error::other)?;
+        let export_row = ExportRow::from_row(&row).map_err(std::io::Error::other)?;
+        writer.serialize(&export_row)?;
+
+        if writer.get_ref().len() >= EXPORT_CHUNK_BYTES {
+            let chunk = std::mem::replace(&mut writer, csv_chunk_writer())
+                .into_inner()
+                .map_err(|e| std::io::Error::other(e.to_string()))?;
+            if tx.send(Ok(chunk)).await.is_err() {
+                // Client went away; stop reading rows
+                return Ok(());
+            }
+        }
     }
 
-    writer.flush().map_err(|e| {
-        ApiError::ValidationError(format!("Failed to flush CSV writer: {e}"))
-    })?;
+    let chunk = writer
+        .into_inner()
+        .map_err(|e| std::io::Error::other(e.to_string()))?;
+    let _ = tx.send(Ok(chunk)).await;
+    Ok(())
+}
+
+/// Runs the export queries and sends one JSON object per game, then per leaderboard, then
+/// per score, to `tx`.
+async fn stream_jsonl(
+    pool: DbPool,
+    params: ExportQueryParams,
+    tx: mpsc::Sender<Result<Vec<u8>, std::io::Error>>,
+) -> Result<(), std::io::Error> {
+    let include_deleted = params.include_deleted.unwrap_or(true);
+    let since = params.since.map(|dt| dt.naive_utc());
+    let mut chunk = Vec::with_capacity(EXPORT_CHUNK_BYTES);
+
+    // Same selection as the CSV export: games with matching scores, or updated since `since`
+    let mut games = sqlx::query_as::<_, GameRow>(
+        r#"
+        SELECT id, hex_id, slug, name, description, score_order, default_sort_by, submission_policy,
+            min_score_val, max_score_val, integer_only, max_submissions_per_minute, extra_schema,
+            created_at, updated_at, deleted_at
+        FROM game g
+        WHERE (?1 IS NULL OR g.hex_id = ?1)
+            AND (?3 OR g.deleted_at IS NULL)
+            AND (?2 IS NULL OR g.updated_at >= ?2 OR EXISTS (
+                SELECT 1 FROM score s
+                WHERE s.game_hex_id = g.hex_id AND s.submitted_at >= ?2 AND (?3 OR s.deleted_at IS NULL)
+            ))
+        ORDER BY g.created_at, g.hex_id
+        "#,
+    )
+    .bind(&params.game_hex_id)
+    .bind(since)
+    .bind(include_deleted)
+    .fetch(&pool);
+
+    while let Some(row) = games.next().await {
+        let record = ExportRecord::Game(Game::from(row.map_err(std::io::Error::other)?));
+        serde_json::to_writer(&mut chunk, &record)?;
+        chunk.push(b'\n');
+        if chunk.len() >= EXPORT_CHUNK_BYTES && tx.send(Ok(std::mem::take(&mut chunk))).await.is_err() {
+            return Ok(());
+        }
+    }
+    drop(games);
+
+    // Every board of the exported games, so scores can be put back on them
+    let mut leaderboards = sqlx::query_as::<_, LeaderboardRow>(
+        r#"
+        SELECT l.id, l.game_hex_id, l.slug, l.name, l.description, l.created_at,
+            (
+                SELECT COUNT(*) FROM score s
+                WHERE s.leaderboard_id = l.id AND s.deleted_at IS NULL
+            ) AS score_count
+        FROM leaderboard l
+        JOIN game g ON g.hex_id = l.game_hex_id
+        WHERE (?1 IS NULL OR g.hex_id = ?1)
+            AND (?3 OR g.deleted_at IS NULL)
+            AND (?2 IS NULL OR g.updated_at >= ?2 OR EXISTS (
+                SELECT 1 FROM score s
+                WHERE s.game_hex_id = g.hex_id AND s.submitted_at >= ?2 AND (?3 OR s.deleted_at IS NULL)
+            ))
+        ORDER BY g.created_at, g.hex_id, l.id
+        "#,
+    )
+    .bind(&params.game_hex_id)
+    .bind(since)
+    .bind(include_deleted)
+    .fetch(&pool);
+
+    while let Some(row) = leaderboards.next().await {
+        let record = ExportRecord::Leaderboard(Leaderboard::from(row.map_err(std::io::Error::other)?));
+        serde_json::to_writer(&mut chunk, &record)?;
+        chunk.push(b'\n');
+        if chunk.len() >= EXPORT_CHUNK_BYTES && tx.send(Ok(std::mem::take(&mut chunk))).await.is_err() {
+            return Ok(());
+        }
+    }
+    drop(leaderboards);
 
-    // Drop the writer to release the borrow on csv_output
-    drop(writer);
+    let mut scores = sqlx::query(
+        r#"
+        SELECT s.id, s.game_hex_id, s.leaderboard_id, s.score, s.score_val, p.user_name, s.user_id, s.extra,
+            s.submitted_at, s.deleted_at, l.slug AS leaderboard
+        FROM score s
+        JOIN game g ON g.hex_id = s.game_hex_id
+        JOIN leaderboard l ON l.id = s.leaderboard_id
+        JOIN player p ON p.user_id = s.user_id
+        WHERE (?1 IS NULL OR s.game_hex_id = ?1)
+            AND (?2 IS NULL OR s.submitted_at >= ?2)
+            AND (?3 OR (s.deleted_at IS NULL AND g.deleted_at IS NULL))
+        ORDER BY g.created_at, g.hex_id, s.submitted_at, s.id
+        "#,
+    )
+    .bind(&params.game_hex_id)
+    .bind(since)
+    .bind(include_deleted)
+    .fetch(&pool);
+
+    while let Some(row) = scores.next().await {
+        let row = row.map_err(std::io::Error::other)?;
+        let record = ExportRecord::Score(ExportScore {
+            score: Score::from(ScoreRow::from_row(&row).map_err(std::io::Error::other)?),
+            leaderboard: row.try_get("leaderboard").map_err(std::io::Error::other)?,
+        });
+        serde_json::to_writer(&mut chunk, &record)?;
+        chunk.push(b'\n');
+        if chunk.len() >= EXPORT_CHUNK_BYTES && tx.send(Ok(std::mem::take(&mut chunk))).await.is_err() {
+            return Ok(());
+        }
+    }
+
+    let _ = tx.send(Ok(chunk)).await;
+    Ok(())
+}
+
+/// Sends the snapshot file at `path` to `tx` in chunks, removing it afterwards.
+async fn stream_snapshot(
+    path: std::path::PathBuf,
+    tx: mpsc::Sender<Result<Vec<u8>, std::io::Error>>,
+) -> Result<(), std::io::Error> {
+    let result = async {
+        let mut file = tokio::fs::File::open(&path).await?;
+        loop {
+            let mut chunk = Vec::with_capacity(EXPORT_CHUNK_BYTES);
+            let read = (&mut file)
+                .take(EXPORT_CHUNK_BYTES as u64)
+                .read_to_end(&mut chunk)
+                .await?;
+            if read == 0 || tx.send(Ok(chunk)).await.is_err() {
+                return Ok(());
+            }
+        }
+    }
+    .await;
+
+    if let Err(e) = tokio::fs::remove_file(&path).await {
+        tracing::warn!("Failed to remove export snapshot {}: {e}", path.display());
+    }
+    result
+}
+
+/// Exports game and score data for backup purposes.
+///
+/// `csv` (the default) returns denormalized data with one row per score, including all game
+/// information. `jsonl` returns one typed JSON object per game and per score. `sqlite`
+/// returns a consistent `VACUUM INTO` snapshot of the whole database.
+///
+/// Data is streamed as it is read, so memory use does not grow with the size of the export.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if the query parameters are invalid, including
+/// filters combined with the `sqlite` format.
+/// Returns `ApiError::InvalidParameter` if the `game_hex_id` format is invalid.
+/// A database failure after streaming has started aborts the response body.
+#[utoipa::path(
+    get,
+    path = "/export",
+    params(
+        ExportQueryParams
+    ),
+    responses(
+        (status = 200, description = "File with the exported data", content_type = ["text/csv", "application/x-ndjson", "application/vnd.sqlite3"]),
+        (status = 400, description = "Invalid game_hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 422, description = "Invalid query parameters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Export"
+)]
+pub async fn export_data(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_key.check_unrestricted("export data")?;
+    let params = serde_urlencoded::from_str::<ExportQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+    if let Some(ref game_hex_id) = params.game_hex_id {
+        Game::validate_hex_id(game_hex_id).map_err(ApiError::InvalidParameter)?;
+    }
+
+    let format = params.format.unwrap_or_default();
+
+    // A snapshot is taken before responding so its errors can still be reported
+    let snapshot = if format == ExportFormat::Sqlite {
+        if params.game_hex_id.is_some() || params.since.is_some() || params.include_deleted.is_some() {
+            return Err(ApiError::ValidationError(
+                "The sqlite format always exports the whole database and takes no filters"
+                    .to_string(),
+            ));
+        }
+
+        let path = std::env::temp_dir().join(format!("leadr_export_{}.db", uuid::Uuid::new_v4()));
+        sqlx::query("VACUUM INTO ?1")
+            .bind(path.to_string_lossy().into_owned())
+            .execute(&pool)
+            .await?;
+        Some(path)
+    } else {
+        None
+    };
 
     // Generate filename with timestamp
     let timestamp = chrono::Utc::now().format("%Y%m%d_%H%M%S");
-    let filename = format!("leadr_backup_{timestamp}.csv");
+    let filename = format!("leadr_backup_{timestamp}.{}", format.extension());
 
     // Create headers
     let mut headers = HeaderMap::new();
-    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("text/csv"));
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
     headers.insert(
         header::CONTENT_DISPOSITION,
         HeaderValue::from_str(&format!("attachment; filename=\"{filename}\""))
             .map_err(|e| ApiError::ValidationError(format!("Invalid header value: {e}")))?,
     );
 
-    // Return CSV response with appropriate headers
-    let response = (StatusCode::OK, headers, csv_output);
+    let (tx, rx) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let result = match snapshot {
+            Some(path) => stream_snapshot(path, tx.clone()).await,
+            None if format == ExportFormat::Jsonl => stream_jsonl(pool, params, tx.clone()).await,
+            None => stream_csv(pool, params, tx.clone()).await,
+        };
+        if let Err(e) = result {
+            tracing::error!("Export failed: {e}");
+            let _ = tx.send(Err(e)).await;
+        }
+    });
+
+    // Return the export with appropriate headers
+    let response = (StatusCode::OK, headers, Body::from_stream(ReceiverStream::new(rx)));
 
     Ok(response)
-}
\ No newline at end of file
+}
@@ -1,22 +1,30 @@
 use axum::{
-    extract::{Path, Query, State},
+    extract::{Extension, Path, RawQuery, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
-use utoipa::OpenApi;
 
 use crate::{
-    db::{repository::GameRepository, DbPool},
+    auth::AuthenticatedKey,
+    db::{
+        repository::{GameRepository, LeaderboardRepository, ScoreRepository},
+        DbPool,
+    },
     error::ApiError,
-    models::{game::{CreateGame, UpdateGame, Game}, PaginatedResponse},
-    utils::pagination::PaginationParams,
+    models::{
+        game::{CreateGame, UpdateGame},
+        stats::GameStatsQueryParams,
+        ApiKeyScope,
+    },
+    utils::pagination::GameQueryParams,
 };
 
 /// Creates a new game.
 /// 
 /// # Errors
-/// Returns `ApiError::ValidationError` if the game name is invalid.
+/// Returns `ApiError::ValidationError` if the game name, slug or validation rules are invalid.
+/// Returns `ApiError::Conflict` if the slug is already used by another game.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     post,
@@ -26,6 +34,8 @@ use crate::{
         (status = 201, description = "Game created successfully", body = Game),
         (status = 400, description = "Invalid game data"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 409, description = "Slug already used by another game"),
+        (status = 422, description = "Validation error"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -42,20 +52,26 @@ pub async fn create_game(
 }
 
 /// Lists games with pagination support.
+///
+/// Admin keys can also list soft-deleted games with `include_deleted` or `only_deleted`.
+/// With `include_counts`, each game carries its `score_count` and `player_count`.
 /// 
 /// # Errors
-/// Returns `ApiError::ValidationError` if pagination parameters are invalid.
+/// Returns `ApiError::ValidationError` if query parameters are invalid.
+/// Returns `ApiError::Forbidden` if a non-admin key asks for deleted games.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     get,
     path = "/games",
     params(
-        PaginationParams
+        GameQueryParams
     ),
     responses(
         (status = 200, description = "List of games", body = PaginatedResponse<Game>),
         (status = 400, description = "Invalid pagination parameters"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "Listing deleted games needs the admin scope"),
+        (status = 422, description = "Invalid query parameters"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -65,9 +81,20 @@ pub async fn create_game(
 )]
 pub async fn list_games(
     State(pool): State<DbPool>,
-    Query(params): Query<PaginationParams>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    RawQuery(query_string): RawQuery,
 ) -> Result<impl IntoResponse, ApiError> {
-    let result = GameRepository::list(&pool, params).await?;
+    let params = serde_urlencoded::from_str::<GameQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+    let deleted = params.deleted_filter();
+    if deleted.includes_deleted() {
+        auth_key.check_scope(ApiKeyScope::Admin, "list deleted games")?;
+    }
+
+    let mut result = GameRepository::list(&pool, params.to_pagination_params(), deleted).await?;
+    if params.include_counts.unwrap_or(false) {
+        GameRepository::add_counts(&pool, &mut result.data).await?;
+    }
     Ok(Json(result))
 }
 
@@ -75,18 +102,20 @@ pub async fn list_games(
 /// 
 /// # Errors
 /// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
 /// Returns `ApiError::NotFound` if no game exists with the given hex_id.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     get,
     path = "/games/{hex_id}",
     params(
-        ("hex_id" = String, Path, description = "6-character game identifier")
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
     ),
     responses(
         (status = 200, description = "Game found", body = Game),
         (status = 400, description = "Invalid hex_id format"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
         (status = 404, description = "Game not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -97,31 +126,93 @@ pub async fn list_games(
 )]
 pub async fn get_game(
     State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     Path(hex_id): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
     let game = GameRepository::get_by_hex_id(&pool, &hex_id).await?;
     Ok(Json(game))
 }
 
+/// Returns aggregate statistics and the score distribution of a game.
+///
+/// Only live scores count. The histogram has `buckets` equal-width buckets (default 10).
+/// With `leaderboard`, only that board of the game counts; without it, all of the game's
+/// boards are counted together.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::ValidationError` if query parameters are invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if no game exists with the given hex_id or it has no
+/// leaderboard with the given slug.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/stats",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        GameStatsQueryParams
+    ),
+    responses(
+        (status = 200, description = "Game statistics", body = GameStats),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game or leaderboard not found"),
+        (status = 422, description = "Invalid query parameters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Games"
+)]
+pub async fn get_game_stats(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    let params = serde_urlencoded::from_str::<GameStatsQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+    let buckets = params.get_buckets()?;
+    auth_key.check_game(&hex_id)?;
+    let leaderboard_id = match params.leaderboard {
+        Some(ref slug) => Some(LeaderboardRepository::id_by_slug(&pool, &hex_id, slug).await?),
+        None => None,
+    };
+
+    let stats = ScoreRepository::get_game_stats(&pool, &hex_id, leaderboard_id, buckets).await?;
+    Ok(Json(stats))
+}
+
 /// Updates an existing game.
 /// 
 /// # Errors
 /// Returns `ApiError::InvalidParameter` if the hex_id format or name is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
 /// Returns `ApiError::NotFound` if no game exists with the given hex_id.
 /// Returns `ApiError::ValidationError` if the update data is invalid.
+/// Returns `ApiError::Conflict` if the new slug is already used by another game.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     put,
     path = "/games/{hex_id}",
     params(
-        ("hex_id" = String, Path, description = "6-character game identifier")
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
     ),
     request_body = UpdateGame,
     responses(
         (status = 200, description = "Game updated successfully", body = Game),
         (status = 400, description = "Invalid data"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
         (status = 404, description = "Game not found"),
+        (status = 409, description = "Slug already used by another game"),
+        (status = 422, description = "Validation error"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -131,9 +222,12 @@ pub async fn get_game(
 )]
 pub async fn update_game(
     State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     Path(hex_id): Path<String>,
     Json(update_data): Json<UpdateGame>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
     let game = GameRepository::update(&pool, &hex_id, update_data).await?;
     Ok(Json(game))
 }
@@ -142,18 +236,20 @@ pub async fn update_game(
 /// 
 /// # Errors
 /// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
 /// Returns `ApiError::NotFound` if no game exists with the given hex_id.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     delete,
     path = "/games/{hex_id}",
     params(
-        ("hex_id" = String, Path, description = "6-character game identifier")
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
     ),
     responses(
         (status = 204, description = "Game deleted successfully"),
         (status = 400, description = "Invalid hex_id format"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
         (status = 404, description = "Game not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -164,8 +260,127 @@ pub async fn update_game(
 )]
 pub async fn delete_game(
     State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     Path(hex_id): Path<String>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
     GameRepository::soft_delete(&pool, &hex_id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Restores a soft-deleted game along with the scores deleted with it.
+///
+/// Scores that were deleted individually before the game stay deleted.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if no deleted game exists with the given hex_id.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/games/{hex_id}/restore",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 200, description = "Game restored", body = Game),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is not allowed for this game"),
+        (status = 404, description = "Deleted game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Games"
+)]
+pub async fn restore_game(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let game = GameRepository::restore(&pool, &hex_id).await?;
+    Ok(Json(game))
+}
+
+/// Generates a new score signing secret for a game, replacing any existing one.
+///
+/// Once a game has a secret, `POST /scores` only accepts signed submissions for it.
+/// The secret is only returned in this response.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if no game exists with the given hex_id.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/games/{hex_id}/signing-secret",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 200, description = "Signing secret generated", body = SigningSecret),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Games"
+)]
+pub async fn rotate_signing_secret(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let signing_secret = GameRepository::rotate_signing_secret(&pool, &hex_id).await?;
+    Ok(Json(signing_secret))
+}
+
+/// Removes a game's score signing secret so submissions no longer need signing.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if no game exists with the given hex_id.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    delete,
+    path = "/games/{hex_id}/signing-secret",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 204, description = "Signing disabled"),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Games"
+)]
+pub async fn clear_signing_secret(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    GameRepository::clear_signing_secret(&pool, &hex_id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,85 @@
+use axum::{
+    body::Bytes,
+    extract::{Extension, RawQuery, State},
+    http::{header, HeaderMap},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{repository::ImportRepository, DbPool},
+    error::ApiError,
+    models::import::{parse_csv, parse_jsonl, ImportFormat, ImportQueryParams},
+};
+
+/// Largest import body accepted, in bytes.
+pub const IMPORT_MAX_BYTES: usize = 64 * 1024 * 1024;
+
+/// Imports games and scores from a CSV or JSON Lines export.
+///
+/// The whole import runs in a single transaction. Records that cannot be applied are
+/// skipped or failed individually and listed in the report; with `dry_run=true` the
+/// transaction is rolled back after the report is built.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if the query parameters or the CSV header are invalid.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/import",
+    params(
+        ImportQueryParams
+    ),
+    request_body(content = String, description = "CSV or JSON Lines export", content_type = "text/csv"),
+    responses(
+        (status = 200, description = "Import report", body = ImportReport),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 413, description = "Import body too large"),
+        (status = 422, description = "Invalid query parameters or file"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Import"
+)]
+pub async fn import_data(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    RawQuery(query_string): RawQuery,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_key.check_unrestricted("import data")?;
+    let params = serde_urlencoded::from_str::<ImportQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+
+    let format = params.format.unwrap_or_else(|| {
+        let content_type = headers
+            .get(header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default();
+        if content_type.starts_with("application/x-ndjson") || content_type.starts_with("application/jsonl") {
+            ImportFormat::Jsonl
+        } else {
+            ImportFormat::Csv
+        }
+    });
+
+    let lines = match format {
+        ImportFormat::Csv => parse_csv(&body).map_err(ApiError::ValidationError)?,
+        ImportFormat::Jsonl => parse_jsonl(&body),
+    };
+
+    let report = ImportRepository::import(
+        &pool,
+        lines,
+        params.mode.unwrap_or_default(),
+        params.dry_run.unwrap_or(false),
+    )
+    .await?;
+    Ok(Json(report))
+}
@@ -0,0 +1,96 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{
+        repository::{GameRepository, LeaderboardRepository},
+        DbPool,
+    },
+    error::ApiError,
+    models::leaderboard::CreateLeaderboard,
+};
+
+/// Adds a leaderboard to a game, e.g. for a level, mode or difficulty.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game does not exist.
+/// Returns `ApiError::ValidationError` if the slug or name is invalid.
+/// Returns `ApiError::Conflict` if the game already has a leaderboard with the slug.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/games/{hex_id}/leaderboards",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    request_body = CreateLeaderboard,
+    responses(
+        (status = 201, description = "Leaderboard created successfully", body = Leaderboard),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 409, description = "Slug already used by another of the game's leaderboards"),
+        (status = 422, description = "Invalid leaderboard data"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Leaderboards"
+)]
+pub async fn create_leaderboard(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+    Json(create_data): Json<CreateLeaderboard>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let leaderboard = LeaderboardRepository::create(&pool, &hex_id, create_data).await?;
+    Ok((StatusCode::CREATED, Json(leaderboard)))
+}
+
+/// Lists a game's leaderboards, starting with its default board.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game does not exist.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/leaderboards",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 200, description = "List of leaderboards", body = Vec<Leaderboard>),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Leaderboards"
+)]
+pub async fn list_leaderboards(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let leaderboards = LeaderboardRepository::list_by_game(&pool, &hex_id).await?;
+    Ok(Json(leaderboards))
+}
@@ -1,4 +1,13 @@
+pub mod api_key;
 pub mod export;
 pub mod game;
 pub mod health;
+pub mod import;
+pub mod leaderboard;
+pub mod player;
+pub mod purge;
+pub mod ranking;
 pub mod score;
+pub mod season;
+pub mod stream;
+pub mod webhook;
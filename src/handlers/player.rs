@@ -0,0 +1,212 @@
+use axum::{
+    extract::{Extension, Path, Query, RawQuery, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{
+        repository::{PlayerRepository, ScoreRepository},
+        DbPool,
+    },
+    error::ApiError,
+    models::{
+        player::{ErasePlayerParams, UpdatePlayer},
+        ranking::RankQueryParams,
+    },
+};
+
+/// Returns a player's profile.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the user ID is invalid.
+/// Returns `ApiError::NotFound` if the player has no profile.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/players/{user_id}",
+    params(
+        ("user_id" = String, Path, description = "Player's user ID")
+    ),
+    responses(
+        (status = 200, description = "Player profile", body = Player),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 404, description = "Player not found"),
+        (status = 422, description = "Invalid user ID"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Players"
+)]
+pub async fn get_player(
+    State(pool): State<DbPool>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let player = PlayerRepository::get(&pool, &user_id).await?;
+    Ok(Json(player))
+}
+
+/// Returns a player's stats on each game they play, with a summary across all games.
+///
+/// Keys restricted to specific games only see stats for those games.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the user ID is invalid.
+/// Returns `ApiError::NotFound` if the player has no profile and no scores.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/players/{user_id}/stats",
+    params(
+        ("user_id" = String, Path, description = "Player's user ID"),
+        RankQueryParams
+    ),
+    responses(
+        (status = 200, description = "Player stats", body = PlayerStats),
+        (status = 400, description = "Invalid query parameters"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 404, description = "Player not found"),
+        (status = 422, description = "Invalid user ID"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Players"
+)]
+pub async fn get_player_stats(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(user_id): Path<String>,
+    Query(params): Query<RankQueryParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    let stats = ScoreRepository::get_player_stats(
+        &pool,
+        &user_id,
+        params.rank_policy.unwrap_or_default(),
+        &auth_key.game_hex_ids,
+    )
+    .await?;
+    Ok(Json(stats))
+}
+
+/// Updates a player's profile.
+///
+/// A new `user_name` shows on all of the player's scores at once. Archived season
+/// standings keep the name the player had when the season closed.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the user ID or profile fields are invalid.
+/// Returns `ApiError::NotFound` if the player has no profile.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    put,
+    path = "/players/{user_id}",
+    params(
+        ("user_id" = String, Path, description = "Player's user ID")
+    ),
+    request_body = UpdatePlayer,
+    responses(
+        (status = 200, description = "Player updated", body = Player),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope"),
+        (status = 404, description = "Player not found"),
+        (status = 422, description = "Invalid user ID or profile fields"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Players"
+)]
+pub async fn update_player(
+    State(pool): State<DbPool>,
+    Path(user_id): Path<String>,
+    Json(payload): Json<UpdatePlayer>,
+) -> Result<impl IntoResponse, ApiError> {
+    let player = PlayerRepository::update(&pool, &user_id, payload).await?;
+    Ok(Json(player))
+}
+
+/// Returns everything stored about a player: all of their scores across every game,
+/// including soft-deleted ones, and their archived season standings.
+///
+/// Each export is recorded in the audit log.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the user ID is invalid.
+/// Returns `ApiError::NotFound` if nothing is stored for the player.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/players/{user_id}/data",
+    params(
+        ("user_id" = String, Path, description = "Player's user ID")
+    ),
+    responses(
+        (status = 200, description = "Player data", body = PlayerData),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope"),
+        (status = 404, description = "No data stored for the player"),
+        (status = 422, description = "Invalid user ID"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Players"
+)]
+pub async fn export_player_data(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(user_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let data = PlayerRepository::export_data(&pool, &user_id, &auth_key).await?;
+    Ok(Json(data))
+}
+
+/// Erases a player's data, either anonymising their scores in place or deleting them.
+///
+/// Anonymised scores keep their values so boards stay intact, but lose the player's
+/// `user_id`, `user_name` and `extra`. Each erasure is recorded in the audit log.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the user ID or query parameters are invalid.
+/// Returns `ApiError::NotFound` if nothing is stored for the player.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    delete,
+    path = "/players/{user_id}",
+    params(
+        ("user_id" = String, Path, description = "Player's user ID"),
+        ErasePlayerParams
+    ),
+    responses(
+        (status = 200, description = "Player data erased", body = PlayerErasure),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope"),
+        (status = 404, description = "No data stored for the player"),
+        (status = 422, description = "Invalid user ID or mode"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Players"
+)]
+pub async fn erase_player(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(user_id): Path<String>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    let params = serde_urlencoded::from_str::<ErasePlayerParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+
+    let erasure =
+        PlayerRepository::erase(&pool, &user_id, params.mode.unwrap_or_default(), &auth_key).await?;
+    Ok(Json(erasure))
+}
@@ -0,0 +1,61 @@
+use axum::{
+    extract::{Extension, RawQuery, State},
+    response::IntoResponse,
+    Json,
+};
+use chrono::Utc;
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{repository::PurgeRepository, DbPool},
+    error::ApiError,
+    models::purge::PurgeQueryParams,
+};
+
+/// Permanently removes games and scores soft-deleted before a cutoff.
+///
+/// Purged records cannot be restored. Deleted games still on an active API key's
+/// allow-list are kept and counted in `games_kept`.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if the query parameters are invalid, or not exactly
+/// one of `before` and `older_than_days` is given.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/purge",
+    params(
+        PurgeQueryParams
+    ),
+    responses(
+        (status = 200, description = "Purge report", body = PurgeReport),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 422, description = "Invalid query parameters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Purge"
+)]
+pub async fn purge_deleted(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    auth_key.check_unrestricted("purge data")?;
+    let params = serde_urlencoded::from_str::<PurgeQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+    let before = params.cutoff(Utc::now()).map_err(ApiError::ValidationError)?;
+
+    let report = PurgeRepository::purge(&pool, before).await?;
+    tracing::info!(
+        "Purged {} games and {} scores deleted before {}",
+        report.games,
+        report.scores,
+        report.before
+    );
+    Ok(Json(report))
+}
@@ -0,0 +1,196 @@
+use axum::{
+    extract::{Extension, Path, Query, RawQuery, State},
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{
+        repository::{GameRepository, LeaderboardRepository, ScoreRepository},
+        DbPool,
+    },
+    error::ApiError,
+    models::ranking::{AroundAnchor, AroundQueryParams, LeaderboardWindow, PlayerRankQueryParams},
+};
+
+/// Retrieves a player's best score on a game along with its rank and percentile.
+///
+/// With `leaderboard`, the player is ranked on that board of the game only; without it, all
+/// of the game's boards are ranked together.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game or the named leaderboard does not exist or the
+/// player has no scores on it.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/ranks/{user_id}",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        ("user_id" = String, Path, description = "Player identifier"),
+        PlayerRankQueryParams
+    ),
+    responses(
+        (status = 200, description = "Player rank found", body = PlayerRank),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game or leaderboard not found or player has no scores"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Rankings"
+)]
+pub async fn get_player_rank(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path((hex_id, user_id)): Path<(String, String)>,
+    Query(params): Query<PlayerRankQueryParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let leaderboard_id = resolve_leaderboard(&pool, &hex_id, params.leaderboard.as_deref()).await?;
+    let player_rank = ScoreRepository::get_player_rank(
+        &pool,
+        &hex_id,
+        leaderboard_id,
+        &user_id,
+        params.rank_policy.unwrap_or_default(),
+    )
+    .await?;
+    Ok(Json(player_rank))
+}
+
+/// Lists the leaderboard entries surrounding a player's best entry on a game.
+///
+/// With `leaderboard`, the window covers that board of the game only; without it, all of the
+/// game's boards are ranked together.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the sort or window parameters are invalid.
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game or the named leaderboard does not exist or the
+/// player has no scores on it.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/leaderboard/around/{user_id}",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        ("user_id" = String, Path, description = "Player identifier"),
+        AroundQueryParams
+    ),
+    responses(
+        (status = 200, description = "Leaderboard window around the player", body = LeaderboardWindow),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game or leaderboard not found or player has no scores"),
+        (status = 422, description = "Invalid query parameters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Rankings"
+)]
+pub async fn get_leaderboard_around_player(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path((hex_id, user_id)): Path<(String, String)>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let params = parse_around_params(query_string)?;
+    list_around(&pool, &hex_id, AroundAnchor::UserId(user_id), &params).await
+}
+
+/// Lists the leaderboard entries surrounding a specific score on a game.
+///
+/// With `leaderboard`, the window covers that board of the game only; without it, all of the
+/// game's boards are ranked together.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the sort or window parameters are invalid
+/// or `around_score_id` is missing.
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game, the named leaderboard or the score does not
+/// exist on it.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/leaderboard/around",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        AroundQueryParams
+    ),
+    responses(
+        (status = 200, description = "Leaderboard window around the score", body = LeaderboardWindow),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game, leaderboard or score not found"),
+        (status = 422, description = "Invalid query parameters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Rankings"
+)]
+pub async fn get_leaderboard_around_score(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let params = parse_around_params(query_string)?;
+    let score_id = params.around_score_id.ok_or_else(|| {
+        ApiError::ValidationError("around_score_id query parameter is required".to_string())
+    })?;
+    list_around(&pool, &hex_id, AroundAnchor::ScoreId(score_id), &params).await
+}
+
+fn parse_around_params(query_string: Option<String>) -> Result<AroundQueryParams, ApiError> {
+    // Parse manually so invalid sort parameters are reported like `/scores`
+    serde_urlencoded::from_str::<AroundQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))
+}
+
+async fn list_around(
+    pool: &DbPool,
+    hex_id: &str,
+    anchor: AroundAnchor,
+    params: &AroundQueryParams,
+) -> Result<Json<LeaderboardWindow>, ApiError> {
+    let leaderboard_id = resolve_leaderboard(pool, hex_id, params.leaderboard.as_deref()).await?;
+    let window = ScoreRepository::list_around(
+        pool,
+        hex_id,
+        leaderboard_id,
+        anchor,
+        params.get_radius(),
+        params.to_sort_params(),
+        params.get_rank_policy(),
+    )
+    .await?;
+    Ok(Json(window))
+}
+
+/// Resolve an optional leaderboard slug to its id; `None` ranks all of the game's boards.
+async fn resolve_leaderboard(pool: &DbPool, hex_id: &str, slug: Option<&str>) -> Result<Option<i64>, ApiError> {
+    match slug {
+        Some(slug) => Ok(Some(LeaderboardRepository::id_by_slug(pool, hex_id, slug).await?)),
+        None => Ok(None),
+    }
+}
@@ -1,35 +1,56 @@
 use axum::{
-    extract::{Path, RawQuery, State},
-    http::StatusCode,
+    extract::{Extension, Path, RawQuery, State},
+    http::{HeaderMap, StatusCode},
     response::IntoResponse,
     Json,
 };
 
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
 use crate::{
+    auth::{AuthenticatedKey, SubmissionSignature},
     db::{
-        repository::{GameRepository, ScoreRepository},
+        repository::{GameRepository, LeaderboardRepository, ScoreRepository},
         DbPool,
     },
     error::ApiError,
-    models::{score::{CreateScore, Score, UpdateScore}, PaginatedResponse},
+    events::ScoreEvents,
+    models::{
+        batch::{BatchScore, CreateScoreBatch, PreparedBatchScore},
+        score::{CreateScore, Score, SubmissionOutcome, UpdateScore},
+        ApiKeyScope, Game,
+    },
     utils::pagination::ScoreQueryParams,
 };
 
-/// Creates a new score for a specific game.
+/// Submits a score for a specific game, applying the game's submission policy.
+///
+/// Games with a signing secret only accept submissions carrying a fresh timestamp, an
+/// unused nonce and a valid HMAC of the canonical payload in the signature headers.
 /// 
 /// # Errors
+/// Returns `ApiError::Unauthorized` if the game requires signed submissions and the
+/// signature is missing, invalid, stale or replayed.
+/// Returns `ApiError::Forbidden` if the API key is restricted to other games.
 /// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid.
-/// Returns `ApiError::NotFound` if the game does not exist.
+/// Returns `ApiError::NotFound` if the game or the named leaderboard does not exist.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     post,
     path = "/scores",
     request_body = CreateScore,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Makes the request safe to retry: a repeat with the same key and body replays the first response")
+    ),
     responses(
-        (status = 201, description = "Score created successfully", body = Score),
+        (status = 200, description = "Player's existing entry replaced or kept", body = ScoreSubmission),
+        (status = 201, description = "Score created successfully", body = ScoreSubmission),
         (status = 400, description = "Invalid score data"),
-        (status = 401, description = "Missing or invalid API key"),
-        (status = 404, description = "Game not found"),
+        (status = 401, description = "Missing or invalid API key or submission signature"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game or leaderboard not found"),
+        (status = 409, description = "Idempotency-Key reused with a different request, or still in use"),
         (status = 422, description = "Validation error"),
         (status = 500, description = "Internal server error")
     ),
@@ -40,29 +61,159 @@ use crate::{
 )]
 pub async fn create_score(
     State(pool): State<DbPool>,
-    Json(create_data): Json<CreateScore>,
+    State(events): State<ScoreEvents>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    headers: HeaderMap,
+    Json(mut create_data): Json<CreateScore>,
 ) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &create_data.game_hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+
     // Validate the input data first (this will return 422 if invalid)
     Score::validate_user_name(&create_data.user_name)?;
     Score::validate_user_id(&create_data.user_id)?;
 
     // Then check if the game exists (this will return 404 if not found)
-    if GameRepository::get_by_hex_id(&pool, &create_data.game_hex_id)
+    let game = GameRepository::get_by_hex_id(&pool, &hex_id)
         .await
-        .is_err()
-    {
-        return Err(ApiError::NotFound);
+        .map_err(|_| ApiError::NotFound)?;
+
+    let nonce = match GameRepository::get_signing_secret(&pool, &game.hex_id).await? {
+        Some(secret) => {
+            let signature = SubmissionSignature::from_headers(&headers)?;
+            signature.verify(&secret, &create_data.canonical_payload(), Utc::now())?;
+            Some(signature.nonce)
+        }
+        None => None,
+    };
+
+    // The signature covers the id as sent; the score is stored under the game's hex_id
+    create_data.game_hex_id = game.hex_id.clone();
+    let submission =
+        ScoreRepository::create(&pool, &events, &game, create_data, nonce.as_deref()).await?;
+    let status = match submission.outcome {
+        SubmissionOutcome::Created => StatusCode::CREATED,
+        SubmissionOutcome::Replaced | SubmissionOutcome::Unchanged => StatusCode::OK,
+    };
+    Ok((status, Json(submission)))
+}
+
+/// Submits up to 100 scores in one transaction, e.g. scores queued while a client was offline.
+///
+/// Each item is checked and applied like a `POST /scores` submission, optionally dated with
+/// its own `submitted_at`. Items for games with a signing secret carry their signature in
+/// a `signature` object instead of headers. Under `all_or_nothing` (the default) a single
+/// failing item rolls the whole batch back; under `partial` the other items are saved.
+///
+/// # Errors
+/// Returns `ApiError::ValidationError` if the batch is empty or too large.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/scores/batch",
+    request_body = CreateScoreBatch,
+    params(
+        ("Idempotency-Key" = Option<String>, Header, description = "Makes the request safe to retry: a repeat with the same key and body replays the first response")
+    ),
+    responses(
+        (status = 200, description = "Batch saved; see each result for failed items in partial mode", body = BatchSubmission),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the submit scope"),
+        (status = 409, description = "Idempotency-Key reused with a different request, or still in use"),
+        (status = 422, description = "Invalid batch, or an all_or_nothing batch with a failing item", body = BatchSubmission),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Scores"
+)]
+pub async fn create_score_batch(
+    State(pool): State<DbPool>,
+    State(events): State<ScoreEvents>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Json(batch): Json<CreateScoreBatch>,
+) -> Result<impl IntoResponse, ApiError> {
+    batch.validate()?;
+
+    let now = Utc::now();
+    let mut games = HashMap::new();
+    let mut items = Vec::with_capacity(batch.scores.len());
+    for item in batch.scores {
+        items.push(prepare_batch_score(&pool, &auth_key, &mut games, item, now).await);
+    }
+
+    let submission = ScoreRepository::create_batch(&pool, &events, batch.mode, items).await?;
+    let status = if submission.committed {
+        StatusCode::OK
+    } else {
+        StatusCode::UNPROCESSABLE_ENTITY
+    };
+    Ok((status, Json(submission)))
+}
+
+/// Makes the checks `create_score` makes before saving, for one batch item.
+///
+/// `games` caches each game and its signing secret, so every game is looked up once per batch.
+async fn prepare_batch_score(
+    pool: &DbPool,
+    auth_key: &AuthenticatedKey,
+    games: &mut HashMap<String, (Game, Option<String>)>,
+    item: BatchScore,
+    now: DateTime<Utc>,
+) -> Result<PreparedBatchScore, ApiError> {
+    let hex_id = GameRepository::resolve_id(pool, &item.score.game_hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    Score::validate_user_name(&item.score.user_name)?;
+    Score::validate_user_id(&item.score.user_id)?;
+    let submitted_at = item.resolve_submitted_at(now)?;
+
+    if !games.contains_key(&hex_id) {
+        let game = GameRepository::get_by_hex_id(pool, &hex_id)
+            .await
+            .map_err(|_| ApiError::NotFound)?;
+        let secret = GameRepository::get_signing_secret(pool, &game.hex_id).await?;
+        games.insert(hex_id.clone(), (game, secret));
     }
+    let (game, secret) = &games[&hex_id];
 
-    let score = ScoreRepository::create(&pool, create_data).await?;
-    Ok((StatusCode::CREATED, Json(score)))
+    let nonce = match secret {
+        Some(secret) => {
+            let signature = item.signature.ok_or_else(|| {
+                ApiError::Unauthorized("Missing signature for a game that requires signed submissions".to_string())
+            })?;
+            let signature =
+                SubmissionSignature::from_parts(signature.timestamp, signature.nonce, &signature.signature)?;
+            signature.verify(secret, &item.score.canonical_payload(), now)?;
+            Some(signature.nonce)
+        }
+        None => None,
+    };
+
+    let mut score = item.score;
+    score.game_hex_id = game.hex_id.clone();
+    Ok(PreparedBatchScore {
+        game: game.clone(),
+        score,
+        submitted_at,
+        nonce,
+    })
 }
 
 /// Lists scores with optional game filtering, pagination and sorting support.
+///
+/// With `leaderboard`, only that board of the game is listed and ranked; without it, all of
+/// the game's boards are listed and ranked together.
+///
+/// Admin keys can also list soft-deleted scores with `include_deleted` or `only_deleted`.
 /// 
 /// # Errors
 /// Returns `ApiError::ValidationError` if pagination or sort parameters are invalid.
 /// Returns `ApiError::InvalidParameter` if the game hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the API key is restricted to other games, or to
+/// specific games and no `game_hex_id` is given, or asks for deleted scores without
+/// the admin scope.
+/// Returns `ApiError::NotFound` if the game has no leaderboard with the given slug.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     get,
@@ -74,6 +225,8 @@ pub async fn create_score(
         (status = 200, description = "List of scores", body = PaginatedResponse<Score>),
         (status = 400, description = "Invalid query parameters"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game or to list deleted scores"),
+        (status = 404, description = "Game or leaderboard not found"),
         (status = 500, description = "Internal server error")
     ),
     security(
@@ -83,6 +236,7 @@ pub async fn create_score(
 )]
 pub async fn list_scores(
     State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     RawQuery(query_string): RawQuery,
 ) -> Result<impl IntoResponse, ApiError> {
     // Parse query parameters manually to provide better error messages
@@ -91,12 +245,36 @@ pub async fn list_scores(
     // Parse all query parameters together
     let query_params = serde_urlencoded::from_str::<ScoreQueryParams>(&query_str)
         .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+    let mut filter_params = query_params.to_filter_params()?;
+    if filter_params.deleted.includes_deleted() {
+        auth_key.check_scope(ApiKeyScope::Admin, "list deleted scores")?;
+    }
+
+    // The game may be given by its slug
+    let game_hex_id = match query_params.game_hex_id {
+        Some(ref id) => Some(GameRepository::resolve_id(&pool, id).await?),
+        None => None,
+    };
+    match game_hex_id {
+        Some(ref game_hex_id) => auth_key.check_game(game_hex_id)?,
+        None if auth_key.is_restricted() => {
+            return Err(ApiError::Forbidden(
+                "API key is restricted to specific games; game_hex_id is required".to_string(),
+            ));
+        }
+        None => {}
+    }
+
+    if let (Some(game_hex_id), Some(leaderboard)) = (&game_hex_id, &query_params.leaderboard) {
+        filter_params.leaderboard_id =
+            Some(LeaderboardRepository::id_by_slug(&pool, game_hex_id, leaderboard).await?);
+    }
 
     // If game_hex_id is provided, list scores for that game, otherwise list all scores
-    let result = if let Some(ref game_hex_id) = query_params.game_hex_id {
-        ScoreRepository::list_by_game(&pool, game_hex_id, query_params.to_pagination_params(), query_params.to_sort_params()).await?
+    let result = if let Some(ref game_hex_id) = game_hex_id {
+        ScoreRepository::list_by_game(&pool, game_hex_id, query_params.to_pagination_params(), query_params.to_sort_params(), filter_params, query_params.get_rank_policy()).await?
     } else {
-        ScoreRepository::list_all(&pool, query_params.to_pagination_params(), query_params.to_sort_params()).await?
+        ScoreRepository::list_all(&pool, query_params.to_pagination_params(), query_params.to_sort_params(), filter_params, query_params.get_rank_policy()).await?
     };
     Ok(Json(result))
 }
@@ -105,6 +283,7 @@ pub async fn list_scores(
 /// 
 /// # Errors
 /// Returns `ApiError::NotFound` if no score exists with the given ID.
+/// Returns `ApiError::Forbidden` if the API key is restricted to other games.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     get,
@@ -115,6 +294,7 @@ pub async fn list_scores(
     responses(
         (status = 200, description = "Score found", body = Score),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
         (status = 404, description = "Score not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -125,17 +305,20 @@ pub async fn list_scores(
 )]
 pub async fn get_score(
     State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     Path(id): Path<i64>,
 ) -> Result<impl IntoResponse, ApiError> {
     let score = ScoreRepository::get_by_id(&pool, id).await?;
+    auth_key.check_game(&score.game_hex_id)?;
     Ok(Json(score))
 }
 
 /// Updates an existing score.
 /// 
 /// # Errors
-/// Returns `ApiError::ValidationError` if user name, user ID, or JSON data is invalid.
+/// Returns `ApiError::ValidationError` if a user name is given, or the user ID or JSON data is invalid.
 /// Returns `ApiError::NotFound` if no score exists with the given ID.
+/// Returns `ApiError::Forbidden` if the API key is restricted to other games.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     put,
@@ -148,6 +331,7 @@ pub async fn get_score(
         (status = 200, description = "Score updated successfully", body = Score),
         (status = 400, description = "Invalid data"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
         (status = 404, description = "Score not found"),
         (status = 422, description = "Validation error"),
         (status = 500, description = "Internal server error")
@@ -159,10 +343,17 @@ pub async fn get_score(
 )]
 pub async fn update_score(
     State(pool): State<DbPool>,
+    State(events): State<ScoreEvents>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     Path(id): Path<i64>,
     Json(update_data): Json<UpdateScore>,
 ) -> Result<impl IntoResponse, ApiError> {
-    let score = ScoreRepository::update(&pool, id, update_data).await?;
+    if auth_key.is_restricted() {
+        let existing = ScoreRepository::get_by_id(&pool, id).await?;
+        auth_key.check_game(&existing.game_hex_id)?;
+    }
+
+    let score = ScoreRepository::update(&pool, &events, id, update_data).await?;
     Ok(Json(score))
 }
 
@@ -170,6 +361,7 @@ pub async fn update_score(
 /// 
 /// # Errors
 /// Returns `ApiError::NotFound` if no score exists with the given ID.
+/// Returns `ApiError::Forbidden` if the API key is restricted to other games.
 /// Returns `ApiError::DatabaseError` if the database operation fails.
 #[utoipa::path(
     delete,
@@ -180,6 +372,7 @@ pub async fn update_score(
     responses(
         (status = 204, description = "Score deleted successfully"),
         (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
         (status = 404, description = "Score not found"),
         (status = 500, description = "Internal server error")
     ),
@@ -190,8 +383,55 @@ pub async fn update_score(
 )]
 pub async fn delete_score(
     State(pool): State<DbPool>,
+    State(events): State<ScoreEvents>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
     Path(id): Path<i64>,
 ) -> Result<impl IntoResponse, ApiError> {
-    ScoreRepository::soft_delete(&pool, id).await?;
+    if auth_key.is_restricted() {
+        let existing = ScoreRepository::get_by_id(&pool, id).await?;
+        auth_key.check_game(&existing.game_hex_id)?;
+    }
+
+    ScoreRepository::soft_delete(&pool, &events, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
+
+/// Restores a soft-deleted score.
+///
+/// # Errors
+/// Returns `ApiError::NotFound` if no deleted score exists with the given ID.
+/// Returns `ApiError::Forbidden` if the API key is restricted to other games.
+/// Returns `ApiError::ValidationError` if the score's game is deleted; restore the game instead.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/scores/{id}/restore",
+    params(
+        ("id" = i64, Path, description = "Score ID")
+    ),
+    responses(
+        (status = 200, description = "Score restored", body = Score),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is not allowed for this game"),
+        (status = 404, description = "Deleted score not found"),
+        (status = 422, description = "Score's game is deleted"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Scores"
+)]
+pub async fn restore_score(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    // Deleted scores are hidden from `get_by_id`, so look the game up directly
+    if auth_key.is_restricted() {
+        auth_key.check_game(&ScoreRepository::game_hex_id(&pool, id).await?)?;
+    }
+
+    let score = ScoreRepository::restore(&pool, id).await?;
+    Ok(Json(score))
+}
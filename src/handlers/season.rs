@@ -0,0 +1,228 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{
+        repository::{GameRepository, LeaderboardRepository, SeasonRepository},
+        DbPool,
+    },
+    error::ApiError,
+    models::season::{CreateSeason, SeasonStandingsQueryParams},
+};
+
+/// Schedules a new season on a game.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game does not exist.
+/// Returns `ApiError::ValidationError` if the season name or schedule is invalid.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/games/{hex_id}/seasons",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    request_body = CreateSeason,
+    responses(
+        (status = 201, description = "Season created successfully", body = Season),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 422, description = "Invalid season data"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Seasons"
+)]
+pub async fn create_season(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+    Json(create_data): Json<CreateSeason>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let season = SeasonRepository::create(&pool, &hex_id, create_data).await?;
+    Ok((StatusCode::CREATED, Json(season)))
+}
+
+/// Lists a game's seasons, most recent first.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game does not exist.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/seasons",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 200, description = "List of seasons", body = Vec<Season>),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Seasons"
+)]
+pub async fn list_seasons(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let seasons = SeasonRepository::list_by_game(&pool, &hex_id).await?;
+    Ok(Json(seasons))
+}
+
+/// Retrieves a specific season of a game.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game or season does not exist.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/seasons/{season_id}",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        ("season_id" = i64, Path, description = "Season ID")
+    ),
+    responses(
+        (status = 200, description = "Season found", body = Season),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game or season not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Seasons"
+)]
+pub async fn get_season(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path((hex_id, season_id)): Path<(String, i64)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let season = SeasonRepository::get(&pool, &hex_id, season_id).await?;
+    Ok(Json(season))
+}
+
+/// Closes a season immediately and archives its final standings.
+///
+/// Seasons are also closed automatically once their end time passes.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game or season does not exist.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/games/{hex_id}/seasons/{season_id}/close",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        ("season_id" = i64, Path, description = "Season ID")
+    ),
+    responses(
+        (status = 200, description = "Season closed", body = Season),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game or season not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Seasons"
+)]
+pub async fn close_season(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path((hex_id, season_id)): Path<(String, i64)>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let season = SeasonRepository::close(&pool, &hex_id, season_id).await?;
+    Ok(Json(season))
+}
+
+/// Lists a closed season's archived final standings in rank order.
+///
+/// Each of the game's leaderboards is ranked separately. With `leaderboard`, only that
+/// board's standings are listed; without it, every board's are listed together.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if the game, season or named leaderboard does not exist.
+/// Returns `ApiError::BadRequest` if the season has not closed yet.
+/// Returns `ApiError::ValidationError` if the cursor is invalid.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/seasons/{season_id}/standings",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug"),
+        ("season_id" = i64, Path, description = "Season ID"),
+        SeasonStandingsQueryParams
+    ),
+    responses(
+        (status = 200, description = "Archived standings", body = PaginatedResponse<SeasonStanding>),
+        (status = 400, description = "Invalid hex_id format or season still open"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game, season or leaderboard not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Seasons"
+)]
+pub async fn list_season_standings(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path((hex_id, season_id)): Path<(String, i64)>,
+    Query(params): Query<SeasonStandingsQueryParams>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let leaderboard_id = match params.leaderboard {
+        Some(ref slug) => Some(LeaderboardRepository::id_by_slug(&pool, &hex_id, slug).await?),
+        None => None,
+    };
+    let standings = SeasonRepository::list_standings(
+        &pool,
+        &hex_id,
+        season_id,
+        leaderboard_id,
+        params.to_pagination_params(),
+    )
+    .await?;
+    Ok(Json(standings))
+}
@@ -0,0 +1,155 @@
+use axum::{
+    extract::{
+        ws::{rejection::WebSocketUpgradeRejection, Message, WebSocket, WebSocketUpgrade},
+        Extension, Path, State,
+    },
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
+};
+use tokio::sync::broadcast;
+use tokio_stream::{
+    wrappers::{errors::BroadcastStreamRecvError, BroadcastStream},
+    StreamExt,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{repository::GameRepository, DbPool},
+    error::ApiError,
+    events::ScoreEvents,
+    models::ScoreEvent,
+};
+
+/// Body of the `lagged` message sent when a subscriber falls too far behind.
+fn lagged_message(skipped: u64) -> String {
+    serde_json::json!({ "type": "lagged", "skipped": skipped }).to_string()
+}
+
+/// Streams a game's score events as Server-Sent Events.
+///
+/// Each event is named after its `type` (`score_created`, `score_updated`, `score_deleted`
+/// or `top_changed`) and carries the event as JSON. A subscriber that falls behind gets a
+/// `lagged` event with the number of events it missed and should refetch the board.
+///
+/// # Errors
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if no game exists with the given hex_id.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/stream",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 200, description = "Stream of score events", body = ScoreEvent, content_type = "text/event-stream"),
+        (status = 400, description = "Invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Streaming"
+)]
+pub async fn stream_events(
+    State(pool): State<DbPool>,
+    State(events): State<ScoreEvents>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+) -> Result<impl IntoResponse, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    auth_key.check_game(&hex_id)?;
+    let game = GameRepository::get_by_hex_id(&pool, &hex_id).await?;
+
+    let stream = BroadcastStream::new(events.subscribe()).filter_map(move |event| match event {
+        Ok(event) if event.game_hex_id() == game.hex_id => {
+            Some(Event::default().event(event.name()).json_data(&event))
+        }
+        Ok(_) => None,
+        Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+            Some(Ok(Event::default().event("lagged").data(lagged_message(skipped))))
+        }
+    });
+    Ok(Sse::new(stream).keep_alive(KeepAlive::default()))
+}
+
+/// Streams a game's score events over a WebSocket.
+///
+/// Sends the same events as `GET /games/{hex_id}/stream`, one JSON text message each,
+/// including `lagged` messages. Messages from the client other than ping and close are
+/// ignored.
+///
+/// # Errors
+/// Returns `ApiError::BadRequest` if the request is not a WebSocket upgrade.
+/// Returns `ApiError::InvalidParameter` if the hex_id format is invalid.
+/// Returns `ApiError::Forbidden` if the key is restricted to other games.
+/// Returns `ApiError::NotFound` if no game exists with the given hex_id.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/games/{hex_id}/ws",
+    params(
+        ("hex_id" = String, Path, description = "Game hex ID or slug")
+    ),
+    responses(
+        (status = 101, description = "Switched to the WebSocket protocol; messages are score events", body = ScoreEvent),
+        (status = 400, description = "Not a WebSocket upgrade request, or invalid hex_id format"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key not allowed for this game"),
+        (status = 404, description = "Game not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Streaming"
+)]
+pub async fn stream_websocket(
+    State(pool): State<DbPool>,
+    State(events): State<ScoreEvents>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(hex_id): Path<String>,
+    upgrade: Result<WebSocketUpgrade, WebSocketUpgradeRejection>,
+) -> Result<Response, ApiError> {
+    let hex_id = GameRepository::resolve_id(&pool, &hex_id).await?;
+    let upgrade = upgrade.map_err(|_| {
+        ApiError::BadRequest("Expected a WebSocket upgrade request".to_string())
+    })?;
+    auth_key.check_game(&hex_id)?;
+    let game = GameRepository::get_by_hex_id(&pool, &hex_id).await?;
+
+    // Subscribe now so no event is missed while the connection is upgraded
+    let receiver = events.subscribe();
+    Ok(upgrade.on_upgrade(move |socket| forward_events(socket, receiver, game.hex_id)))
+}
+
+/// Sends the game's events to the socket until either side closes it.
+async fn forward_events(mut socket: WebSocket, mut receiver: broadcast::Receiver<ScoreEvent>, game_hex_id: String) {
+    loop {
+        let message = tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(event) if event.game_hex_id() == game_hex_id => {
+                    serde_json::to_string(&event).unwrap_or_default()
+                }
+                Ok(_) => continue,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => lagged_message(skipped),
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // Reading also answers pings and echoes the client's close, so keep
+            // reading until the connection is gone
+            incoming = socket.recv() => match incoming {
+                Some(Ok(_)) => continue,
+                Some(Err(_)) | None => break,
+            },
+        };
+        if socket.send(Message::Text(message)).await.is_err() {
+            break;
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use axum::{
+    extract::{Extension, Path, RawQuery, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{repository::WebhookRepository, DbPool},
+    error::ApiError,
+    models::webhook::{CreateWebhook, DeliveryQueryParams, UpdateWebhook},
+};
+
+/// Webhooks can see every game's events, so keys restricted to some games may not manage them.
+fn check_unrestricted(auth_key: &AuthenticatedKey) -> Result<(), ApiError> {
+    auth_key.check_unrestricted("manage webhooks")
+}
+
+/// Registers a webhook.
+///
+/// The signing secret is only returned in this response and when it is rotated.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if the URL, events or game are invalid.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/webhooks",
+    request_body = CreateWebhook,
+    responses(
+        (status = 201, description = "Webhook created successfully", body = CreatedWebhook),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 422, description = "Invalid webhook data"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn create_webhook(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Json(create_data): Json<CreateWebhook>,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    let created = WebhookRepository::create(&pool, create_data).await?;
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+/// Lists all webhooks.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/webhooks",
+    responses(
+        (status = 200, description = "List of webhooks", body = Vec<Webhook>),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn list_webhooks(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    let webhooks = WebhookRepository::list(&pool).await?;
+    Ok(Json(webhooks))
+}
+
+/// Retrieves a webhook.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::NotFound` if no webhook exists with the given ID.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = i64, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 200, description = "Webhook found", body = Webhook),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 404, description = "Webhook not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn get_webhook(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    let webhook = WebhookRepository::get(&pool, id).await?;
+    Ok(Json(webhook))
+}
+
+/// Updates a webhook's URL, events or active flag.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if the URL or events are invalid.
+/// Returns `ApiError::NotFound` if no webhook exists with the given ID.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    put,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = i64, Path, description = "Webhook ID")
+    ),
+    request_body = UpdateWebhook,
+    responses(
+        (status = 200, description = "Webhook updated successfully", body = Webhook),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 404, description = "Webhook not found"),
+        (status = 422, description = "Invalid webhook data"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn update_webhook(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+    Json(update_data): Json<UpdateWebhook>,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    let webhook = WebhookRepository::update(&pool, id, update_data).await?;
+    Ok(Json(webhook))
+}
+
+/// Deletes a webhook and its delivery log. Pending deliveries are dropped.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::NotFound` if no webhook exists with the given ID.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    delete,
+    path = "/webhooks/{id}",
+    params(
+        ("id" = i64, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 204, description = "Webhook deleted successfully"),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 404, description = "Webhook not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn delete_webhook(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    WebhookRepository::delete(&pool, id).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// Generates a new signing secret for a webhook, replacing the old one.
+///
+/// Deliveries sent from now on, including retries, are signed with the new secret.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::NotFound` if no webhook exists with the given ID.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    post,
+    path = "/webhooks/{id}/secret",
+    params(
+        ("id" = i64, Path, description = "Webhook ID")
+    ),
+    responses(
+        (status = 200, description = "Signing secret rotated", body = WebhookSecret),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 404, description = "Webhook not found"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn rotate_webhook_secret(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    let secret = WebhookRepository::rotate_secret(&pool, id).await?;
+    Ok(Json(secret))
+}
+
+/// Lists a webhook's most recent deliveries, newest first, with the outcome of each.
+///
+/// # Errors
+/// Returns `ApiError::Forbidden` if the key is restricted to specific games.
+/// Returns `ApiError::ValidationError` if query parameters are invalid.
+/// Returns `ApiError::NotFound` if no webhook exists with the given ID.
+/// Returns `ApiError::DatabaseError` if the database operation fails.
+#[utoipa::path(
+    get,
+    path = "/webhooks/{id}/deliveries",
+    params(
+        ("id" = i64, Path, description = "Webhook ID"),
+        DeliveryQueryParams
+    ),
+    responses(
+        (status = 200, description = "Deliveries of the webhook", body = Vec<WebhookDelivery>),
+        (status = 401, description = "Missing or invalid API key"),
+        (status = 403, description = "API key lacks the admin scope or is restricted to specific games"),
+        (status = 404, description = "Webhook not found"),
+        (status = 422, description = "Invalid query parameters"),
+        (status = 500, description = "Internal server error")
+    ),
+    security(
+        ("api_key" = [])
+    ),
+    tag = "Webhooks"
+)]
+pub async fn list_webhook_deliveries(
+    State(pool): State<DbPool>,
+    Extension(auth_key): Extension<AuthenticatedKey>,
+    Path(id): Path<i64>,
+    RawQuery(query_string): RawQuery,
+) -> Result<impl IntoResponse, ApiError> {
+    check_unrestricted(&auth_key)?;
+    let params = serde_urlencoded::from_str::<DeliveryQueryParams>(&query_string.unwrap_or_default())
+        .map_err(|e| ApiError::ValidationError(format!("Invalid query parameters: {e}")))?;
+
+    let deliveries = WebhookRepository::list_deliveries(&pool, id, &params).await?;
+    Ok(Json(deliveries))
+}
@@ -0,0 +1,133 @@
+use axum::{
+    body::Body,
+    extract::{Request, State},
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{Duration, Utc};
+
+use crate::{
+    auth::AuthenticatedKey,
+    db::{repository::IdempotencyRepository, DbPool},
+    error::ApiError,
+    models::{request_hash, validate_idempotency_key, IdempotencyClaim, StoredResponse},
+};
+
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+
+/// Set on responses replayed from an earlier request with the same idempotency key.
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Largest request body buffered to check it against an earlier request's; matches the
+/// default limit of the `Json` extractor.
+pub const MAX_IDEMPOTENT_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+#[derive(Clone)]
+pub struct IdempotencyState {
+    pub pool: DbPool,
+    /// How long a key and its response are remembered.
+    pub ttl: Duration,
+}
+
+impl IdempotencyState {
+    #[must_use]
+    pub fn new(pool: DbPool, ttl: Duration) -> Self {
+        Self { pool, ttl }
+    }
+}
+
+fn replay(stored: StoredResponse) -> Response {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::OK);
+    let mut response = (status, stored.body).into_response();
+    let headers = response.headers_mut();
+    headers.insert(header::CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+async fn release(pool: &DbPool, id: i64) {
+    if let Err(e) = IdempotencyRepository::release(pool, id).await {
+        tracing::error!("Failed to release idempotency key {id}: {e}");
+    }
+}
+
+/// Middleware making requests with an `Idempotency-Key` header safe to retry.
+///
+/// Keys are scoped to the API key and endpoint. The first request with a key runs and, if
+/// it succeeds, its response is saved for the state's TTL; a retry with the same body gets
+/// that response back instead of running again. Failed requests save nothing, so their
+/// retries run again. Requests without the header pass straight through.
+///
+/// Must run after [`crate::auth::api_key_middleware`].
+///
+/// # Errors
+/// Returns `ApiError::BadRequest` if the key is invalid or the body is too large to compare.
+/// Returns `ApiError::Conflict` if the key was used with a different body, or the request
+/// that first used it is still being processed.
+/// Returns `ApiError::DatabaseError` if the key cannot be looked up.
+pub async fn idempotency_middleware(
+    State(state): State<IdempotencyState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, ApiError> {
+    let Some(value) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(next.run(request).await);
+    };
+    let key = value
+        .to_str()
+        .map_err(|_| ApiError::BadRequest("Idempotency-Key must be printable ASCII".to_string()))?
+        .to_string();
+    validate_idempotency_key(&key).map_err(ApiError::BadRequest)?;
+
+    let api_key_id = request
+        .extensions()
+        .get::<AuthenticatedKey>()
+        .and_then(|auth_key| auth_key.id);
+    let endpoint = request.uri().path().to_string();
+
+    let (parts, body) = request.into_parts();
+    let body = axum::body::to_bytes(body, MAX_IDEMPOTENT_BODY_BYTES)
+        .await
+        .map_err(|_| ApiError::BadRequest("Request body is too large".to_string()))?;
+    let hash = request_hash(&body);
+
+    let claim =
+        IdempotencyRepository::claim(&state.pool, api_key_id, &endpoint, &key, &hash, Utc::now(), state.ttl)
+            .await?;
+    let id = match claim {
+        IdempotencyClaim::Claimed(id) => id,
+        IdempotencyClaim::Replay(stored) => return Ok(replay(stored)),
+        IdempotencyClaim::InProgress => {
+            return Err(ApiError::Conflict(
+                "A request with this Idempotency-Key is still being processed".to_string(),
+            ))
+        }
+        IdempotencyClaim::Mismatch => {
+            return Err(ApiError::Conflict(
+                "Idempotency-Key has already been used with a different request".to_string(),
+            ))
+        }
+    };
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    if !response.status().is_success() {
+        release(&state.pool, id).await;
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let Ok(body) = axum::body::to_bytes(body, usize::MAX).await else {
+        release(&state.pool, id).await;
+        return Err(ApiError::InternalServerError);
+    };
+    let stored = StoredResponse {
+        status: parts.status.as_u16(),
+        body: String::from_utf8_lossy(&body).into_owned(),
+    };
+    if let Err(e) = IdempotencyRepository::complete(&state.pool, id, &stored).await {
+        tracing::error!("Failed to save the response for idempotency key {id}: {e}");
+    }
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
@@ -1,11 +1,15 @@
 pub mod auth;
 pub mod db;
 pub mod error;
+pub mod events;
 pub mod handlers;
+pub mod idempotency;
 pub mod models;
 pub mod utils;
+pub mod webhooks;
 
 use axum::{
+    extract::{DefaultBodyLimit, FromRef},
     middleware,
     routing::{delete, get, post, put},
     Router,
@@ -18,7 +22,13 @@ use utoipa::{
 };
 use utoipa_swagger_ui::SwaggerUi;
 
-use crate::{auth::api_key_middleware, db::DbPool};
+use crate::{
+    auth::{api_key_middleware, AuthState},
+    db::DbPool,
+    events::ScoreEvents,
+    idempotency::{idempotency_middleware, IdempotencyState},
+    models::{idempotency_ttl_from_env, ApiKeyScope, DEFAULT_IDEMPOTENCY_TTL_SECS},
+};
 
 #[derive(OpenApi)]
 #[openapi(
@@ -27,37 +37,147 @@ use crate::{auth::api_key_middleware, db::DbPool};
         handlers::game::create_game,
         handlers::game::list_games,
         handlers::game::get_game,
+        handlers::game::get_game_stats,
+        handlers::stream::stream_events,
+        handlers::stream::stream_websocket,
         handlers::game::update_game,
         handlers::game::delete_game,
+        handlers::game::restore_game,
+        handlers::game::rotate_signing_secret,
+        handlers::game::clear_signing_secret,
         handlers::score::create_score,
+        handlers::score::create_score_batch,
         handlers::score::list_scores,
         handlers::score::get_score,
         handlers::score::update_score,
         handlers::score::delete_score,
-        handlers::export::export_data
+        handlers::score::restore_score,
+        handlers::leaderboard::create_leaderboard,
+        handlers::leaderboard::list_leaderboards,
+        handlers::ranking::get_player_rank,
+        handlers::ranking::get_leaderboard_around_player,
+        handlers::ranking::get_leaderboard_around_score,
+        handlers::season::create_season,
+        handlers::season::list_seasons,
+        handlers::season::get_season,
+        handlers::season::close_season,
+        handlers::season::list_season_standings,
+        handlers::export::export_data,
+        handlers::import::import_data,
+        handlers::purge::purge_deleted,
+        handlers::player::get_player,
+        handlers::player::update_player,
+        handlers::player::get_player_stats,
+        handlers::player::export_player_data,
+        handlers::player::erase_player,
+        handlers::api_key::create_api_key,
+        handlers::api_key::list_api_keys,
+        handlers::api_key::revoke_api_key,
+        handlers::webhook::create_webhook,
+        handlers::webhook::list_webhooks,
+        handlers::webhook::get_webhook,
+        handlers::webhook::update_webhook,
+        handlers::webhook::delete_webhook,
+        handlers::webhook::rotate_webhook_secret,
+        handlers::webhook::list_webhook_deliveries
     ),
     components(
         schemas(
             models::Game,
+            models::ScoreOrder,
+            models::SubmissionPolicy,
+            models::ScoreValidation,
             models::CreateGame,
             models::UpdateGame,
+            models::ScoreEvent,
+            models::GameStats,
+            models::GameStatsQueryParams,
+            models::ScoreValStats,
+            models::DailySubmissions,
+            models::HistogramBucket,
+            models::SigningSecret,
             models::Score,
             models::CreateScore,
             models::UpdateScore,
+            models::ScoreSubmission,
+            models::SubmissionOutcome,
+            models::CreateScoreBatch,
+            models::BatchScore,
+            models::BatchSignature,
+            models::BatchMode,
+            models::BatchSubmission,
+            models::BatchItemResult,
             models::PaginatedResponse<models::Game>,
             models::PaginatedResponse<models::Score>,
+            models::PaginatedResponse<models::SeasonStanding>,
             utils::pagination::PaginationParams,
+            utils::pagination::GameQueryParams,
             utils::pagination::ScoreQueryParams,
             utils::pagination::ScoreSortField,
-            utils::pagination::SortOrder
+            utils::pagination::SortOrder,
+            utils::pagination::TimeWindow,
+            handlers::export::ExportFormat,
+            models::ImportMode,
+            models::ImportFormat,
+            models::ImportQueryParams,
+            models::PurgeQueryParams,
+            models::PurgeReport,
+            models::Player,
+            models::UpdatePlayer,
+            models::PlayerStats,
+            models::PlayerStatsSummary,
+            models::PlayerGameStats,
+            models::PlayerData,
+            models::ErasureMode,
+            models::ErasePlayerParams,
+            models::PlayerErasure,
+            models::ImportReport,
+            models::ImportCounts,
+            models::ImportIssue,
+            models::ImportIssueKind,
+            models::ImportRecordKind,
+            models::RankPolicy,
+            models::RankQueryParams,
+            models::PlayerRankQueryParams,
+            models::PlayerRank,
+            models::AroundQueryParams,
+            models::LeaderboardWindow,
+            models::Leaderboard,
+            models::CreateLeaderboard,
+            models::Season,
+            models::CreateSeason,
+            models::SeasonStanding,
+            models::SeasonStandingsQueryParams,
+            models::ApiKey,
+            models::ApiKeyScope,
+            models::CreateApiKey,
+            models::CreatedApiKey,
+            models::Webhook,
+            models::WebhookEvent,
+            models::CreateWebhook,
+            models::CreatedWebhook,
+            models::UpdateWebhook,
+            models::WebhookSecret,
+            models::WebhookDelivery,
+            models::DeliveryStatus,
+            models::DeliveryQueryParams
         )
     ),
     modifiers(&SecurityAddon),
     tags(
         (name = "Health", description = "Health check endpoint"),
         (name = "Games", description = "Game/Leaderboard management"),
+        (name = "Leaderboards", description = "Separate boards within a game, e.g. per level or difficulty"),
         (name = "Scores", description = "Score management"),
-        (name = "Export", description = "Data export operations")
+        (name = "Rankings", description = "Player ranks and leaderboard positions"),
+        (name = "Streaming", description = "Real-time score events over Server-Sent Events and WebSocket"),
+        (name = "Seasons", description = "Scheduled seasons and archived standings"),
+        (name = "Export", description = "Data export operations"),
+        (name = "Import", description = "Data import operations"),
+        (name = "Purge", description = "Permanent removal of soft-deleted data"),
+        (name = "Players", description = "Player profiles, data access and erasure requests"),
+        (name = "API Keys", description = "Scoped API key management (admin only)"),
+        (name = "Webhooks", description = "Signed HTTP callbacks on score and game events (admin only)")
     ),
     info(
         title = "LEADR API",
@@ -84,30 +204,152 @@ impl Modify for SecurityAddon {
     }
 }
 
+/// State shared by every handler; handlers extract the part they need.
+#[derive(Clone)]
+pub struct AppState {
+    pub pool: DbPool,
+    pub events: ScoreEvents,
+}
+
+impl FromRef<AppState> for DbPool {
+    fn from_ref(state: &AppState) -> Self {
+        state.pool.clone()
+    }
+}
+
+impl FromRef<AppState> for ScoreEvents {
+    fn from_ref(state: &AppState) -> Self {
+        state.events.clone()
+    }
+}
+
 pub fn create_app(pool: DbPool) -> Router {
     // Public routes (no auth required)
     let public_routes = Router::new().route("/health", get(handlers::health::health_check));
 
-    // Protected routes (require API key)
-    let protected_routes = Router::new()
+    // Read-only routes, open to every key scope
+    let read_routes = Router::new()
         .route("/games", get(handlers::game::list_games))
-        .route("/games", post(handlers::game::create_game))
         .route("/games/:hex_id", get(handlers::game::get_game))
-        .route("/games/:hex_id", put(handlers::game::update_game))
-        .route("/games/:hex_id", delete(handlers::game::delete_game))
+        .route("/games/:hex_id/stats", get(handlers::game::get_game_stats))
+        .route("/games/:hex_id/stream", get(handlers::stream::stream_events))
+        .route("/games/:hex_id/ws", get(handlers::stream::stream_websocket))
+        .route(
+            "/games/:hex_id/ranks/:user_id",
+            get(handlers::ranking::get_player_rank),
+        )
+        .route(
+            "/games/:hex_id/leaderboard/around",
+            get(handlers::ranking::get_leaderboard_around_score),
+        )
+        .route(
+            "/games/:hex_id/leaderboard/around/:user_id",
+            get(handlers::ranking::get_leaderboard_around_player),
+        )
+        .route(
+            "/games/:hex_id/leaderboards",
+            get(handlers::leaderboard::list_leaderboards),
+        )
+        .route("/games/:hex_id/seasons", get(handlers::season::list_seasons))
+        .route(
+            "/games/:hex_id/seasons/:season_id",
+            get(handlers::season::get_season),
+        )
+        .route(
+            "/games/:hex_id/seasons/:season_id/standings",
+            get(handlers::season::list_season_standings),
+        )
         .route("/scores", get(handlers::score::list_scores))
-        .route("/scores", post(handlers::score::create_score))
         .route("/scores/:id", get(handlers::score::get_score))
+        .route("/players/:user_id", get(handlers::player::get_player))
+        .route("/players/:user_id/stats", get(handlers::player::get_player_stats))
+        .route_layer(middleware::from_fn_with_state(
+            AuthState::new(pool.clone(), ApiKeyScope::Read),
+            api_key_middleware,
+        ));
+
+    // Score submission, for keys shipped in game clients; the idempotency layer runs after
+    // authentication, as it scopes keys to the API key
+    let submit_routes = Router::new()
+        .route("/scores", post(handlers::score::create_score))
+        .route("/scores/batch", post(handlers::score::create_score_batch))
+        .route_layer(middleware::from_fn_with_state(
+            IdempotencyState::new(
+                pool.clone(),
+                idempotency_ttl_from_env()
+                    .unwrap_or_else(|_| chrono::Duration::seconds(DEFAULT_IDEMPOTENCY_TTL_SECS)),
+            ),
+            idempotency_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            AuthState::new(pool.clone(), ApiKeyScope::Submit),
+            api_key_middleware,
+        ));
+
+    // Management routes, admin keys only
+    let admin_routes = Router::new()
+        .route("/games", post(handlers::game::create_game))
+        .route("/games/:hex_id", put(handlers::game::update_game))
+        .route("/games/:hex_id", delete(handlers::game::delete_game))
+        .route("/games/:hex_id/restore", post(handlers::game::restore_game))
+        .route(
+            "/games/:hex_id/signing-secret",
+            post(handlers::game::rotate_signing_secret),
+        )
+        .route(
+            "/games/:hex_id/signing-secret",
+            delete(handlers::game::clear_signing_secret),
+        )
+        .route(
+            "/games/:hex_id/leaderboards",
+            post(handlers::leaderboard::create_leaderboard),
+        )
+        .route("/games/:hex_id/seasons", post(handlers::season::create_season))
+        .route(
+            "/games/:hex_id/seasons/:season_id/close",
+            post(handlers::season::close_season),
+        )
         .route("/scores/:id", put(handlers::score::update_score))
         .route("/scores/:id", delete(handlers::score::delete_score))
+        .route("/scores/:id/restore", post(handlers::score::restore_score))
         .route("/export", get(handlers::export::export_data))
-        .layer(middleware::from_fn(api_key_middleware));
+        .route(
+            "/import",
+            post(handlers::import::import_data)
+                .layer(DefaultBodyLimit::max(handlers::import::IMPORT_MAX_BYTES)),
+        )
+        .route("/purge", post(handlers::purge::purge_deleted))
+        .route("/players/:user_id/data", get(handlers::player::export_player_data))
+        .route("/players/:user_id", put(handlers::player::update_player))
+        .route("/players/:user_id", delete(handlers::player::erase_player))
+        .route("/api-keys", get(handlers::api_key::list_api_keys))
+        .route("/api-keys", post(handlers::api_key::create_api_key))
+        .route("/api-keys/:id", delete(handlers::api_key::revoke_api_key))
+        .route("/webhooks", get(handlers::webhook::list_webhooks))
+        .route("/webhooks", post(handlers::webhook::create_webhook))
+        .route("/webhooks/:id", get(handlers::webhook::get_webhook))
+        .route("/webhooks/:id", put(handlers::webhook::update_webhook))
+        .route("/webhooks/:id", delete(handlers::webhook::delete_webhook))
+        .route("/webhooks/:id/secret", post(handlers::webhook::rotate_webhook_secret))
+        .route(
+            "/webhooks/:id/deliveries",
+            get(handlers::webhook::list_webhook_deliveries),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            AuthState::new(pool.clone(), ApiKeyScope::Admin),
+            api_key_middleware,
+        ));
 
     Router::new()
         .merge(public_routes)
-        .merge(protected_routes)
+        .merge(read_routes)
+        .merge(submit_routes)
+        .merge(admin_routes)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
-        .with_state(pool)
+        .with_state(AppState {
+            pool,
+            events: ScoreEvents::default(),
+        })
         .layer(CorsLayer::permissive())
         .layer(TraceLayer::new_for_http())
 }
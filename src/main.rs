@@ -1,4 +1,76 @@
-use leadr_api::{create_app, db};
+use std::time::Duration;
+
+use chrono::Utc;
+use leadr_api::{
+    create_app,
+    db::{
+        self,
+        repository::{PurgeRepository, SeasonRepository},
+        DbPool,
+    },
+    models::{hex_id_len_from_env, idempotency_ttl_from_env, purge_after_days_from_env, retention_cutoff},
+    webhooks,
+};
+
+/// How often the background task looks for seasons past their end time.
+const SEASON_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How often the background task purges soft-deleted records past their retention period.
+const PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How often the background task sends webhook deliveries that are due.
+const WEBHOOK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Periodically closes seasons whose end time has passed, archiving their final standings.
+async fn close_expired_seasons(pool: DbPool) {
+    let mut interval = tokio::time::interval(SEASON_CHECK_INTERVAL);
+    loop {
+        interval.tick().await;
+        match SeasonRepository::close_expired(&pool).await {
+            Ok(closed) => {
+                for season in closed {
+                    tracing::info!(
+                        "Closed season {} ({}) on game {}",
+                        season.id,
+                        season.name,
+                        season.game_hex_id
+                    );
+                }
+            }
+            Err(e) => tracing::error!("Failed to close expired seasons: {e}"),
+        }
+    }
+}
+
+/// Periodically and permanently removes records soft-deleted more than `days` days ago.
+async fn purge_expired_deletions(pool: DbPool, days: u32) {
+    let mut interval = tokio::time::interval(PURGE_INTERVAL);
+    loop {
+        interval.tick().await;
+        match PurgeRepository::purge(&pool, retention_cutoff(Utc::now(), days)).await {
+            Ok(report) if report.games > 0 || report.scores > 0 => tracing::info!(
+                "Purged {} games and {} scores deleted before {}",
+                report.games,
+                report.scores,
+                report.before
+            ),
+            Ok(_) => {}
+            Err(e) => tracing::error!("Failed to purge deleted records: {e}"),
+        }
+    }
+}
+
+/// Periodically sends pending webhook deliveries, retrying failed ones with backoff.
+async fn deliver_webhooks(pool: DbPool) {
+    let client = webhooks::client();
+    let mut interval = tokio::time::interval(WEBHOOK_POLL_INTERVAL);
+    loop {
+        interval.tick().await;
+        if let Err(e) = webhooks::deliver_due(&pool, &client).await {
+            tracing::error!("Failed to deliver webhooks: {e}");
+        }
+    }
+}
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -15,9 +87,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         std::process::exit(1);
     });
 
+    let purge_after_days = purge_after_days_from_env().unwrap_or_else(|e| {
+        eprintln!("ERROR: {e}");
+        std::process::exit(1);
+    });
+
+    if let Err(e) = idempotency_ttl_from_env().and(hex_id_len_from_env().map(|_| ())) {
+        eprintln!("ERROR: {e}");
+        std::process::exit(1);
+    }
+
     // Initialize database with proper lifecycle management
     let pool = db::initialize_database().await?;
 
+    tokio::spawn(close_expired_seasons(pool.clone()));
+    tokio::spawn(deliver_webhooks(pool.clone()));
+    if let Some(days) = purge_after_days {
+        tracing::info!("Purging soft-deleted records after {days} days");
+        tokio::spawn(purge_expired_deletions(pool.clone(), days));
+    }
+
     let app = create_app(pool);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:3000").await?;
@@ -0,0 +1,156 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// What an API key may do. Each scope includes everything the scopes below it allow.
+#[derive(
+    Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema,
+)]
+pub enum ApiKeyScope {
+    /// Read games, leaderboards, ranks and seasons.
+    #[serde(rename = "read")]
+    Read,
+    /// Read access plus submitting scores; suitable for shipping in a game client.
+    #[serde(rename = "submit")]
+    Submit,
+    /// Full access, including managing games, scores, exports and API keys.
+    #[serde(rename = "admin")]
+    Admin,
+}
+
+impl ApiKeyScope {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ApiKeyScope::Read => "read",
+            ApiKeyScope::Submit => "submit",
+            ApiKeyScope::Admin => "admin",
+        }
+    }
+
+    /// Whether a key with this scope may access a route requiring `required`.
+    #[must_use]
+    pub fn allows(self, required: ApiKeyScope) -> bool {
+        self >= required
+    }
+}
+
+impl std::str::FromStr for ApiKeyScope {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "read" => Ok(ApiKeyScope::Read),
+            "submit" => Ok(ApiKeyScope::Submit),
+            "admin" => Ok(ApiKeyScope::Admin),
+            _ => Err(format!("Invalid API key scope: {s}")),
+        }
+    }
+}
+
+/// A stored API key. The key itself is only kept as a hash.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ApiKey {
+    pub id: i64,
+    pub name: String,
+    /// First characters of the key, to help identify it.
+    pub prefix: String,
+    pub scope: ApiKeyScope,
+    /// Games this key is restricted to; empty means every game.
+    pub game_hex_ids: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub revoked_at: Option<DateTime<Utc>>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct ApiKeyRow {
+    pub id: i64,
+    pub name: String,
+    pub prefix: String,
+    pub scope: String,
+    pub created_at: chrono::NaiveDateTime,
+    pub revoked_at: Option<chrono::NaiveDateTime>,
+}
+
+impl TryFrom<ApiKeyRow> for ApiKey {
+    type Error = String;
+
+    /// Fails on a scope the server does not know, so a corrupt row never becomes a usable key.
+    fn try_from(row: ApiKeyRow) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: row.id,
+            name: row.name,
+            prefix: row.prefix,
+            scope: row.scope.parse()?,
+            game_hex_ids: Vec::new(),
+            created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
+            revoked_at: row
+                .revoked_at
+                .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc)),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateApiKey {
+    pub name: String,
+    pub scope: ApiKeyScope,
+    /// Restrict the key to these games; omit to allow every game.
+    #[serde(default)]
+    pub game_hex_ids: Vec<String>,
+}
+
+/// A newly created API key. The plaintext `key` is only ever returned here.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatedApiKey {
+    #[serde(flatten)]
+    pub api_key: ApiKey,
+    pub key: String,
+}
+
+/// Length of the key prefix stored for identification.
+pub const API_KEY_PREFIX_LEN: usize = 12;
+
+impl ApiKey {
+    /// Generates a new random plaintext key.
+    #[must_use]
+    pub fn generate_key() -> String {
+        use rand::distributions::Alphanumeric;
+        use rand::Rng;
+
+        let secret: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(40)
+            .map(char::from)
+            .collect();
+        format!("leadr_{secret}")
+    }
+
+    /// Hashes a plaintext key for storage and lookup.
+    #[must_use]
+    pub fn hash_key(key: &str) -> String {
+        use sha2::{Digest, Sha256};
+
+        hex::encode(Sha256::digest(key.as_bytes()))
+    }
+
+    /// Validates that an API key name meets the requirements.
+    ///
+    /// # Errors
+    /// Returns an error string if the name is empty or exceeds 255 characters.
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if name.trim().is_empty() {
+            return Err("API key name cannot be empty".to_string());
+        }
+        if name.len() > 255 {
+            return Err("API key name cannot exceed 255 characters".to_string());
+        }
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at.is_some()
+    }
+}
@@ -0,0 +1,16 @@
+/// Sensitive admin actions recorded in the audit log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    PlayerDataExport,
+    PlayerErasure,
+}
+
+impl AuditAction {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::PlayerDataExport => "player.data_export",
+            AuditAction::PlayerErasure => "player.erasure",
+        }
+    }
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::{CreateScore, Game, Score, ScoreSubmission, SubmissionOutcome};
+
+/// Most scores accepted in one batch.
+pub const MAX_BATCH_SIZE: usize = 100;
+
+/// How far ahead of the server clock a batch item's `submitted_at` may be, in seconds.
+pub const MAX_SUBMITTED_AT_SKEW_SECS: i64 = 300;
+
+/// What happens to a batch when some of its items fail.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum BatchMode {
+    /// Save every item or none of them.
+    #[serde(rename = "all_or_nothing")]
+    #[default]
+    AllOrNothing,
+    /// Save the items that succeed and report the ones that fail.
+    #[serde(rename = "partial")]
+    Partial,
+}
+
+/// The signature of one batch item, computed exactly like the `POST /scores` signature headers.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BatchSignature {
+    /// Unix time, in seconds, at which the item was signed.
+    pub timestamp: i64,
+    /// Single-use value; a nonce is only accepted once per game.
+    pub nonce: String,
+    /// Hex-encoded HMAC-SHA256 of the signed message.
+    pub signature: String,
+}
+
+/// One score of a batch.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct BatchScore {
+    #[serde(flatten)]
+    pub score: CreateScore,
+    /// When the score was achieved, e.g. while the client was offline; defaults to now.
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// Required for games with a signing secret.
+    pub signature: Option<BatchSignature>,
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateScoreBatch {
+    /// Defaults to `all_or_nothing`.
+    #[serde(default)]
+    pub mode: BatchMode,
+    pub scores: Vec<BatchScore>,
+}
+
+impl CreateScoreBatch {
+    /// Checks the batch holds between one and `MAX_BATCH_SIZE` scores.
+    ///
+    /// # Errors
+    /// Returns an error if the batch is empty or too large.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.scores.is_empty() {
+            return Err("A batch must contain at least one score".to_string());
+        }
+        if self.scores.len() > MAX_BATCH_SIZE {
+            return Err(format!(
+                "A batch cannot contain more than {MAX_BATCH_SIZE} scores"
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl BatchScore {
+    /// The item's `submitted_at`, or `now` if it has none.
+    ///
+    /// # Errors
+    /// Returns an error if `submitted_at` is more than `MAX_SUBMITTED_AT_SKEW_SECS` after `now`.
+    pub fn resolve_submitted_at(&self, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        match self.submitted_at {
+            Some(submitted_at) if (submitted_at - now).num_seconds() > MAX_SUBMITTED_AT_SKEW_SECS => {
+                Err("submitted_at cannot be in the future".to_string())
+            }
+            Some(submitted_at) => Ok(submitted_at),
+            None => Ok(now),
+        }
+    }
+}
+
+/// A batch item that passed the checks made before the batch's transaction.
+#[derive(Debug, Clone)]
+pub struct PreparedBatchScore {
+    pub game: Game,
+    pub score: CreateScore,
+    pub submitted_at: DateTime<Utc>,
+    /// Nonce of a verified signature, recorded along with the score.
+    pub nonce: Option<String>,
+}
+
+/// What happened to one item of a batch.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BatchItemResult {
+    /// Position of the item in the request, from 0.
+    pub index: usize,
+    /// The status `POST /scores` would have answered with for this item alone.
+    pub status: u16,
+    /// How the game's submission policy applied the item; `None` if it failed.
+    pub outcome: Option<SubmissionOutcome>,
+    pub is_personal_best: Option<bool>,
+    /// The saved entry; `None` if the item failed or the batch was not committed.
+    pub score: Option<Score>,
+    pub error: Option<String>,
+}
+
+impl BatchItemResult {
+    #[must_use]
+    pub fn succeeded(index: usize, submission: ScoreSubmission) -> Self {
+        let status = match submission.outcome {
+            SubmissionOutcome::Created => 201,
+            SubmissionOutcome::Replaced | SubmissionOutcome::Unchanged => 200,
+        };
+        Self {
+            index,
+            status,
+            outcome: Some(submission.outcome),
+            is_personal_best: Some(submission.is_personal_best),
+            score: Some(submission.score),
+            error: None,
+        }
+    }
+
+    #[must_use]
+    pub fn failed(index: usize, status: u16, error: String) -> Self {
+        Self {
+            index,
+            status,
+            outcome: None,
+            is_personal_best: None,
+            score: None,
+            error: Some(error),
+        }
+    }
+}
+
+/// The result of a batch submission. When it was not committed, the tallies and results
+/// describe what saving it would have done.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct BatchSubmission {
+    pub mode: BatchMode,
+    /// Whether anything was saved; `false` when an `all_or_nothing` batch had a failure.
+    pub committed: bool,
+    pub created: u64,
+    pub replaced: u64,
+    pub unchanged: u64,
+    pub failed: u64,
+    /// One result per item, in request order.
+    pub results: Vec<BatchItemResult>,
+}
+
+impl BatchSubmission {
+    /// Tallies the results of a batch.
+    #[must_use]
+    pub fn new(mode: BatchMode, committed: bool, results: Vec<BatchItemResult>) -> Self {
+        let count = |outcome: Option<SubmissionOutcome>| {
+            results.iter().filter(|result| result.outcome == outcome).count() as u64
+        };
+        Self {
+            mode,
+            committed,
+            created: count(Some(SubmissionOutcome::Created)),
+            replaced: count(Some(SubmissionOutcome::Replaced)),
+            unchanged: count(Some(SubmissionOutcome::Unchanged)),
+            failed: count(None),
+            results,
+        }
+    }
+}
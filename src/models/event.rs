@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::models::Score;
+
+/// Number of entries watched for `top_changed` events.
+pub const TOP_SCORES_LIMIT: i64 = 10;
+
+/// A change to a game's board, pushed to stream subscribers.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScoreEvent {
+    /// A submission added a new entry.
+    ScoreCreated { score: Score },
+    /// An entry was edited, or replaced by a submission under `keep_best`/`keep_latest`.
+    ScoreUpdated { score: Score },
+    /// An entry was soft-deleted.
+    ScoreDeleted { game_hex_id: String, score_id: i64 },
+    /// The top entries of one of the game's boards changed; `scores` is the board's new
+    /// top, best first, with ranks.
+    TopChanged {
+        game_hex_id: String,
+        leaderboard_id: i64,
+        scores: Vec<Score>,
+    },
+}
+
+impl ScoreEvent {
+    #[must_use]
+    pub fn game_hex_id(&self) -> &str {
+        match self {
+            ScoreEvent::ScoreCreated { score } | ScoreEvent::ScoreUpdated { score } => &score.game_hex_id,
+            ScoreEvent::ScoreDeleted { game_hex_id, .. } | ScoreEvent::TopChanged { game_hex_id, .. } => {
+                game_hex_id
+            }
+        }
+    }
+
+    /// The event's `type`, used as the SSE event name.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            ScoreEvent::ScoreCreated { .. } => "score_created",
+            ScoreEvent::ScoreUpdated { .. } => "score_updated",
+            ScoreEvent::ScoreDeleted { .. } => "score_deleted",
+            ScoreEvent::TopChanged { .. } => "top_changed",
+        }
+    }
+}
@@ -1,16 +1,272 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock};
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
 use utoipa::ToSchema;
 
+use crate::utils::pagination::{ScoreSortField, SortOrder};
+
+/// Environment variable holding the length of newly generated game ids.
+pub const HEX_ID_LEN_VAR: &str = "LEADR_GAME_ID_LENGTH";
+
+/// Length of generated game ids when `LEADR_GAME_ID_LENGTH` is unset.
+pub const DEFAULT_HEX_ID_LEN: usize = 6;
+
+/// Shortest accepted game id.
+pub const MIN_HEX_ID_LEN: usize = 6;
+
+/// Longest accepted game id.
+pub const MAX_HEX_ID_LEN: usize = 32;
+
+/// How many ids are generated for a new game before giving up on finding a free one.
+pub const HEX_ID_ATTEMPTS: usize = 5;
+
+/// Shortest accepted game slug.
+pub const MIN_SLUG_LEN: usize = 3;
+
+/// Longest accepted game slug.
+pub const MAX_SLUG_LEN: usize = 64;
+
+/// Reads the length of generated game ids from `LEADR_GAME_ID_LENGTH`, falling back to the
+/// default if unset or empty.
+///
+/// # Errors
+/// Returns an error if the variable is not a whole number between `MIN_HEX_ID_LEN` and `MAX_HEX_ID_LEN`.
+pub fn hex_id_len_from_env() -> Result<usize, String> {
+    match std::env::var(HEX_ID_LEN_VAR) {
+        Ok(value) if !value.trim().is_empty() => match value.trim().parse::<usize>() {
+            Ok(len) if (MIN_HEX_ID_LEN..=MAX_HEX_ID_LEN).contains(&len) => Ok(len),
+            _ => Err(format!(
+                "{HEX_ID_LEN_VAR} must be a whole number from {MIN_HEX_ID_LEN} to {MAX_HEX_ID_LEN}, got '{value}'"
+            )),
+        },
+        _ => Ok(DEFAULT_HEX_ID_LEN),
+    }
+}
+
+/// Which end of a game's board is the best.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum ScoreOrder {
+    #[serde(rename = "higher_is_better")]
+    #[default]
+    HigherIsBetter,
+    #[serde(rename = "lower_is_better")]
+    LowerIsBetter,
+}
+
+impl ScoreOrder {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ScoreOrder::HigherIsBetter => "higher_is_better",
+            ScoreOrder::LowerIsBetter => "lower_is_better",
+        }
+    }
+
+    /// The sort order that lists the best scores first.
+    #[must_use]
+    pub fn best_first(self) -> SortOrder {
+        match self {
+            ScoreOrder::HigherIsBetter => SortOrder::Descending,
+            ScoreOrder::LowerIsBetter => SortOrder::Ascending,
+        }
+    }
+
+    /// Whether `candidate` beats `current` on a board with this order.
+    #[must_use]
+    pub fn is_better(self, candidate: f64, current: f64) -> bool {
+        match self {
+            ScoreOrder::HigherIsBetter => candidate > current,
+            ScoreOrder::LowerIsBetter => candidate < current,
+        }
+    }
+
+    /// SQL `ORDER BY` term ranking `score_val` best first.
+    #[must_use]
+    pub fn to_sql_rank_order(self) -> &'static str {
+        match self {
+            ScoreOrder::HigherIsBetter => "score_val DESC",
+            ScoreOrder::LowerIsBetter => "score_val ASC",
+        }
+    }
+}
+
+impl std::str::FromStr for ScoreOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "higher_is_better" => Ok(ScoreOrder::HigherIsBetter),
+            "lower_is_better" => Ok(ScoreOrder::LowerIsBetter),
+            _ => Err(format!("Invalid score order: {s}")),
+        }
+    }
+}
+
+/// How repeat submissions from the same player are stored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum SubmissionPolicy {
+    /// Every submission adds a new entry.
+    #[serde(rename = "keep_all")]
+    #[default]
+    KeepAll,
+    /// One entry per player, replaced only when beaten.
+    #[serde(rename = "keep_best")]
+    KeepBest,
+    /// One entry per player, replaced by every submission.
+    #[serde(rename = "keep_latest")]
+    KeepLatest,
+}
+
+impl SubmissionPolicy {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SubmissionPolicy::KeepAll => "keep_all",
+            SubmissionPolicy::KeepBest => "keep_best",
+            SubmissionPolicy::KeepLatest => "keep_latest",
+        }
+    }
+}
+
+impl std::str::FromStr for SubmissionPolicy {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep_all" => Ok(SubmissionPolicy::KeepAll),
+            "keep_best" => Ok(SubmissionPolicy::KeepBest),
+            "keep_latest" => Ok(SubmissionPolicy::KeepLatest),
+            _ => Err(format!("Invalid submission policy: {s}")),
+        }
+    }
+}
+
+/// Server-side rules every score submitted to a game must satisfy.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, ToSchema)]
+pub struct ScoreValidation {
+    /// Lowest accepted `score_val`, inclusive.
+    #[serde(default)]
+    pub min_score_val: Option<f64>,
+    /// Highest accepted `score_val`, inclusive.
+    #[serde(default)]
+    pub max_score_val: Option<f64>,
+    /// Reject `score_val`s with a fractional part.
+    #[serde(default)]
+    pub integer_only: bool,
+    /// Most submissions accepted per `user_id` in any rolling minute.
+    #[serde(default)]
+    pub max_submissions_per_minute: Option<i64>,
+    /// JSON Schema the `extra` field must match when present.
+    #[serde(default)]
+    pub extra_schema: Option<JsonValue>,
+}
+
+impl ScoreValidation {
+    /// Checks that the rules themselves are consistent.
+    ///
+    /// # Errors
+    /// Returns an error string if a bound is not finite, the bounds are reversed, the
+    /// rate limit is not positive or `extra_schema` is not a valid JSON Schema.
+    pub fn validate(&self) -> Result<(), String> {
+        for bound in [self.min_score_val, self.max_score_val].into_iter().flatten() {
+            if !bound.is_finite() {
+                return Err("Score bounds must be finite numbers".to_string());
+            }
+        }
+        if let (Some(min), Some(max)) = (self.min_score_val, self.max_score_val) {
+            if min > max {
+                return Err("min_score_val cannot be greater than max_score_val".to_string());
+            }
+        }
+        if self.max_submissions_per_minute.is_some_and(|limit| limit < 1) {
+            return Err("max_submissions_per_minute must be at least 1".to_string());
+        }
+        if let Some(ref schema) = self.extra_schema {
+            compiled_schema(schema)?;
+        }
+        Ok(())
+    }
+
+    /// Checks a submitted score value and `extra` payload against the rules.
+    ///
+    /// Non-finite values are always rejected, whatever the rules say.
+    ///
+    /// # Errors
+    /// Returns an error string describing the first rule the submission breaks.
+    pub fn check_score(&self, score_val: f64, extra: Option<&JsonValue>) -> Result<(), String> {
+        if !score_val.is_finite() {
+            return Err("Score value must be a finite number".to_string());
+        }
+        if let Some(min) = self.min_score_val {
+            if score_val < min {
+                return Err(format!("Score value {score_val} is below the minimum of {min}"));
+            }
+        }
+        if let Some(max) = self.max_score_val {
+            if score_val > max {
+                return Err(format!("Score value {score_val} is above the maximum of {max}"));
+            }
+        }
+        if self.integer_only && score_val.fract() != 0.0 {
+            return Err(format!("Score value {score_val} must be a whole number"));
+        }
+        if let (Some(schema), Some(extra)) = (&self.extra_schema, extra) {
+            compiled_schema(schema)?.validate(extra).map_err(|e| {
+                format!("Extra field does not match the game's schema at '{}': {e}", e.instance_path)
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the compiled validator for an `extra_schema`, compiling it only the first time
+/// that schema is seen so submissions don't pay for it again.
+///
+/// # Errors
+/// Returns an error string if the schema is not a valid JSON Schema.
+fn compiled_schema(schema: &JsonValue) -> Result<Arc<jsonschema::Validator>, String> {
+    static SCHEMAS: OnceLock<Mutex<HashMap<String, Arc<jsonschema::Validator>>>> = OnceLock::new();
+
+    let key = schema.to_string();
+    let cache = SCHEMAS.get_or_init(Mutex::default);
+    if let Some(validator) = cache.lock().unwrap_or_else(|e| e.into_inner()).get(&key) {
+        return Ok(Arc::clone(validator));
+    }
+    let validator = Arc::new(
+        jsonschema::validator_for(schema).map_err(|e| format!("Invalid extra_schema: {e}"))?,
+    );
+    cache
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(key, Arc::clone(&validator));
+    Ok(validator)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
 pub struct Game {
     pub id: i64,
     pub hex_id: String,
+    /// Optional human-readable identifier, accepted anywhere `hex_id` is in a `/games/{id}` path.
+    #[serde(default)]
+    pub slug: Option<String>,
     pub name: String,
     pub description: Option<String>,
+    pub score_order: ScoreOrder,
+    pub default_sort_by: ScoreSortField,
+    pub submission_policy: SubmissionPolicy,
+    pub validation: ScoreValidation,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
+    /// Live scores on the game; only set when a listing asks for counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score_count: Option<i64>,
+    /// Distinct players with live scores; only set when a listing asks for counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub player_count: Option<i64>,
 }
 
 // Database representation with proper SQLite types
@@ -18,8 +274,17 @@ pub struct Game {
 pub struct GameRow {
     pub id: i64,
     pub hex_id: String,
+    pub slug: Option<String>,
     pub name: String,
     pub description: Option<String>,
+    pub score_order: String,
+    pub default_sort_by: String,
+    pub submission_policy: String,
+    pub min_score_val: Option<f64>,
+    pub max_score_val: Option<f64>,
+    pub integer_only: bool,
+    pub max_submissions_per_minute: Option<i64>,
+    pub extra_schema: Option<String>, // JSON stored as TEXT
     pub created_at: chrono::NaiveDateTime,
     pub updated_at: chrono::NaiveDateTime,
     pub deleted_at: Option<chrono::NaiveDateTime>,
@@ -30,13 +295,26 @@ impl From<GameRow> for Game {
         Self {
             id: row.id,
             hex_id: row.hex_id,
+            slug: row.slug,
             name: row.name,
             description: row.description,
+            score_order: row.score_order.parse().unwrap_or_default(),
+            default_sort_by: row.default_sort_by.parse().unwrap_or_default(),
+            submission_policy: row.submission_policy.parse().unwrap_or_default(),
+            validation: ScoreValidation {
+                min_score_val: row.min_score_val,
+                max_score_val: row.max_score_val,
+                integer_only: row.integer_only,
+                max_submissions_per_minute: row.max_submissions_per_minute,
+                extra_schema: row.extra_schema.and_then(|s| serde_json::from_str(&s).ok()),
+            },
             created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
             updated_at: DateTime::from_naive_utc_and_offset(row.updated_at, Utc),
             deleted_at: row
                 .deleted_at
                 .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc)),
+            score_count: None,
+            player_count: None,
         }
     }
 }
@@ -44,21 +322,56 @@ impl From<GameRow> for Game {
 #[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateGame {
     pub name: String,
+    /// Lowercase letters, digits and hyphens, e.g. `space-invaders-hard`.
+    pub slug: Option<String>,
     pub description: Option<String>,
+    pub score_order: Option<ScoreOrder>,
+    pub default_sort_by: Option<ScoreSortField>,
+    pub submission_policy: Option<SubmissionPolicy>,
+    pub validation: Option<ScoreValidation>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateGame {
     pub name: Option<String>,
+    /// New slug; an empty string removes it.
+    pub slug: Option<String>,
     pub description: Option<String>,
+    pub score_order: Option<ScoreOrder>,
+    pub default_sort_by: Option<ScoreSortField>,
+    pub submission_policy: Option<SubmissionPolicy>,
+    pub validation: Option<ScoreValidation>,
+}
+
+/// A game's score signing secret. The secret is only ever returned here, when it is generated.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SigningSecret {
+    pub game_hex_id: String,
+    pub signing_secret: String,
 }
 
 impl Game {
+    /// Generates a new random secret for signing score submissions.
+    #[must_use]
+    pub fn generate_signing_secret() -> String {
+        use rand::RngCore;
+
+        let mut bytes = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut bytes);
+        hex::encode(bytes)
+    }
+
     #[must_use]
     pub fn generate_hex_id() -> String {
+        Self::generate_hex_id_with_len(DEFAULT_HEX_ID_LEN)
+    }
+
+    /// Generates a random id of `len` lowercase hex digits.
+    #[must_use]
+    pub fn generate_hex_id_with_len(len: usize) -> String {
         use rand::Rng;
         let mut rng = rand::thread_rng();
-        (0..6)
+        (0..len)
             .map(|_| format!("{:x}", rng.gen_range(0..16)))
             .collect()
     }
@@ -69,21 +382,30 @@ impl Game {
         Self {
             id: 0, // Will be set by database
             hex_id: Self::generate_hex_id(),
+            slug: None,
             name,
             description,
+            score_order: ScoreOrder::default(),
+            default_sort_by: ScoreSortField::default(),
+            submission_policy: SubmissionPolicy::default(),
+            validation: ScoreValidation::default(),
             created_at: now,
             updated_at: now,
             deleted_at: None,
+            score_count: None,
+            player_count: None,
         }
     }
 
     /// Normalizes and validates a hex ID. Converts to lowercase and validates format.
     /// 
     /// # Errors
-    /// Returns an error string if the hex ID is not exactly 6 characters or contains invalid characters.
+    /// Returns an error string if the hex ID is not 6 to 32 characters long or contains invalid characters.
     pub fn normalize_and_validate_hex_id(hex_id: &str) -> Result<String, String> {
-        if hex_id.len() != 6 {
-            return Err("Hex ID must be exactly 6 characters".to_string());
+        if !(MIN_HEX_ID_LEN..=MAX_HEX_ID_LEN).contains(&hex_id.len()) {
+            return Err(format!(
+                "Hex ID must be {MIN_HEX_ID_LEN} to {MAX_HEX_ID_LEN} characters long"
+            ));
         }
         
         let normalized = hex_id.to_lowercase();
@@ -98,13 +420,15 @@ impl Game {
         Ok(normalized)
     }
 
-    /// Validates that a hex ID has the correct format (6 lowercase alphanumeric characters).
+    /// Validates that a hex ID has the correct format (6 to 32 lowercase alphanumeric characters).
     /// 
     /// # Errors
-    /// Returns an error string if the hex ID is not exactly 6 characters or contains invalid characters.
+    /// Returns an error string if the hex ID is not 6 to 32 characters long or contains invalid characters.
     pub fn validate_hex_id(hex_id: &str) -> Result<(), String> {
-        if hex_id.len() != 6 {
-            return Err("Hex ID must be exactly 6 characters".to_string());
+        if !(MIN_HEX_ID_LEN..=MAX_HEX_ID_LEN).contains(&hex_id.len()) {
+            return Err(format!(
+                "Hex ID must be {MIN_HEX_ID_LEN} to {MAX_HEX_ID_LEN} characters long"
+            ));
         }
         if !hex_id
             .chars()
@@ -117,6 +441,33 @@ impl Game {
         Ok(())
     }
 
+    /// Validates a slug: 3 to 64 lowercase letters, digits and single hyphens, neither
+    /// starting nor ending with a hyphen.
+    ///
+    /// # Errors
+    /// Returns an error string if the slug is malformed.
+    pub fn validate_slug(slug: &str) -> Result<(), String> {
+        if !(MIN_SLUG_LEN..=MAX_SLUG_LEN).contains(&slug.len()) {
+            return Err(format!(
+                "Slug must be {MIN_SLUG_LEN} to {MAX_SLUG_LEN} characters long"
+            ));
+        }
+        if !slug
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase() || c == '-')
+        {
+            return Err(
+                "Slug must contain only lowercase letters, digits and hyphens".to_string(),
+            );
+        }
+        if slug.starts_with('-') || slug.ends_with('-') || slug.contains("--") {
+            return Err(
+                "Slug cannot start or end with a hyphen or contain consecutive hyphens".to_string(),
+            );
+        }
+        Ok(())
+    }
+
     /// Validates that a game name meets the requirements.
     /// 
     /// # Errors
@@ -150,9 +501,24 @@ impl Game {
         if let Some(name) = update_data.name {
             self.name = name;
         }
+        if let Some(slug) = update_data.slug {
+            self.slug = Some(slug).filter(|slug| !slug.is_empty());
+        }
         if let Some(description) = update_data.description {
             self.description = Some(description);
         }
+        if let Some(score_order) = update_data.score_order {
+            self.score_order = score_order;
+        }
+        if let Some(default_sort_by) = update_data.default_sort_by {
+            self.default_sort_by = default_sort_by;
+        }
+        if let Some(submission_policy) = update_data.submission_policy {
+            self.submission_policy = submission_policy;
+        }
+        if let Some(validation) = update_data.validation {
+            self.validation = validation;
+        }
         self.updated_at = Utc::now();
     }
 }
@@ -0,0 +1,75 @@
+use chrono::Duration;
+use sha2::{Digest, Sha256};
+
+/// Environment variable holding how long, in seconds, idempotency keys are remembered.
+pub const IDEMPOTENCY_TTL_VAR: &str = "LEADR_IDEMPOTENCY_TTL_SECS";
+
+/// How long idempotency keys are remembered when `LEADR_IDEMPOTENCY_TTL_SECS` is unset.
+pub const DEFAULT_IDEMPOTENCY_TTL_SECS: i64 = 24 * 60 * 60;
+
+/// How long a request may hold its idempotency key before a retry may take the key over,
+/// in case the server stopped before answering it.
+pub const IDEMPOTENCY_LOCK_SECS: i64 = 60;
+
+/// Maximum length of an idempotency key.
+pub const MAX_IDEMPOTENCY_KEY_LEN: usize = 255;
+
+/// Reads the idempotency key TTL from `LEADR_IDEMPOTENCY_TTL_SECS`, falling back to the
+/// default if unset or empty.
+///
+/// # Errors
+/// Returns an error if the variable is set to something other than a positive whole number of seconds.
+pub fn idempotency_ttl_from_env() -> Result<Duration, String> {
+    match std::env::var(IDEMPOTENCY_TTL_VAR) {
+        Ok(value) if !value.trim().is_empty() => match value.trim().parse::<i64>() {
+            Ok(secs) if secs > 0 => Ok(Duration::seconds(secs)),
+            _ => Err(format!(
+                "{IDEMPOTENCY_TTL_VAR} must be a positive whole number of seconds, got '{value}'"
+            )),
+        },
+        _ => Ok(Duration::seconds(DEFAULT_IDEMPOTENCY_TTL_SECS)),
+    }
+}
+
+/// Checks an `Idempotency-Key` header value.
+///
+/// # Errors
+/// Returns an error if the key is empty or longer than `MAX_IDEMPOTENCY_KEY_LEN`.
+pub fn validate_idempotency_key(key: &str) -> Result<(), String> {
+    if key.is_empty() {
+        return Err("Idempotency-Key cannot be empty".to_string());
+    }
+    if key.len() > MAX_IDEMPOTENCY_KEY_LEN {
+        return Err(format!(
+            "Idempotency-Key cannot be longer than {MAX_IDEMPOTENCY_KEY_LEN} characters"
+        ));
+    }
+    Ok(())
+}
+
+/// Hex-encoded SHA-256 of a request body, used to tell a retry from a different request
+/// reusing its key.
+#[must_use]
+pub fn request_hash(body: &[u8]) -> String {
+    hex::encode(Sha256::digest(body))
+}
+
+/// A response saved under an idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// What a request finds when it presents an idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IdempotencyClaim {
+    /// The key is new, or expired, and now belongs to this request until it completes.
+    Claimed(i64),
+    /// The same request already completed; its response is replayed.
+    Replay(StoredResponse),
+    /// The same request is still being processed.
+    InProgress,
+    /// The key was used with a different request body.
+    Mismatch,
+}
@@ -0,0 +1,393 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::{ScoreOrder, ScoreValidation, SubmissionPolicy};
+use crate::utils::pagination::ScoreSortField;
+
+/// How imported records are combined with the data already stored.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum ImportMode {
+    /// Insert new records and overwrite existing ones with the same `hex_id` or score id.
+    #[serde(rename = "merge")]
+    #[default]
+    Merge,
+    /// Merge, then soft-delete every live game and score missing from the import.
+    #[serde(rename = "replace")]
+    Replace,
+    /// Insert new records only; existing ones are left alone and reported as skipped.
+    #[serde(rename = "append")]
+    Append,
+}
+
+/// File format of an import, matching the export formats.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ImportFormat {
+    #[serde(rename = "csv")]
+    Csv,
+    #[serde(rename = "jsonl")]
+    Jsonl,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct ImportQueryParams {
+    /// How to combine the import with existing data (default: merge).
+    pub mode: Option<ImportMode>,
+    /// Body format; defaults from the `Content-Type` header, then to csv.
+    pub format: Option<ImportFormat>,
+    /// Validate and report without saving anything.
+    pub dry_run: Option<bool>,
+}
+
+/// A game read from an import file. Fields the file does not carry are `None` and keep
+/// their stored (or default) values.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportGame {
+    pub id: Option<i64>,
+    pub hex_id: String,
+    pub slug: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    pub score_order: Option<ScoreOrder>,
+    pub default_sort_by: Option<ScoreSortField>,
+    pub submission_policy: Option<SubmissionPolicy>,
+    pub validation: Option<ScoreValidation>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// A leaderboard read from an import file, matched to stored boards by game and slug.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportLeaderboard {
+    pub game_hex_id: String,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// A score read from an import file.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ImportScore {
+    pub id: Option<i64>,
+    pub game_hex_id: String,
+    /// Slug of the score's board; new scores without one go on the default board.
+    pub leaderboard: Option<String>,
+    pub score: String,
+    pub score_val: Option<f64>,
+    pub user_name: String,
+    pub user_id: String,
+    pub extra: Option<JsonValue>,
+    pub submitted_at: DateTime<Utc>,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+/// One record of an import file, tagged like the JSON Lines export.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ImportRecord {
+    Game(ImportGame),
+    Leaderboard(ImportLeaderboard),
+    Score(ImportScore),
+}
+
+impl ImportRecord {
+    #[must_use]
+    pub fn kind(&self) -> ImportRecordKind {
+        match self {
+            ImportRecord::Game(_) => ImportRecordKind::Game,
+            ImportRecord::Leaderboard(_) => ImportRecordKind::Leaderboard,
+            ImportRecord::Score(_) => ImportRecordKind::Score,
+        }
+    }
+
+    /// The identifier reported for this record: the game's `hex_id`, the leaderboard's
+    /// `hex_id/slug` or the score's id.
+    #[must_use]
+    pub fn key(&self) -> Option<String> {
+        match self {
+            ImportRecord::Game(game) => Some(game.hex_id.clone()),
+            ImportRecord::Leaderboard(leaderboard) => {
+                Some(format!("{}/{}", leaderboard.game_hex_id, leaderboard.slug))
+            }
+            ImportRecord::Score(score) => score.id.map(|id| id.to_string()),
+        }
+    }
+}
+
+/// A parsed record, or the reason its line could not be read.
+#[derive(Debug, Clone)]
+pub struct ImportLine {
+    /// 1-based line (JSON Lines) or record (CSV, counting the header) number.
+    pub line: u64,
+    pub record: Result<ImportRecord, String>,
+}
+
+/// One row of the CSV export format.
+#[derive(Debug, Deserialize)]
+struct CsvRow {
+    // Game fields
+    game_hex_id: String,
+    game_name: String,
+    game_description: Option<String>,
+    game_created_at: String,
+    game_updated_at: Option<String>,
+    game_deleted_at: Option<String>,
+
+    // Leaderboard fields - missing from exports made before games had several boards
+    leaderboard_slug: Option<String>,
+    leaderboard_name: Option<String>,
+    leaderboard_description: Option<String>,
+    leaderboard_created_at: Option<String>,
+
+    // Score fields - empty for boards without scores
+    score_id: Option<i64>,
+    score_value: Option<String>,
+    score_val: Option<f64>,
+    user_name: Option<String>,
+    user_id: Option<String>,
+    extra: Option<String>,
+    score_submitted_at: Option<String>,
+    score_deleted_at: Option<String>,
+}
+
+fn parse_timestamp(field: &str, value: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|e| format!("Invalid {field} '{value}': {e}"))
+}
+
+fn parse_optional_timestamp(field: &str, value: Option<&str>) -> Result<Option<DateTime<Utc>>, String> {
+    value
+        .filter(|v| !v.is_empty())
+        .map(|v| parse_timestamp(field, v))
+        .transpose()
+}
+
+impl CsvRow {
+    fn to_game(&self) -> Result<ImportGame, String> {
+        let created_at = parse_timestamp("game_created_at", &self.game_created_at)?;
+        Ok(ImportGame {
+            id: None,
+            hex_id: self.game_hex_id.clone(),
+            slug: None,
+            name: self.game_name.clone(),
+            description: self.game_description.clone().filter(|d| !d.is_empty()),
+            score_order: None,
+            default_sort_by: None,
+            submission_policy: None,
+            validation: None,
+            created_at,
+            updated_at: parse_optional_timestamp("game_updated_at", self.game_updated_at.as_deref())?,
+            deleted_at: parse_optional_timestamp("game_deleted_at", self.game_deleted_at.as_deref())?,
+        })
+    }
+
+    /// The row's board, if the file has leaderboard columns.
+    fn to_leaderboard(&self) -> Option<Result<ImportLeaderboard, String>> {
+        let slug = self.leaderboard_slug.as_deref().filter(|v| !v.is_empty())?;
+        Some(
+            parse_optional_timestamp("leaderboard_created_at", self.leaderboard_created_at.as_deref())
+                .map(|created_at| ImportLeaderboard {
+                    game_hex_id: self.game_hex_id.clone(),
+                    slug: slug.to_string(),
+                    name: self.leaderboard_name.clone().unwrap_or_default(),
+                    description: self.leaderboard_description.clone().filter(|d| !d.is_empty()),
+                    created_at,
+                }),
+        )
+    }
+
+    /// Whether the row carries a score, rather than only a game or board without scores.
+    fn has_score(&self) -> bool {
+        self.score_id.is_some() || self.score_value.as_deref().is_some_and(|v| !v.is_empty())
+    }
+
+    fn into_score(self) -> Result<ImportScore, String> {
+        let submitted_at = self
+            .score_submitted_at
+            .as_deref()
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| "Missing score_submitted_at".to_string())
+            .and_then(|v| parse_timestamp("score_submitted_at", v))?;
+        let extra = self
+            .extra
+            .as_deref()
+            .filter(|v| !v.is_empty())
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| format!("Invalid JSON in extra: {e}"))?;
+
+        Ok(ImportScore {
+            id: self.score_id,
+            game_hex_id: self.game_hex_id,
+            leaderboard: self.leaderboard_slug.filter(|v| !v.is_empty()),
+            score: self.score_value.unwrap_or_default(),
+            score_val: self.score_val,
+            user_name: self.user_name.unwrap_or_default(),
+            user_id: self.user_id.unwrap_or_default(),
+            extra,
+            submitted_at,
+            deleted_at: parse_optional_timestamp("score_deleted_at", self.score_deleted_at.as_deref())?,
+        })
+    }
+}
+
+/// Parses the CSV export format. Each game and leaderboard is read from the first row that
+/// mentions it.
+///
+/// # Errors
+/// Returns an error string if the header row cannot be read.
+pub fn parse_csv(data: &[u8]) -> Result<Vec<ImportLine>, String> {
+    let mut reader = csv::Reader::from_reader(data);
+    reader
+        .headers()
+        .map_err(|e| format!("Invalid CSV header: {e}"))?;
+
+    let mut seen_games = std::collections::HashSet::new();
+    let mut seen_leaderboards = std::collections::HashSet::new();
+    let mut lines = Vec::new();
+    for (index, result) in reader.deserialize::<CsvRow>().enumerate() {
+        // Line 1 is the header
+        let line = index as u64 + 2;
+        let row = match result {
+            Ok(row) => row,
+            Err(e) => {
+                lines.push(ImportLine { line, record: Err(format!("Invalid CSV row: {e}")) });
+                continue;
+            }
+        };
+
+        if seen_games.insert(row.game_hex_id.to_lowercase()) {
+            lines.push(ImportLine { line, record: row.to_game().map(ImportRecord::Game) });
+        }
+        if let Some(leaderboard) = row.to_leaderboard() {
+            let key = (row.game_hex_id.to_lowercase(), row.leaderboard_slug.clone());
+            if seen_leaderboards.insert(key) {
+                lines.push(ImportLine { line, record: leaderboard.map(ImportRecord::Leaderboard) });
+            }
+        }
+        if row.has_score() {
+            lines.push(ImportLine { line, record: row.into_score().map(ImportRecord::Score) });
+        }
+    }
+    Ok(lines)
+}
+
+/// Parses the JSON Lines export format, skipping blank lines.
+#[must_use]
+pub fn parse_jsonl(data: &[u8]) -> Vec<ImportLine> {
+    data.split(|&b| b == b'\n')
+        .enumerate()
+        .filter(|(_, line)| !line.trim_ascii().is_empty())
+        .map(|(index, line)| ImportLine {
+            line: index as u64 + 1,
+            record: serde_json::from_slice(line).map_err(|e| format!("Invalid JSON record: {e}")),
+        })
+        .collect()
+}
+
+/// Whether a record reported by an import was skipped or failed.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ImportIssueKind {
+    #[serde(rename = "skipped")]
+    Skipped,
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum ImportRecordKind {
+    #[serde(rename = "game")]
+    Game,
+    #[serde(rename = "leaderboard")]
+    Leaderboard,
+    #[serde(rename = "score")]
+    Score,
+}
+
+/// A record that was not imported, and why.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ImportIssue {
+    pub line: u64,
+    /// `None` if the line could not be parsed at all.
+    pub record: Option<ImportRecordKind>,
+    /// The game's `hex_id`, the leaderboard's `hex_id/slug` or the score's id, when known.
+    pub key: Option<String>,
+    pub outcome: ImportIssueKind,
+    pub reason: String,
+}
+
+/// Per-record-type tallies of an import.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct ImportCounts {
+    pub created: u64,
+    pub updated: u64,
+    pub skipped: u64,
+    pub failed: u64,
+    /// Live records soft-deleted because a `replace` import did not include them.
+    pub deleted: u64,
+}
+
+/// What an import did, or would do on a dry run.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ImportReport {
+    pub mode: ImportMode,
+    pub dry_run: bool,
+    pub games: ImportCounts,
+    pub leaderboards: ImportCounts,
+    pub scores: ImportCounts,
+    /// Every skipped or failed record.
+    pub issues: Vec<ImportIssue>,
+}
+
+impl ImportReport {
+    #[must_use]
+    pub fn new(mode: ImportMode, dry_run: bool) -> Self {
+        Self {
+            mode,
+            dry_run,
+            games: ImportCounts::default(),
+            leaderboards: ImportCounts::default(),
+            scores: ImportCounts::default(),
+            issues: Vec::new(),
+        }
+    }
+
+    fn counts_mut(&mut self, kind: ImportRecordKind) -> &mut ImportCounts {
+        match kind {
+            ImportRecordKind::Game => &mut self.games,
+            ImportRecordKind::Leaderboard => &mut self.leaderboards,
+            ImportRecordKind::Score => &mut self.scores,
+        }
+    }
+
+    pub fn record_created(&mut self, kind: ImportRecordKind) {
+        self.counts_mut(kind).created += 1;
+    }
+
+    pub fn record_updated(&mut self, kind: ImportRecordKind) {
+        self.counts_mut(kind).updated += 1;
+    }
+
+    /// Records a skipped or failed line. Lines that could not be parsed have no `kind`.
+    pub fn record_issue(
+        &mut self,
+        line: u64,
+        kind: Option<ImportRecordKind>,
+        key: Option<String>,
+        outcome: ImportIssueKind,
+        reason: String,
+    ) {
+        if let Some(kind) = kind {
+            let counts = self.counts_mut(kind);
+            match outcome {
+                ImportIssueKind::Skipped => counts.skipped += 1,
+                ImportIssueKind::Failed => counts.failed += 1,
+            }
+        }
+        self.issues.push(ImportIssue { line, record: kind, key, outcome, reason });
+    }
+}
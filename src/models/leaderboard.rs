@@ -0,0 +1,102 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Slug of the board every game starts with, which takes submissions that do not name one.
+pub const DEFAULT_LEADERBOARD_SLUG: &str = "default";
+
+/// Longest allowed leaderboard slug.
+pub const MAX_LEADERBOARD_SLUG_LEN: usize = 64;
+
+/// One of a game's boards, e.g. a level, mode or difficulty.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct Leaderboard {
+    pub id: i64,
+    pub game_hex_id: String,
+    /// Identifies the board within its game.
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    /// Live scores on the board.
+    pub score_count: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct LeaderboardRow {
+    pub id: i64,
+    pub game_hex_id: String,
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub score_count: i64,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<LeaderboardRow> for Leaderboard {
+    fn from(row: LeaderboardRow) -> Self {
+        Self {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            slug: row.slug,
+            name: row.name,
+            description: row.description,
+            score_count: row.score_count,
+            created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateLeaderboard {
+    pub slug: String,
+    pub name: String,
+    pub description: Option<String>,
+}
+
+impl Leaderboard {
+    /// Validates that a leaderboard slug meets the requirements.
+    ///
+    /// # Errors
+    /// Returns an error string if the slug is empty, longer than `MAX_LEADERBOARD_SLUG_LEN`,
+    /// contains anything but lowercase letters, digits and hyphens, or starts, ends or
+    /// doubles up with a hyphen.
+    pub fn validate_slug(slug: &str) -> Result<(), String> {
+        if slug.is_empty() || slug.len() > MAX_LEADERBOARD_SLUG_LEN {
+            return Err(format!(
+                "Leaderboard slug must be 1 to {MAX_LEADERBOARD_SLUG_LEN} characters long"
+            ));
+        }
+        if !slug
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_lowercase() || c == '-')
+        {
+            return Err(
+                "Leaderboard slug must contain only lowercase letters, digits and hyphens"
+                    .to_string(),
+            );
+        }
+        if slug.starts_with('-') || slug.ends_with('-') || slug.contains("--") {
+            return Err(
+                "Leaderboard slug cannot start or end with a hyphen or contain consecutive hyphens"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+
+    /// Validates that a leaderboard name meets the requirements.
+    ///
+    /// # Errors
+    /// Returns an error string if the name is empty or exceeds 255 characters.
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if name.trim().is_empty() {
+            return Err("Leaderboard name cannot be empty".to_string());
+        }
+        if name.len() > 255 {
+            return Err("Leaderboard name cannot exceed 255 characters".to_string());
+        }
+        Ok(())
+    }
+}
@@ -1,7 +1,33 @@
+pub mod api_key;
+pub mod audit;
+pub mod batch;
+pub mod event;
 pub mod game;
+pub mod idempotency;
+pub mod import;
+pub mod leaderboard;
 pub mod score;
 pub mod pagination;
+pub mod player;
+pub mod purge;
+pub mod ranking;
+pub mod season;
+pub mod stats;
+pub mod webhook;
 
+pub use api_key::*;
+pub use audit::*;
+pub use batch::*;
+pub use event::*;
 pub use game::*;
+pub use idempotency::*;
+pub use import::*;
+pub use leaderboard::*;
 pub use score::*;
 pub use pagination::*;
+pub use player::*;
+pub use purge::*;
+pub use ranking::*;
+pub use season::*;
+pub use stats::*;
+pub use webhook::*;
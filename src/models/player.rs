@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::{Score, SeasonStanding};
+
+/// Display name given to a player's entries when their data is anonymised.
+pub const ANONYMIZED_USER_NAME: &str = "Anonymous player";
+
+/// Longest accepted avatar URL, in bytes.
+pub const MAX_AVATAR_URL_LEN: usize = 2048;
+
+/// A player's profile. Its `user_name` is the name shown on every one of their scores.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct Player {
+    pub user_id: String,
+    pub user_name: String,
+    pub avatar_url: Option<String>,
+    pub metadata: Option<JsonValue>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct PlayerRow {
+    pub user_id: String,
+    pub user_name: String,
+    pub avatar_url: Option<String>,
+    pub metadata: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<PlayerRow> for Player {
+    fn from(row: PlayerRow) -> Self {
+        Self {
+            user_id: row.user_id,
+            user_name: row.user_name,
+            avatar_url: row.avatar_url,
+            metadata: row.metadata.and_then(|m| serde_json::from_str(&m).ok()),
+            created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
+            updated_at: DateTime::from_naive_utc_and_offset(row.updated_at, Utc),
+        }
+    }
+}
+
+/// Changes to a player's profile; a new `user_name` applies to all of their scores at once.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct UpdatePlayer {
+    pub user_name: Option<String>,
+    pub avatar_url: Option<String>,
+    pub metadata: Option<JsonValue>,
+}
+
+impl UpdatePlayer {
+    /// Validates the display name and avatar URL.
+    ///
+    /// # Errors
+    /// Returns an error if the name is empty or too long, or the avatar URL is not an
+    /// `http(s)` URL of at most `MAX_AVATAR_URL_LEN` bytes.
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(ref user_name) = self.user_name {
+            Score::validate_user_name(user_name)?;
+        }
+        if let Some(ref avatar_url) = self.avatar_url {
+            if !(avatar_url.starts_with("https://") || avatar_url.starts_with("http://")) {
+                return Err("Avatar URL must start with http:// or https://".to_string());
+            }
+            if avatar_url.len() > MAX_AVATAR_URL_LEN {
+                return Err(format!(
+                    "Avatar URL cannot exceed {MAX_AVATAR_URL_LEN} characters"
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Everything stored about a player, for data access requests.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PlayerData {
+    pub user_id: String,
+    pub exported_at: DateTime<Utc>,
+    /// `None` if the player's profile no longer exists.
+    pub player: Option<Player>,
+    /// Every score across all games, including soft-deleted ones.
+    pub scores: Vec<Score>,
+    /// Archived final positions in closed seasons.
+    pub season_standings: Vec<SeasonStanding>,
+}
+
+/// How a player's scores are erased.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum ErasureMode {
+    /// Keep the scores on the boards, detached from the player: `user_id` and `user_name`
+    /// are replaced and `extra` is cleared.
+    #[serde(rename = "anonymize")]
+    #[default]
+    Anonymize,
+    /// Permanently delete the scores.
+    #[serde(rename = "delete")]
+    Delete,
+}
+
+impl ErasureMode {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErasureMode::Anonymize => "anonymize",
+            ErasureMode::Delete => "delete",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct ErasePlayerParams {
+    /// How to erase the player's scores (default: anonymize).
+    pub mode: Option<ErasureMode>,
+}
+
+/// Result of erasing a player's data.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct PlayerErasure {
+    pub user_id: String,
+    pub mode: ErasureMode,
+    /// Scores anonymised or deleted.
+    pub scores: u64,
+    /// Season standings anonymised; archived ranks are kept in either mode.
+    pub season_standings: u64,
+    /// Whether the player's profile was deleted.
+    pub player_deleted: bool,
+}
+
+/// A player's activity on one game. Only live scores count.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PlayerGameStats {
+    pub game_hex_id: String,
+    pub game_name: String,
+    pub submissions: i64,
+    /// The player's best-ranked entry across the game's leaderboards, with its current rank.
+    pub best_score: Score,
+    /// The player's most recent entry.
+    pub latest_score: Score,
+    /// Current rank of `best_score` on its leaderboard of the game.
+    pub rank: i64,
+    pub first_played_at: DateTime<Utc>,
+    pub last_played_at: DateTime<Utc>,
+    pub average_score_val: f64,
+}
+
+/// Totals across every game a player has live scores on.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PlayerStatsSummary {
+    pub games_played: i64,
+    pub submissions: i64,
+    /// Mean `score_val` of every submission, whatever the game.
+    pub average_score_val: Option<f64>,
+    /// The player's highest current rank on any game.
+    pub best_rank: Option<i64>,
+    pub first_played_at: Option<DateTime<Utc>>,
+    pub last_played_at: Option<DateTime<Utc>>,
+}
+
+impl PlayerStatsSummary {
+    /// Sums up per-game stats; averages are weighted by each game's submissions.
+    #[must_use]
+    pub fn from_games(games: &[PlayerGameStats]) -> Self {
+        let submissions: i64 = games.iter().map(|game| game.submissions).sum();
+        let score_val_total: f64 = games
+            .iter()
+            .map(|game| game.average_score_val * game.submissions as f64)
+            .sum();
+
+        Self {
+            games_played: games.len() as i64,
+            submissions,
+            average_score_val: (submissions > 0).then(|| score_val_total / submissions as f64),
+            best_rank: games.iter().map(|game| game.rank).min(),
+            first_played_at: games.iter().map(|game| game.first_played_at).min(),
+            last_played_at: games.iter().map(|game| game.last_played_at).max(),
+        }
+    }
+}
+
+/// A player's stats per game, most recently played first, with a summary across games.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PlayerStats {
+    pub user_id: String,
+    pub summary: PlayerStatsSummary,
+    pub games: Vec<PlayerGameStats>,
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// Environment variable holding the retention period, in days, of soft-deleted records.
+pub const PURGE_AFTER_DAYS_VAR: &str = "LEADR_PURGE_AFTER_DAYS";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct PurgeQueryParams {
+    /// Purge records soft-deleted before this RFC 3339 time.
+    pub before: Option<DateTime<Utc>>,
+    /// Purge records soft-deleted more than this many days ago.
+    pub older_than_days: Option<u32>,
+}
+
+impl PurgeQueryParams {
+    /// Resolves the cutoff; exactly one of `before` and `older_than_days` must be given.
+    ///
+    /// # Errors
+    /// Returns an error if neither or both parameters are set.
+    pub fn cutoff(&self, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+        match (self.before, self.older_than_days) {
+            (Some(before), None) => Ok(before),
+            (None, Some(days)) => Ok(retention_cutoff(now, days)),
+            _ => Err("Exactly one of 'before' or 'older_than_days' is required".to_string()),
+        }
+    }
+}
+
+/// The instant `days` days before `now`.
+#[must_use]
+pub fn retention_cutoff(now: DateTime<Utc>, days: u32) -> DateTime<Utc> {
+    now - Duration::days(i64::from(days))
+}
+
+/// Reads the retention period from `LEADR_PURGE_AFTER_DAYS`; `None` if unset or empty.
+///
+/// # Errors
+/// Returns an error if the variable is set to something other than a whole number of days.
+pub fn purge_after_days_from_env() -> Result<Option<u32>, String> {
+    match std::env::var(PURGE_AFTER_DAYS_VAR) {
+        Ok(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse()
+            .map(Some)
+            .map_err(|_| format!("{PURGE_AFTER_DAYS_VAR} must be a whole number of days, got '{value}'")),
+        _ => Ok(None),
+    }
+}
+
+/// Rows permanently removed by a purge.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct PurgeReport {
+    /// Records soft-deleted before this time were eligible.
+    pub before: DateTime<Utc>,
+    pub games: u64,
+    /// Soft-deleted scores, plus every score of a purged game.
+    pub scores: u64,
+    /// Seasons of purged games, with their archived standings.
+    pub seasons: u64,
+    /// Eligible games kept because an active API key's allow-list still names them.
+    pub games_kept: u64,
+}
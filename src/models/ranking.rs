@@ -0,0 +1,129 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::Score;
+use crate::utils::pagination::{ScoreSortField, ScoreSortParams, SortOrder};
+
+pub const DEFAULT_AROUND_RADIUS: u32 = 5;
+pub const MAX_AROUND_RADIUS: u32 = 50;
+
+/// How tied scores are ranked on a leaderboard.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum RankPolicy {
+    /// Ties share a rank and the next rank follows on directly (1, 2, 2, 3).
+    #[serde(rename = "dense")]
+    Dense,
+    /// Ties share a rank and the following ranks are skipped (1, 2, 2, 4).
+    #[serde(rename = "competition")]
+    #[default]
+    Competition,
+    /// Every entry gets a distinct rank; earlier submissions win ties (1, 2, 3, 4).
+    #[serde(rename = "ordinal")]
+    Ordinal,
+}
+
+impl RankPolicy {
+    /// Builds the SQL window expression computing this rank.
+    ///
+    /// `partition` is an optional `PARTITION BY` column (e.g. `game_hex_id`) and
+    /// `rank_order` the best-first `ORDER BY` term (e.g. `score_val DESC`).
+    #[must_use]
+    pub fn to_sql_window(self, partition: Option<&str>, rank_order: &str) -> String {
+        let function = match self {
+            RankPolicy::Dense => "DENSE_RANK()",
+            RankPolicy::Competition => "RANK()",
+            RankPolicy::Ordinal => "ROW_NUMBER()",
+        };
+        let tiebreak = match self {
+            RankPolicy::Ordinal => ", id ASC",
+            RankPolicy::Dense | RankPolicy::Competition => "",
+        };
+        let partition_clause = partition
+            .map(|column| format!("PARTITION BY {column} "))
+            .unwrap_or_default();
+
+        format!("{function} OVER ({partition_clause}ORDER BY {rank_order}{tiebreak})")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct RankQueryParams {
+    pub rank_policy: Option<RankPolicy>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct PlayerRankQueryParams {
+    /// Only rank on this leaderboard of the game, by slug.
+    /// Leave out to rank all of the game's boards together.
+    pub leaderboard: Option<String>,
+    pub rank_policy: Option<RankPolicy>,
+}
+
+/// A player's standing on a single game's leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct PlayerRank {
+    pub game_hex_id: String,
+    pub user_id: String,
+    pub rank: i64,
+    /// Position on the board as a percentile (100 = top, 0 = bottom).
+    pub percentile: f64,
+    pub total_scores: i64,
+    pub best_score: Score,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct AroundQueryParams {
+    /// Only list entries on this leaderboard of the game, by slug.
+    /// Leave out to rank all of the game's boards together.
+    pub leaderboard: Option<String>,
+    /// Number of entries to include on each side of the centre entry.
+    pub radius: Option<u32>,
+    /// Centre the window on this score instead of the player's best entry.
+    pub around_score_id: Option<i64>,
+    pub sort_by: Option<ScoreSortField>,
+    pub order: Option<SortOrder>,
+    pub rank_policy: Option<RankPolicy>,
+}
+
+impl AroundQueryParams {
+    #[must_use]
+    pub fn get_radius(&self) -> u32 {
+        match self.radius {
+            Some(radius) if radius <= MAX_AROUND_RADIUS => radius,
+            Some(_) => MAX_AROUND_RADIUS,
+            None => DEFAULT_AROUND_RADIUS,
+        }
+    }
+
+    #[must_use]
+    pub fn to_sort_params(&self) -> ScoreSortParams {
+        ScoreSortParams {
+            sort_by: self.sort_by.clone(),
+            order: self.order.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn get_rank_policy(&self) -> RankPolicy {
+        self.rank_policy.unwrap_or_default()
+    }
+}
+
+/// The entry a leaderboard window is centred on.
+#[derive(Debug, Clone)]
+pub enum AroundAnchor {
+    /// The player's best-ranked entry.
+    UserId(String),
+    ScoreId(i64),
+}
+
+/// A slice of a game's leaderboard centred on a single entry.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LeaderboardWindow {
+    pub game_hex_id: String,
+    pub center_score_id: i64,
+    pub radius: u32,
+    pub data: Vec<Score>,
+    pub has_more_above: bool,
+    pub has_more_below: bool,
+}
@@ -7,6 +7,8 @@ use utoipa::ToSchema;
 pub struct Score {
     pub id: i64,
     pub game_hex_id: String,
+    /// The game's leaderboard the score is on.
+    pub leaderboard_id: i64,
     pub score: String,  // Changed to String per schema
     pub score_val: f64, // Renamed from score_num
     pub user_name: String,
@@ -14,6 +16,9 @@ pub struct Score {
     pub extra: Option<JsonValue>,
     pub submitted_at: DateTime<Utc>,
     pub deleted_at: Option<DateTime<Utc>>,
+    /// Leaderboard position within the game; only set on ranked listings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rank: Option<i64>,
 }
 
 // Database representation with proper SQLite types
@@ -21,6 +26,7 @@ pub struct Score {
 pub struct ScoreRow {
     pub id: i64,
     pub game_hex_id: String,
+    pub leaderboard_id: i64,
     pub score: String,
     pub score_val: f64,
     pub user_name: String,
@@ -35,6 +41,7 @@ impl From<ScoreRow> for Score {
         Self {
             id: row.id,
             game_hex_id: row.game_hex_id,
+            leaderboard_id: row.leaderboard_id,
             score: row.score,
             score_val: row.score_val,
             user_name: row.user_name,
@@ -44,13 +51,17 @@ impl From<ScoreRow> for Score {
             deleted_at: row
                 .deleted_at
                 .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc)),
+            rank: None,
         }
     }
 }
 
 #[derive(Debug, Clone, Deserialize, ToSchema)]
 pub struct CreateScore {
+    /// The game's `hex_id` or slug.
     pub game_hex_id: String,
+    /// Slug of the game's leaderboard to submit to; defaults to `default`.
+    pub leaderboard: Option<String>,
     pub score: String,          // Changed to String
     pub score_val: Option<f64>, // Renamed from score_num
     pub user_name: String,
@@ -58,26 +69,83 @@ pub struct CreateScore {
     pub extra: Option<JsonValue>,
 }
 
+impl CreateScore {
+    /// The canonical form of the submission covered by its HMAC signature.
+    ///
+    /// One field per line in declaration order. A missing `score_val` or `extra` is an
+    /// empty line, `score_val` uses the shortest decimal that round-trips (`100`, `12.5`)
+    /// and `extra` is compact JSON with object keys sorted. A named `leaderboard` follows
+    /// on one more line, so submissions to the default board sign the same payload as before.
+    #[must_use]
+    pub fn canonical_payload(&self) -> String {
+        let score_val = self.score_val.map(|v| v.to_string()).unwrap_or_default();
+        let extra = self
+            .extra
+            .as_ref()
+            .map(JsonValue::to_string)
+            .unwrap_or_default();
+
+        let mut lines = vec![
+            self.game_hex_id.as_str(),
+            self.score.as_str(),
+            score_val.as_str(),
+            self.user_name.as_str(),
+            self.user_id.as_str(),
+            extra.as_str(),
+        ];
+        if let Some(ref leaderboard) = self.leaderboard {
+            lines.push(leaderboard.as_str());
+        }
+        lines.join("\n")
+    }
+}
+
 #[derive(Debug, Deserialize, ToSchema)]
 pub struct UpdateScore {
     pub score: Option<String>,  // Changed to String
     pub score_val: Option<f64>, // Renamed from score_num
+    /// Rejected by `PUT /scores/{id}`; players are renamed with `PUT /players/{user_id}`.
     pub user_name: Option<String>,
     pub user_id: Option<String>,
     pub extra: Option<JsonValue>,
 }
 
+/// What happened to the stored leaderboard entry on submission.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum SubmissionOutcome {
+    /// A new entry was added.
+    #[serde(rename = "created")]
+    Created,
+    /// The player's existing entry was overwritten.
+    #[serde(rename = "replaced")]
+    Replaced,
+    /// The player's existing entry was kept; the returned score is that entry.
+    #[serde(rename = "unchanged")]
+    Unchanged,
+}
+
+/// Result of submitting a score under the game's submission policy.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ScoreSubmission {
+    #[serde(flatten)]
+    pub score: Score,
+    pub outcome: SubmissionOutcome,
+    /// Whether the submitted value beats every earlier score by this player on the leaderboard.
+    pub is_personal_best: bool,
+}
+
 impl Score {
-    #[must_use]
-    pub fn new(create_data: CreateScore) -> Self {
-        // Try to parse score as f64 for score_val, fallback to 0.0
-        let score_val = create_data
-            .score_val
-            .unwrap_or_else(|| create_data.score.parse::<f64>().unwrap_or(0.0));
+    /// Creates an unsaved score from submitted data.
+    ///
+    /// # Errors
+    /// Returns an error string if `score_val` is missing and `score` is not a number.
+    pub fn new(create_data: CreateScore) -> Result<Self, String> {
+        let score_val = Self::parse_score_val(&create_data.score, create_data.score_val)?;
 
-        Self {
+        Ok(Self {
             id: 0, // Will be set by database
             game_hex_id: create_data.game_hex_id,
+            leaderboard_id: 0, // Will be set by database
             score: create_data.score,
             score_val,
             user_name: create_data.user_name,
@@ -85,9 +153,30 @@ impl Score {
             extra: create_data.extra,
             submitted_at: Utc::now(),
             deleted_at: None,
+            rank: None,
+        })
+    }
+
+    /// Returns the numeric value of a score: `score_val` if given, otherwise `score` parsed.
+    ///
+    /// # Errors
+    /// Returns an error string if `score_val` is missing and `score` is not a number.
+    pub fn parse_score_val(score: &str, score_val: Option<f64>) -> Result<f64, String> {
+        match score_val {
+            Some(score_val) => Ok(score_val),
+            None => score
+                .trim()
+                .parse::<f64>()
+                .map_err(|_| "score_val is required when score is not a number".to_string()),
         }
     }
 
+    #[must_use]
+    pub fn with_rank(mut self, rank: Option<i64>) -> Self {
+        self.rank = rank;
+        self
+    }
+
     #[must_use]
     pub fn is_deleted(&self) -> bool {
         self.deleted_at.is_some()
@@ -101,15 +190,16 @@ impl Score {
         self.deleted_at = None;
     }
 
-    pub fn update(&mut self, update_data: UpdateScore) {
+    /// Applies an update, recomputing `score_val` from a new `score` unless one is given.
+    ///
+    /// # Errors
+    /// Returns an error string, leaving the score unchanged, if `score` changes to a
+    /// non-number without a `score_val`.
+    pub fn update(&mut self, update_data: UpdateScore) -> Result<(), String> {
         if let Some(score) = update_data.score {
-            self.score.clone_from(&score);
-            // Update score_val to match if not explicitly provided
-            if update_data.score_val.is_none() {
-                self.score_val = score.parse::<f64>().unwrap_or(0.0);
-            }
-        }
-        if let Some(score_val) = update_data.score_val {
+            self.score_val = Self::parse_score_val(&score, update_data.score_val)?;
+            self.score = score;
+        } else if let Some(score_val) = update_data.score_val {
             self.score_val = score_val;
         }
         if let Some(user_name) = update_data.user_name {
@@ -121,6 +211,7 @@ impl Score {
         if let Some(extra) = update_data.extra {
             self.extra = Some(extra);
         }
+        Ok(())
     }
 
     /// Validates that a user name meets the requirements.
@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::utils::pagination::PaginationParams;
+
+/// A scheduled competitive period on a game's leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct Season {
+    pub id: i64,
+    pub game_hex_id: String,
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    /// When the final standings were archived; `None` while the season is open.
+    pub closed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct SeasonRow {
+    pub id: i64,
+    pub game_hex_id: String,
+    pub name: String,
+    pub starts_at: chrono::NaiveDateTime,
+    pub ends_at: chrono::NaiveDateTime,
+    pub closed_at: Option<chrono::NaiveDateTime>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<SeasonRow> for Season {
+    fn from(row: SeasonRow) -> Self {
+        Self {
+            id: row.id,
+            game_hex_id: row.game_hex_id,
+            name: row.name,
+            starts_at: DateTime::from_naive_utc_and_offset(row.starts_at, Utc),
+            ends_at: DateTime::from_naive_utc_and_offset(row.ends_at, Utc),
+            closed_at: row
+                .closed_at
+                .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc)),
+            created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateSeason {
+    pub name: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct SeasonStandingsQueryParams {
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+    /// Only list standings on this leaderboard of the game, by slug.
+    /// Leave out to list all of the game's boards together.
+    pub leaderboard: Option<String>,
+}
+
+impl SeasonStandingsQueryParams {
+    #[must_use]
+    pub fn to_pagination_params(&self) -> PaginationParams {
+        PaginationParams {
+            cursor: self.cursor.clone(),
+            limit: self.limit,
+        }
+    }
+}
+
+/// A player's archived final position in a closed season.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct SeasonStanding {
+    pub id: i64,
+    pub season_id: i64,
+    /// The game's leaderboard the standing was ranked on.
+    pub leaderboard_id: i64,
+    /// Final position among the season's entries on the same leaderboard.
+    pub rank: i64,
+    pub user_id: String,
+    pub user_name: String,
+    pub score: String,
+    pub score_val: f64,
+    pub submitted_at: DateTime<Utc>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct SeasonStandingRow {
+    pub id: i64,
+    pub season_id: i64,
+    pub leaderboard_id: i64,
+    pub rank: i64,
+    pub user_id: String,
+    pub user_name: String,
+    pub score: String,
+    pub score_val: f64,
+    pub submitted_at: chrono::NaiveDateTime,
+}
+
+impl From<SeasonStandingRow> for SeasonStanding {
+    fn from(row: SeasonStandingRow) -> Self {
+        Self {
+            id: row.id,
+            season_id: row.season_id,
+            leaderboard_id: row.leaderboard_id,
+            rank: row.rank,
+            user_id: row.user_id,
+            user_name: row.user_name,
+            score: row.score,
+            score_val: row.score_val,
+            submitted_at: DateTime::from_naive_utc_and_offset(row.submitted_at, Utc),
+        }
+    }
+}
+
+impl Season {
+    #[must_use]
+    pub fn is_closed(&self) -> bool {
+        self.closed_at.is_some()
+    }
+
+    /// The end of the period whose scores count towards the season.
+    ///
+    /// A season closed early only counts scores submitted before it was closed.
+    #[must_use]
+    pub fn effective_end(&self) -> DateTime<Utc> {
+        self.closed_at
+            .map_or(self.ends_at, |closed_at| closed_at.min(self.ends_at))
+    }
+
+    /// Validates that a season name meets the requirements.
+    ///
+    /// # Errors
+    /// Returns an error string if the name is empty or exceeds 255 characters.
+    pub fn validate_name(name: &str) -> Result<(), String> {
+        if name.trim().is_empty() {
+            return Err("Season name cannot be empty".to_string());
+        }
+        if name.len() > 255 {
+            return Err("Season name cannot exceed 255 characters".to_string());
+        }
+        Ok(())
+    }
+
+    /// Validates that a season ends after it starts.
+    ///
+    /// # Errors
+    /// Returns an error string if `ends_at` is not after `starts_at`.
+    pub fn validate_schedule(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Result<(), String> {
+        if ends_at <= starts_at {
+            return Err("Season must end after it starts".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+pub const DEFAULT_HISTOGRAM_BUCKETS: u32 = 10;
+pub const MAX_HISTOGRAM_BUCKETS: u32 = 100;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct GameStatsQueryParams {
+    /// Number of equal-width histogram buckets between the lowest and highest `score_val`.
+    pub buckets: Option<u32>,
+    /// Only count scores on this leaderboard of the game, by slug.
+    /// Leave out to count all of the game's boards together.
+    pub leaderboard: Option<String>,
+}
+
+impl GameStatsQueryParams {
+    /// The requested bucket count, or the default.
+    ///
+    /// # Errors
+    /// Returns an error if the count is zero or above `MAX_HISTOGRAM_BUCKETS`.
+    pub fn get_buckets(&self) -> Result<u32, String> {
+        match self.buckets {
+            None => Ok(DEFAULT_HISTOGRAM_BUCKETS),
+            Some(buckets) if (1..=MAX_HISTOGRAM_BUCKETS).contains(&buckets) => Ok(buckets),
+            Some(_) => Err(format!("buckets must be between 1 and {MAX_HISTOGRAM_BUCKETS}")),
+        }
+    }
+}
+
+/// Summary statistics of a game's live `score_val`s.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct ScoreValStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    /// The middle value, or the mean of the two middle values for an even count.
+    pub median: f64,
+    /// Nearest-rank percentiles: the smallest value at or above that share of scores.
+    pub p50: f64,
+    pub p90: f64,
+    pub p99: f64,
+}
+
+/// Live scores submitted on one UTC day.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub struct DailySubmissions {
+    pub date: NaiveDate,
+    pub submissions: i64,
+}
+
+/// Scores with `lower <= score_val < upper`; the last bucket also includes `upper`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct HistogramBucket {
+    pub lower: f64,
+    pub upper: f64,
+    pub count: i64,
+}
+
+/// Aggregate statistics and the score distribution of a game. Only live scores count.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct GameStats {
+    pub game_hex_id: String,
+    pub total_scores: i64,
+    pub unique_players: i64,
+    /// `None` if the game has no scores.
+    pub score_val: Option<ScoreValStats>,
+    /// Days with at least one submission, oldest first.
+    pub submissions_per_day: Vec<DailySubmissions>,
+    pub histogram: Vec<HistogramBucket>,
+}
+
+/// Zero-based position of the nearest-rank `percentile` among `count` sorted values.
+#[must_use]
+pub fn percentile_offset(count: i64, percentile: f64) -> i64 {
+    let rank = (percentile / 100.0 * count as f64).ceil() as i64;
+    rank.clamp(1, count.max(1)) - 1
+}
+
+/// Width of each of `buckets` equal buckets spanning `min..=max`; zero if all values are equal.
+#[must_use]
+pub fn bucket_width(min: f64, max: f64, buckets: u32) -> f64 {
+    (max - min) / f64::from(buckets)
+}
+
+/// Builds the histogram from per-bucket counts, including empty buckets.
+///
+/// `counts` pairs a zero-based bucket index with its count. When every value is equal
+/// there is a single bucket.
+#[must_use]
+pub fn build_histogram(min: f64, max: f64, buckets: u32, counts: &[(i64, i64)]) -> Vec<HistogramBucket> {
+    let width = bucket_width(min, max, buckets);
+    if width == 0.0 {
+        let count = counts.iter().map(|(_, count)| count).sum();
+        return vec![HistogramBucket { lower: min, upper: max, count }];
+    }
+
+    (0..buckets)
+        .map(|index| {
+            let count = counts
+                .iter()
+                .find(|(bucket, _)| *bucket == i64::from(index))
+                .map_or(0, |(_, count)| *count);
+            let upper = if index + 1 == buckets { max } else { min + width * f64::from(index + 1) };
+            HistogramBucket { lower: min + width * f64::from(index), upper, count }
+        })
+        .collect()
+}
@@ -0,0 +1,367 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::models::ScoreEvent;
+
+/// Longest accepted webhook URL, in bytes.
+pub const MAX_WEBHOOK_URL_LEN: usize = 2048;
+
+/// Attempts made at a delivery before it is marked failed.
+pub const WEBHOOK_MAX_ATTEMPTS: i64 = 8;
+
+/// Delay before the first retry of a delivery, in seconds; it doubles with each attempt.
+pub const WEBHOOK_RETRY_BASE_SECS: i64 = 30;
+
+/// Longest delay between two attempts at a delivery, in seconds.
+pub const WEBHOOK_RETRY_MAX_SECS: i64 = 60 * 60;
+
+pub const DEFAULT_DELIVERY_LIMIT: u32 = 50;
+pub const MAX_DELIVERY_LIMIT: u32 = 200;
+
+/// An event a webhook can subscribe to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash, ToSchema)]
+pub enum WebhookEvent {
+    /// A submission added a new entry.
+    #[serde(rename = "score.created")]
+    ScoreCreated,
+    /// An entry was edited, or replaced by a submission under `keep_best`/`keep_latest`.
+    #[serde(rename = "score.updated")]
+    ScoreUpdated,
+    /// An entry was soft-deleted.
+    #[serde(rename = "score.deleted")]
+    ScoreDeleted,
+    /// A game's top entries changed.
+    #[serde(rename = "score.top_n")]
+    ScoreTopN,
+    #[serde(rename = "game.created")]
+    GameCreated,
+    #[serde(rename = "game.updated")]
+    GameUpdated,
+    #[serde(rename = "game.deleted")]
+    GameDeleted,
+}
+
+impl WebhookEvent {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            WebhookEvent::ScoreCreated => "score.created",
+            WebhookEvent::ScoreUpdated => "score.updated",
+            WebhookEvent::ScoreDeleted => "score.deleted",
+            WebhookEvent::ScoreTopN => "score.top_n",
+            WebhookEvent::GameCreated => "game.created",
+            WebhookEvent::GameUpdated => "game.updated",
+            WebhookEvent::GameDeleted => "game.deleted",
+        }
+    }
+}
+
+impl std::str::FromStr for WebhookEvent {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "score.created" => Ok(WebhookEvent::ScoreCreated),
+            "score.updated" => Ok(WebhookEvent::ScoreUpdated),
+            "score.deleted" => Ok(WebhookEvent::ScoreDeleted),
+            "score.top_n" => Ok(WebhookEvent::ScoreTopN),
+            "game.created" => Ok(WebhookEvent::GameCreated),
+            "game.updated" => Ok(WebhookEvent::GameUpdated),
+            "game.deleted" => Ok(WebhookEvent::GameDeleted),
+            _ => Err(format!("Invalid webhook event: {s}")),
+        }
+    }
+}
+
+impl From<&ScoreEvent> for WebhookEvent {
+    fn from(event: &ScoreEvent) -> Self {
+        match event {
+            ScoreEvent::ScoreCreated { .. } => WebhookEvent::ScoreCreated,
+            ScoreEvent::ScoreUpdated { .. } => WebhookEvent::ScoreUpdated,
+            ScoreEvent::ScoreDeleted { .. } => WebhookEvent::ScoreDeleted,
+            ScoreEvent::TopChanged { .. } => WebhookEvent::ScoreTopN,
+        }
+    }
+}
+
+/// A registered webhook. Its signing secret is only returned when it is generated.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct Webhook {
+    pub id: i64,
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    /// Only events of this game are sent; `None` means every game.
+    pub game_hex_id: Option<String>,
+    /// Inactive webhooks are sent nothing; their pending deliveries wait until reactivated.
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct WebhookRow {
+    pub id: i64,
+    pub url: String,
+    pub events: String,
+    pub game_hex_id: Option<String>,
+    pub active: bool,
+    pub created_at: chrono::NaiveDateTime,
+    pub updated_at: chrono::NaiveDateTime,
+}
+
+impl From<WebhookRow> for Webhook {
+    fn from(row: WebhookRow) -> Self {
+        Self {
+            id: row.id,
+            url: row.url,
+            // Unknown event names are dropped rather than failing the whole webhook
+            events: serde_json::from_str::<Vec<String>>(&row.events)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|event| event.parse().ok())
+                .collect(),
+            game_hex_id: row.game_hex_id,
+            active: row.active,
+            created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
+            updated_at: DateTime::from_naive_utc_and_offset(row.updated_at, Utc),
+        }
+    }
+}
+
+impl Webhook {
+    /// Validates a target URL.
+    ///
+    /// # Errors
+    /// Returns an error if the URL is not an absolute `http(s)` URL of at most
+    /// `MAX_WEBHOOK_URL_LEN` bytes.
+    pub fn validate_url(url: &str) -> Result<(), String> {
+        if url.len() > MAX_WEBHOOK_URL_LEN {
+            return Err(format!(
+                "Webhook URL cannot exceed {MAX_WEBHOOK_URL_LEN} characters"
+            ));
+        }
+        match reqwest::Url::parse(url) {
+            Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => Ok(()),
+            _ => Err("Webhook URL must be an absolute http:// or https:// URL".to_string()),
+        }
+    }
+
+    /// Sorts and de-duplicates an event filter, which must not be empty.
+    ///
+    /// # Errors
+    /// Returns an error if no events are given.
+    pub fn normalize_events(mut events: Vec<WebhookEvent>) -> Result<Vec<WebhookEvent>, String> {
+        events.sort();
+        events.dedup();
+        if events.is_empty() {
+            return Err("A webhook must subscribe to at least one event".to_string());
+        }
+        Ok(events)
+    }
+
+    /// The stored form of an event filter: a JSON array of event names.
+    #[must_use]
+    pub fn events_json(events: &[WebhookEvent]) -> String {
+        serde_json::to_string(events).unwrap_or_else(|_| "[]".to_string())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct CreateWebhook {
+    pub url: String,
+    pub events: Vec<WebhookEvent>,
+    /// Only send events of this game; omit for every game.
+    pub game_hex_id: Option<String>,
+}
+
+/// A newly created webhook. The signing `secret` is only ever returned here and on rotation.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CreatedWebhook {
+    #[serde(flatten)]
+    pub webhook: Webhook,
+    pub secret: String,
+}
+
+/// Changes to a webhook. Its game filter is fixed when it is created.
+#[derive(Debug, Clone, Default, Deserialize, ToSchema)]
+pub struct UpdateWebhook {
+    pub url: Option<String>,
+    pub events: Option<Vec<WebhookEvent>>,
+    pub active: Option<bool>,
+}
+
+/// A webhook's rotated signing secret, returned only when it is generated.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSecret {
+    pub webhook_id: i64,
+    pub secret: String,
+}
+
+/// Where a delivery stands.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, ToSchema)]
+pub enum DeliveryStatus {
+    /// Not yet accepted by the target; retried at `next_attempt_at`.
+    #[serde(rename = "pending")]
+    Pending,
+    /// The target answered with a 2xx status.
+    #[serde(rename = "succeeded")]
+    Succeeded,
+    /// Every attempt failed; no more are made.
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+impl DeliveryStatus {
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DeliveryStatus::Pending => "pending",
+            DeliveryStatus::Succeeded => "succeeded",
+            DeliveryStatus::Failed => "failed",
+        }
+    }
+}
+
+impl std::str::FromStr for DeliveryStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(DeliveryStatus::Pending),
+            "succeeded" => Ok(DeliveryStatus::Succeeded),
+            "failed" => Ok(DeliveryStatus::Failed),
+            _ => Err(format!("Invalid delivery status: {s}")),
+        }
+    }
+}
+
+/// One event sent, or to be sent, to a webhook.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, ToSchema)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub event: WebhookEvent,
+    pub game_hex_id: Option<String>,
+    /// The event's `data`, as sent in the request body.
+    pub payload: JsonValue,
+    pub status: DeliveryStatus,
+    pub attempts: i64,
+    /// When the next attempt is due; `None` once the delivery succeeded or failed.
+    pub next_attempt_at: Option<DateTime<Utc>>,
+    pub last_attempt_at: Option<DateTime<Utc>>,
+    /// HTTP status of the last response, if the target answered at all.
+    pub response_status: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+// Database representation with proper SQLite types
+#[derive(Debug, sqlx::FromRow)]
+pub struct WebhookDeliveryRow {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub event: String,
+    pub game_hex_id: Option<String>,
+    pub payload: String,
+    pub status: String,
+    pub attempts: i64,
+    pub next_attempt_at: Option<chrono::NaiveDateTime>,
+    pub last_attempt_at: Option<chrono::NaiveDateTime>,
+    pub response_status: Option<i64>,
+    pub last_error: Option<String>,
+    pub created_at: chrono::NaiveDateTime,
+}
+
+impl From<WebhookDeliveryRow> for WebhookDelivery {
+    fn from(row: WebhookDeliveryRow) -> Self {
+        Self {
+            id: row.id,
+            webhook_id: row.webhook_id,
+            // Rows are only written with known events and statuses
+            event: row.event.parse().unwrap_or(WebhookEvent::ScoreCreated),
+            game_hex_id: row.game_hex_id,
+            payload: serde_json::from_str(&row.payload).unwrap_or(JsonValue::Null),
+            status: row.status.parse().unwrap_or(DeliveryStatus::Failed),
+            attempts: row.attempts,
+            next_attempt_at: row
+                .next_attempt_at
+                .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc)),
+            last_attempt_at: row
+                .last_attempt_at
+                .map(|dt| DateTime::from_naive_utc_and_offset(dt, Utc)),
+            response_status: row.response_status,
+            last_error: row.last_error,
+            created_at: DateTime::from_naive_utc_and_offset(row.created_at, Utc),
+        }
+    }
+}
+
+impl WebhookDelivery {
+    /// The JSON request body sent to the webhook; the signature covers these exact bytes.
+    #[must_use]
+    pub fn body(&self) -> String {
+        serde_json::json!({
+            "delivery_id": self.id,
+            "event": self.event,
+            "game_hex_id": self.game_hex_id,
+            "occurred_at": self.created_at,
+            "data": self.payload,
+        })
+        .to_string()
+    }
+}
+
+/// A delivery due for an attempt, with where and how to send it.
+#[derive(Debug, Clone)]
+pub struct DueDelivery {
+    pub delivery: WebhookDelivery,
+    pub url: String,
+    pub secret: String,
+}
+
+/// The result of one attempt at a delivery.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeliveryAttempt {
+    /// HTTP status of the response, if the target answered at all.
+    pub response_status: Option<u16>,
+    /// Why the attempt failed: a transport error or the non-2xx status.
+    pub error: Option<String>,
+}
+
+impl DeliveryAttempt {
+    #[must_use]
+    pub fn succeeded(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
+/// How long to wait after the `attempts`-th failed attempt before trying again.
+///
+/// Starts at `WEBHOOK_RETRY_BASE_SECS` and doubles each time, up to `WEBHOOK_RETRY_MAX_SECS`.
+#[must_use]
+pub fn retry_delay(attempts: i64) -> Duration {
+    let doublings = u32::try_from(attempts.saturating_sub(1).clamp(0, 30)).unwrap_or(30);
+    let secs = WEBHOOK_RETRY_BASE_SECS.saturating_mul(1_i64 << doublings);
+    Duration::seconds(secs.min(WEBHOOK_RETRY_MAX_SECS))
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct DeliveryQueryParams {
+    /// Only deliveries with this status.
+    pub status: Option<DeliveryStatus>,
+    /// Most recent deliveries to return (default 50, max 200).
+    pub limit: Option<u32>,
+}
+
+impl DeliveryQueryParams {
+    /// The requested limit, clamped to `1..=MAX_DELIVERY_LIMIT`.
+    #[must_use]
+    pub fn get_limit(&self) -> u32 {
+        self.limit
+            .unwrap_or(DEFAULT_DELIVERY_LIMIT)
+            .clamp(1, MAX_DELIVERY_LIMIT)
+    }
+}
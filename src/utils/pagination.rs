@@ -1,6 +1,10 @@
+use chrono::{DateTime, Datelike, Days, Months, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use utoipa::{ToSchema, IntoParams};
 
+use crate::models::{Game, RankPolicy};
+
 pub const DEFAULT_PAGE_SIZE: u32 = 25;
 pub const MAX_PAGE_SIZE: u32 = 100;
 
@@ -10,6 +14,74 @@ pub struct PaginationParams {
     pub limit: Option<u32>,
 }
 
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema, IntoParams)]
+pub struct GameQueryParams {
+    pub cursor: Option<String>,
+    pub limit: Option<u32>,
+    /// Also list soft-deleted games (admin keys only).
+    pub include_deleted: Option<bool>,
+    /// Only list soft-deleted games (admin keys only).
+    pub only_deleted: Option<bool>,
+    /// Embed each game's `score_count` and `player_count`.
+    pub include_counts: Option<bool>,
+}
+
+impl GameQueryParams {
+    #[must_use]
+    pub fn to_pagination_params(&self) -> PaginationParams {
+        PaginationParams {
+            cursor: self.cursor.clone(),
+            limit: self.limit,
+        }
+    }
+
+    #[must_use]
+    pub fn deleted_filter(&self) -> DeletedFilter {
+        DeletedFilter::from_flags(self.include_deleted, self.only_deleted)
+    }
+}
+
+/// Whether a listing shows live records, soft-deleted ones, or both.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DeletedFilter {
+    #[default]
+    Exclude,
+    Include,
+    Only,
+}
+
+impl DeletedFilter {
+    /// Resolves the `include_deleted` and `only_deleted` query flags; `only_deleted` wins.
+    #[must_use]
+    pub fn from_flags(include_deleted: Option<bool>, only_deleted: Option<bool>) -> Self {
+        match (include_deleted, only_deleted) {
+            (_, Some(true)) => DeletedFilter::Only,
+            (Some(true), _) => DeletedFilter::Include,
+            _ => DeletedFilter::Exclude,
+        }
+    }
+
+    #[must_use]
+    pub fn includes_live(self) -> bool {
+        self != DeletedFilter::Only
+    }
+
+    #[must_use]
+    pub fn includes_deleted(self) -> bool {
+        self != DeletedFilter::Exclude
+    }
+
+    /// SQL condition on a `deleted_at` column selecting the filtered records.
+    #[must_use]
+    pub fn to_sql_condition(self, column: &str) -> String {
+        match self {
+            DeletedFilter::Exclude => format!("{column} IS NULL"),
+            DeletedFilter::Include => "1".to_string(),
+            DeletedFilter::Only => format!("{column} IS NOT NULL"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PaginatedResponse<T> {
     pub data: Vec<T>,
@@ -20,7 +92,7 @@ pub struct PaginatedResponse<T> {
     pub page_size: u32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
 pub enum SortOrder {
     #[serde(rename = "asc")]
     Ascending,
@@ -29,7 +101,7 @@ pub enum SortOrder {
     Descending,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, Default, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
 pub enum ScoreSortField {
     #[serde(rename = "score")]
     #[default]
@@ -40,6 +112,78 @@ pub enum ScoreSortField {
     UserName,
 }
 
+impl ScoreSortField {
+    #[must_use]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScoreSortField::Score => "score",
+            ScoreSortField::Date => "date",
+            ScoreSortField::UserName => "user_name",
+        }
+    }
+}
+
+impl std::str::FromStr for ScoreSortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "score" => Ok(ScoreSortField::Score),
+            "date" => Ok(ScoreSortField::Date),
+            "user_name" => Ok(ScoreSortField::UserName),
+            _ => Err(format!("Invalid sort field: {s}")),
+        }
+    }
+}
+
+/// A calendar period a leaderboard can be restricted to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Default, PartialEq, Eq, ToSchema)]
+pub enum TimeWindow {
+    #[serde(rename = "daily")]
+    Daily,
+    /// Monday to Sunday.
+    #[serde(rename = "weekly")]
+    Weekly,
+    #[serde(rename = "monthly")]
+    Monthly,
+    #[serde(rename = "all_time")]
+    #[default]
+    AllTime,
+}
+
+impl TimeWindow {
+    /// Returns the `[start, end)` bounds of the window containing `anchor`,
+    /// with calendar boundaries taken in `tz`. `AllTime` has no bounds.
+    #[must_use]
+    pub fn bounds(self, anchor: DateTime<Utc>, tz: Tz) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        let today = anchor.with_timezone(&tz).date_naive();
+        let (start, end) = match self {
+            TimeWindow::AllTime => return None,
+            TimeWindow::Daily => (today, today + Days::new(1)),
+            TimeWindow::Weekly => {
+                let start = today - Days::new(u64::from(today.weekday().num_days_from_monday()));
+                (start, start + Days::new(7))
+            }
+            TimeWindow::Monthly => {
+                let start = today.with_day(1).unwrap_or(today);
+                (start, start + Months::new(1))
+            }
+        };
+
+        Some((local_midnight(start, tz), local_midnight(end, tz)))
+    }
+}
+
+/// The instant a calendar day starts in `tz`.
+fn local_midnight(date: NaiveDate, tz: Tz) -> DateTime<Utc> {
+    let midnight = date.and_time(NaiveTime::MIN);
+    // Days that skip midnight for a DST change start at the end of the gap instead
+    tz.from_local_datetime(&midnight)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(midnight + chrono::Duration::hours(1))).earliest())
+        .map_or_else(|| midnight.and_utc(), |start| start.with_timezone(&Utc))
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoreSortParams {
     pub sort_by: Option<ScoreSortField>,
@@ -48,11 +192,47 @@ pub struct ScoreSortParams {
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema, IntoParams)]
 pub struct ScoreQueryParams {
+    /// Only list this game's scores, by `hex_id` or slug.
     pub game_hex_id: Option<String>,
+    /// Only list scores on this leaderboard of the game, by slug; requires `game_hex_id`.
+    /// Leave out to list all of the game's boards together.
+    pub leaderboard: Option<String>,
     pub cursor: Option<String>,
     pub limit: Option<u32>,
     pub sort_by: Option<ScoreSortField>,
     pub order: Option<SortOrder>,
+    pub rank_policy: Option<RankPolicy>,
+    /// Only list each player's best entry per game.
+    pub distinct_users: Option<bool>,
+    /// Restrict the leaderboard to the calendar period containing `anchor`.
+    pub window: Option<TimeWindow>,
+    /// IANA timezone used for window boundaries (default: UTC).
+    #[schema(value_type = Option<String>)]
+    #[param(value_type = Option<String>)]
+    pub tz: Option<Tz>,
+    /// Instant the window is taken around (default: now).
+    pub anchor: Option<DateTime<Utc>>,
+    /// Only include scores submitted at or after this time.
+    pub from: Option<DateTime<Utc>>,
+    /// Only include scores submitted before this time.
+    pub to: Option<DateTime<Utc>>,
+    /// Also list soft-deleted scores (admin keys only).
+    pub include_deleted: Option<bool>,
+    /// Only list soft-deleted scores (admin keys only).
+    pub only_deleted: Option<bool>,
+}
+
+/// Which scores a listing includes.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreFilterParams {
+    pub distinct_users: bool,
+    /// Inclusive lower bound on `submitted_at`.
+    pub submitted_from: Option<DateTime<Utc>>,
+    /// Exclusive upper bound on `submitted_at`.
+    pub submitted_to: Option<DateTime<Utc>>,
+    pub deleted: DeletedFilter,
+    /// Only scores on this board of the game.
+    pub leaderboard_id: Option<i64>,
 }
 
 impl ScoreQueryParams {
@@ -71,6 +251,46 @@ impl ScoreQueryParams {
             order: self.order.clone(),
         }
     }
+
+    /// Resolves the time window and `from`/`to` bounds into a single submission range.
+    ///
+    /// The `leaderboard` slug is left for the caller to resolve into `leaderboard_id`.
+    ///
+    /// # Errors
+    /// Returns an error if `from` is not before `to`, or `leaderboard` is given without
+    /// `game_hex_id`.
+    pub fn to_filter_params(&self) -> Result<ScoreFilterParams, String> {
+        if self.leaderboard.is_some() && self.game_hex_id.is_none() {
+            return Err("'leaderboard' requires 'game_hex_id'".to_string());
+        }
+        if let (Some(from), Some(to)) = (self.from, self.to) {
+            if from >= to {
+                return Err("'from' must be before 'to'".to_string());
+            }
+        }
+
+        let window = self
+            .window
+            .unwrap_or_default()
+            .bounds(self.anchor.unwrap_or_else(Utc::now), self.tz.unwrap_or(Tz::UTC));
+        let (window_from, window_to) = window.unzip();
+
+        Ok(ScoreFilterParams {
+            distinct_users: self.distinct_users.unwrap_or(false),
+            submitted_from: window_from.max(self.from),
+            submitted_to: match (window_to, self.to) {
+                (Some(window_to), Some(to)) => Some(window_to.min(to)),
+                (window_to, to) => window_to.or(to),
+            },
+            deleted: DeletedFilter::from_flags(self.include_deleted, self.only_deleted),
+            leaderboard_id: None,
+        })
+    }
+
+    #[must_use]
+    pub fn get_rank_policy(&self) -> RankPolicy {
+        self.rank_policy.unwrap_or_default()
+    }
 }
 
 impl PaginationParams {
@@ -168,6 +388,23 @@ impl ScoreSortParams {
         Self { sort_by, order }
     }
 
+    /// Fills in any unspecified sort settings from the game's configuration.
+    ///
+    /// Score sorts default to best-first for the game's `score_order`.
+    #[must_use]
+    pub fn with_game_defaults(self, game: &Game) -> Self {
+        let sort_by = self.sort_by.unwrap_or_else(|| game.default_sort_by.clone());
+        let order = self.order.unwrap_or_else(|| match sort_by {
+            ScoreSortField::Score => game.score_order.best_first(),
+            ScoreSortField::Date | ScoreSortField::UserName => SortOrder::default(),
+        });
+
+        Self {
+            sort_by: Some(sort_by),
+            order: Some(order),
+        }
+    }
+
     #[must_use]
     pub fn get_sort_field(&self) -> ScoreSortField {
         self.sort_by.clone().unwrap_or_default()
@@ -277,5 +514,21 @@ pub mod cursor {
                 sort_value,
             }
         }
+
+        /// The sort value in the form stored in the database, for comparing against `sort_field`.
+        ///
+        /// Dates are stored as naive UTC text rather than RFC 3339, so they are
+        /// converted to compare correctly.
+        #[must_use]
+        pub fn to_sql_value(&self, sort_field: &str) -> String {
+            match sort_field {
+                "submitted_at" => chrono::DateTime::parse_from_rfc3339(&self.sort_value)
+                    .map_or_else(
+                        |_| self.sort_value.clone(),
+                        |date| date.naive_utc().format("%F %T%.f").to_string(),
+                    ),
+                _ => self.sort_value.clone(),
+            }
+        }
     }
 }
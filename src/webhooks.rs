@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    auth::{SubmissionSignature, SIGNATURE_HEADER, SIGNATURE_TIMESTAMP_HEADER},
+    db::{repository::WebhookRepository, DbPool},
+    error::Result,
+    models::{DeliveryAttempt, DueDelivery},
+};
+
+/// Header naming the event a webhook request carries.
+pub const EVENT_HEADER: &str = "leadr-event";
+
+/// Header carrying the delivery id; retries of a delivery reuse it.
+pub const DELIVERY_HEADER: &str = "leadr-delivery";
+
+/// How long a webhook target has to answer before the attempt counts as failed.
+pub const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Most deliveries attempted per call to [`deliver_due`].
+pub const DELIVERY_BATCH_SIZE: u32 = 50;
+
+/// An HTTP client suitable for sending webhooks.
+///
+/// # Panics
+/// Panics if the TLS backend cannot be initialised.
+#[must_use]
+pub fn client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(WEBHOOK_TIMEOUT)
+        .user_agent(concat!("leadr-webhooks/", env!("CARGO_PKG_VERSION")))
+        .build()
+        .expect("HTTP client configuration is valid")
+}
+
+/// Sends one delivery, signed with its webhook's secret.
+///
+/// The signature is the hex-encoded HMAC-SHA256 of `"{timestamp}\n{delivery_id}\n{body}"`,
+/// the same scheme signed score submissions use with the delivery id as the nonce.
+pub async fn send(client: &reqwest::Client, due: &DueDelivery, now: DateTime<Utc>) -> DeliveryAttempt {
+    let delivery = &due.delivery;
+    let body = delivery.body();
+    let delivery_id = delivery.id.to_string();
+    let timestamp = now.timestamp();
+    let signature = SubmissionSignature::sign(&due.secret, timestamp, &delivery_id, &body);
+
+    let response = client
+        .post(&due.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header(EVENT_HEADER, delivery.event.as_str())
+        .header(DELIVERY_HEADER, &delivery_id)
+        .header(SIGNATURE_TIMESTAMP_HEADER, timestamp.to_string())
+        .header(SIGNATURE_HEADER, signature)
+        .body(body)
+        .send()
+        .await;
+
+    match response {
+        Ok(response) if response.status().is_success() => DeliveryAttempt {
+            response_status: Some(response.status().as_u16()),
+            error: None,
+        },
+        Ok(response) => DeliveryAttempt {
+            response_status: Some(response.status().as_u16()),
+            error: Some(format!("Unexpected response status {}", response.status())),
+        },
+        Err(e) => DeliveryAttempt {
+            response_status: None,
+            error: Some(e.to_string()),
+        },
+    }
+}
+
+/// Attempts every delivery that is due, recording each result and scheduling retries.
+///
+/// Returns the number of deliveries attempted.
+///
+/// # Errors
+/// Returns `ApiError::DatabaseError` if the deliveries cannot be read or a result cannot be saved.
+pub async fn deliver_due(pool: &DbPool, client: &reqwest::Client) -> Result<usize> {
+    let due = WebhookRepository::due_deliveries(pool, Utc::now(), DELIVERY_BATCH_SIZE).await?;
+    for delivery in &due {
+        let now = Utc::now();
+        let attempt = send(client, delivery, now).await;
+        if let Some(ref error) = attempt.error {
+            tracing::warn!(
+                "Webhook delivery {} to {} failed: {error}",
+                delivery.delivery.id,
+                delivery.url
+            );
+        }
+        WebhookRepository::record_attempt(pool, &delivery.delivery, &attempt, now).await?;
+    }
+    Ok(due.len())
+}
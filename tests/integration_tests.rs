@@ -17,6 +17,37 @@ async fn create_test_app() -> Router {
     create_app(pool)
 }
 
+// Helper function to create test app that keeps the pool reachable, e.g. for backdating scores
+async fn create_test_app_with_pool() -> (Router, db::DbPool) {
+    std::env::set_var("LEADR_API_KEY", "test_api_key_123");
+
+    let pool = db::create_pool("sqlite::memory:").await.unwrap();
+    db::run_migrations(&pool).await.unwrap();
+    (create_app(pool.clone()), pool)
+}
+
+// Helper function to insert a score with a specific submission time
+async fn create_test_score_at(
+    pool: &db::DbPool,
+    hex_id: &str,
+    user_id: &str,
+    score_val: f64,
+    submitted_at: chrono::DateTime<chrono::Utc>,
+) {
+    let create_data = leadr_api::models::CreateScore {
+        game_hex_id: hex_id.to_string(),
+        leaderboard: None,
+        score: score_val.to_string(),
+        score_val: Some(score_val),
+        user_name: format!("Player {user_id}"),
+        user_id: user_id.to_string(),
+        extra: None,
+    };
+    leadr_api::db::repository::ScoreRepository::create_with_timestamp(pool, create_data, submitted_at)
+        .await
+        .unwrap();
+}
+
 // Helper function to create request with API key
 fn request_with_api_key(method: &str, uri: &str, body: Option<&str>) -> Request<Body> {
     let builder = Request::builder()
@@ -42,6 +73,72 @@ fn request_without_api_key(method: &str, uri: &str) -> Request<Body> {
         .unwrap()
 }
 
+// Helper function to read a JSON response body
+async fn response_json(response: axum::response::Response) -> serde_json::Value {
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&body).unwrap()
+}
+
+// Helper function to send a request with API key and read its status and JSON body, if any
+async fn send(app: &Router, method: &str, uri: &str, body: Option<&str>) -> (StatusCode, serde_json::Value) {
+    let response = app
+        .clone()
+        .oneshot(request_with_api_key(method, uri, body))
+        .await
+        .unwrap();
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, serde_json::from_slice(&body).unwrap_or(serde_json::Value::Null))
+}
+
+// Helper function to count the live scores listed for a game
+async fn count_scores(app: &Router, hex_id: &str) -> usize {
+    let (status, body) = send(app, "GET", &format!("/scores?game_hex_id={hex_id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    body["data"].as_array().unwrap().len()
+}
+
+// Helper function to create a game and return its hex_id
+async fn create_test_game(app: &Router, name: &str) -> String {
+    let response = app
+        .clone()
+        .oneshot(request_with_api_key(
+            "POST",
+            "/games",
+            Some(&json!({ "name": name }).to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let game = response_json(response).await;
+    game["hex_id"].as_str().unwrap().to_string()
+}
+
+// Helper function to submit a score for a game
+async fn create_test_score(app: &Router, hex_id: &str, user_id: &str, score_val: f64) -> serde_json::Value {
+    let score_data = json!({
+        "game_hex_id": hex_id,
+        "score": score_val.to_string(),
+        "score_val": score_val,
+        "user_name": format!("Player {user_id}"),
+        "user_id": user_id
+    });
+
+    let response = app
+        .clone()
+        .oneshot(request_with_api_key(
+            "POST",
+            "/scores",
+            Some(&score_data.to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    response_json(response).await
+}
+
 #[cfg(test)]
 mod game_endpoint_tests {
     use super::*;
@@ -466,7 +563,7 @@ mod score_endpoint_tests {
     async fn test_create_score_without_auth() {
         let app = create_test_app().await;
 
-        let score_data = json!({
+        let _score_data = json!({
             "score": "500",
             "user_name": "Player",
             "user_id": "id123"
@@ -601,7 +698,7 @@ mod score_endpoint_tests {
     async fn test_update_score_without_auth() {
         let app = create_test_app().await;
 
-        let update_data = json!({
+        let _update_data = json!({
             "score": 1500
         });
 
@@ -955,16 +1052,12 @@ mod pagination_and_sorting_tests {
             .await
             .unwrap();
 
-        // Should return CSV file with proper headers, or at minimum not return METHOD_NOT_ALLOWED
-        assert!(response.status() == StatusCode::OK || response.status() == StatusCode::INTERNAL_SERVER_ERROR);
-        
-        // If successful, verify headers
-        if response.status() == StatusCode::OK {
-            let headers = response.headers();
-            assert_eq!(headers.get("content-type").unwrap(), "text/csv");
-            assert!(headers.get("content-disposition").unwrap().to_str().unwrap().contains("attachment"));
-            assert!(headers.get("content-disposition").unwrap().to_str().unwrap().contains("leadr_backup_"));
-        }
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let headers = response.headers();
+        assert_eq!(headers.get("content-type").unwrap(), "text/csv");
+        assert!(headers.get("content-disposition").unwrap().to_str().unwrap().contains("attachment"));
+        assert!(headers.get("content-disposition").unwrap().to_str().unwrap().contains("leadr_backup_"));
     }
 
     #[tokio::test]
@@ -979,3 +1072,4528 @@ mod pagination_and_sorting_tests {
         assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
     }
 }
+
+#[cfg(test)]
+mod ranking_tests {
+    use super::*;
+
+    async fn ranks_for(app: &Router, uri: &str) -> Vec<(String, i64)> {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", uri, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| {
+                (
+                    s["user_id"].as_str().unwrap().to_string(),
+                    s["rank"].as_i64().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    async fn create_tied_board(app: &Router) -> String {
+        let hex_id = create_test_game(app, "Ranked Game").await;
+        create_test_score(app, &hex_id, "alice", 300.0).await;
+        create_test_score(app, &hex_id, "bob", 200.0).await;
+        create_test_score(app, &hex_id, "carol", 200.0).await;
+        create_test_score(app, &hex_id, "dave", 100.0).await;
+        hex_id
+    }
+
+    #[tokio::test]
+    async fn test_list_scores_competition_rank_by_default() {
+        let app = create_test_app().await;
+        let hex_id = create_tied_board(&app).await;
+
+        let ranks = ranks_for(&app, &format!("/scores?game_hex_id={hex_id}")).await;
+        let values: Vec<i64> = ranks.iter().map(|(_, rank)| *rank).collect();
+        assert_eq!(values, vec![1, 2, 2, 4]);
+    }
+
+    #[tokio::test]
+    async fn test_list_scores_dense_and_ordinal_rank() {
+        let app = create_test_app().await;
+        let hex_id = create_tied_board(&app).await;
+
+        let dense = ranks_for(&app, &format!("/scores?game_hex_id={hex_id}&rank_policy=dense")).await;
+        let values: Vec<i64> = dense.iter().map(|(_, rank)| *rank).collect();
+        assert_eq!(values, vec![1, 2, 2, 3]);
+
+        let ordinal = ranks_for(&app, &format!("/scores?game_hex_id={hex_id}&rank_policy=ordinal")).await;
+        assert_eq!(
+            ordinal,
+            vec![
+                ("alice".to_string(), 1),
+                ("bob".to_string(), 2),
+                ("carol".to_string(), 3),
+                ("dave".to_string(), 4)
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rank_independent_of_sort_order() {
+        let app = create_test_app().await;
+        let hex_id = create_tied_board(&app).await;
+
+        let ranks = ranks_for(&app, &format!("/scores?game_hex_id={hex_id}&order=asc")).await;
+        assert_eq!(ranks.first().unwrap(), &("dave".to_string(), 4));
+        assert_eq!(ranks.last().unwrap(), &("alice".to_string(), 1));
+    }
+
+    #[tokio::test]
+    async fn test_get_player_rank_success() {
+        let app = create_test_app().await;
+        let hex_id = create_tied_board(&app).await;
+        create_test_score(&app, &hex_id, "dave", 250.0).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/ranks/dave"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(body["rank"], 2);
+        assert_eq!(body["total_scores"], 5);
+        assert_eq!(body["best_score"]["score_val"], 250.0);
+        assert_eq!(body["percentile"], 75.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_player_rank_unknown_player() {
+        let app = create_test_app().await;
+        let hex_id = create_tied_board(&app).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/ranks/nobody"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_get_player_rank_unknown_game() {
+        let app = create_test_app().await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", "/games/zzz999/ranks/alice", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
+
+#[cfg(test)]
+mod leaderboard_around_tests {
+    use super::*;
+
+    async fn create_board(app: &Router, size: usize) -> String {
+        let hex_id = create_test_game(app, "Around Game").await;
+        for i in 0..size {
+            create_test_score(app, &hex_id, &format!("p{i}"), (i * 10) as f64).await;
+        }
+        hex_id
+    }
+
+    fn user_ids(body: &serde_json::Value) -> Vec<String> {
+        body["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|s| s["user_id"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_around_player_centered_window() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 10).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around/p5?radius=2"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(user_ids(&body), vec!["p7", "p6", "p5", "p4", "p3"]);
+        assert_eq!(body["data"][2]["rank"], 5);
+        assert_eq!(body["has_more_above"], true);
+        assert_eq!(body["has_more_below"], true);
+    }
+
+    #[tokio::test]
+    async fn test_around_player_clipped_at_top() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 5).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around/p4?radius=2"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(user_ids(&body), vec!["p4", "p3", "p2"]);
+        assert_eq!(body["has_more_above"], false);
+        assert_eq!(body["has_more_below"], true);
+    }
+
+    #[tokio::test]
+    async fn test_around_player_honours_sort_order() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 5).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around/p2?radius=1&order=asc"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(user_ids(&body), vec!["p1", "p2", "p3"]);
+    }
+
+    #[tokio::test]
+    async fn test_around_score_id() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 5).await;
+        let score = create_test_score(&app, &hex_id, "target", 25.0).await;
+        let score_id = score["id"].as_i64().unwrap();
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around?around_score_id={score_id}&radius=1"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(body["center_score_id"], score_id);
+        assert_eq!(user_ids(&body), vec!["p3", "target", "p2"]);
+    }
+
+    #[tokio::test]
+    async fn test_around_score_id_required() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 2).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_around_unknown_player() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 2).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around/nobody"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_around_invalid_sort_field() {
+        let app = create_test_app().await;
+        let hex_id = create_board(&app, 2).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "GET",
+                &format!("/games/{hex_id}/leaderboard/around/p1?sort_by=invalid_field"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[cfg(test)]
+mod game_score_order_tests {
+    use super::*;
+
+    async fn create_speedrun_game(app: &Router) -> String {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/games",
+                Some(&json!({ "name": "Speedrun", "score_order": "lower_is_better" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let game = response_json(response).await;
+        assert_eq!(game["score_order"], "lower_is_better");
+        assert_eq!(game["default_sort_by"], "score");
+        game["hex_id"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_lower_is_better_default_listing_and_rank() {
+        let app = create_test_app().await;
+        let hex_id = create_speedrun_game(&app).await;
+        create_test_score(&app, &hex_id, "slow", 90.0).await;
+        create_test_score(&app, &hex_id, "fast", 30.0).await;
+        create_test_score(&app, &hex_id, "medium", 60.0).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/scores?game_hex_id={hex_id}"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data[0]["user_id"], "fast");
+        assert_eq!(data[0]["rank"], 1);
+        assert_eq!(data[2]["user_id"], "slow");
+        assert_eq!(data[2]["rank"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_lower_is_better_explicit_order_keeps_rank() {
+        let app = create_test_app().await;
+        let hex_id = create_speedrun_game(&app).await;
+        create_test_score(&app, &hex_id, "slow", 90.0).await;
+        create_test_score(&app, &hex_id, "fast", 30.0).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/scores?game_hex_id={hex_id}&order=desc"), None))
+            .await
+            .unwrap();
+        let body = response_json(response).await;
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data[0]["user_id"], "slow");
+        assert_eq!(data[0]["rank"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_lower_is_better_player_rank_uses_best_time() {
+        let app = create_test_app().await;
+        let hex_id = create_speedrun_game(&app).await;
+        create_test_score(&app, &hex_id, "runner", 50.0).await;
+        create_test_score(&app, &hex_id, "runner", 40.0).await;
+        create_test_score(&app, &hex_id, "rival", 45.0).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/ranks/runner"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(body["rank"], 1);
+        assert_eq!(body["best_score"]["score_val"], 40.0);
+    }
+
+    #[tokio::test]
+    async fn test_global_listing_ranks_each_game_in_its_direction() {
+        let app = create_test_app().await;
+        let speedrun = create_speedrun_game(&app).await;
+        let arcade = create_test_game(&app, "Arcade").await;
+        create_test_score(&app, &speedrun, "fast", 10.0).await;
+        create_test_score(&app, &speedrun, "slow", 20.0).await;
+        create_test_score(&app, &arcade, "high", 20.0).await;
+        create_test_score(&app, &arcade, "low", 10.0).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", "/scores", None))
+            .await
+            .unwrap();
+        let body = response_json(response).await;
+        for score in body["data"].as_array().unwrap() {
+            let expected = match score["user_id"].as_str().unwrap() {
+                "fast" | "high" => 1,
+                _ => 2,
+            };
+            assert_eq!(score["rank"], expected);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_update_game_score_settings() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Switching").await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "PUT",
+                &format!("/games/{hex_id}"),
+                Some(&json!({ "score_order": "lower_is_better", "default_sort_by": "date" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let game = response_json(response).await;
+        assert_eq!(game["score_order"], "lower_is_better");
+        assert_eq!(game["default_sort_by"], "date");
+    }
+
+    #[tokio::test]
+    async fn test_create_game_invalid_score_order() {
+        let app = create_test_app().await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "POST",
+                "/games",
+                Some(&json!({ "name": "Bad", "score_order": "sideways" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[cfg(test)]
+mod submission_policy_tests {
+    use super::*;
+
+    async fn create_game_with_policy(app: &Router, policy: &str) -> String {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/games",
+                Some(&json!({ "name": "Policy Game", "submission_policy": policy }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let game = response_json(response).await;
+        assert_eq!(game["submission_policy"], policy);
+        game["hex_id"].as_str().unwrap().to_string()
+    }
+
+    async fn submit_score(app: &Router, hex_id: &str, user_id: &str, score_val: f64) -> (StatusCode, serde_json::Value) {
+        let score_data = json!({
+            "game_hex_id": hex_id,
+            "score": score_val.to_string(),
+            "score_val": score_val,
+            "user_name": format!("Player {user_id}"),
+            "user_id": user_id
+        });
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("POST", "/scores", Some(&score_data.to_string())))
+            .await
+            .unwrap();
+        let status = response.status();
+        (status, response_json(response).await)
+    }
+
+    async fn list_game_scores(app: &Router, query: &str) -> Vec<serde_json::Value> {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/scores?{query}"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        response_json(response).await["data"].as_array().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn test_keep_all_reports_personal_best() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Keep All").await;
+
+        let first = create_test_score(&app, &hex_id, "player", 100.0).await;
+        assert_eq!(first["outcome"], "created");
+        assert_eq!(first["is_personal_best"], true);
+
+        let worse = create_test_score(&app, &hex_id, "player", 50.0).await;
+        assert_eq!(worse["is_personal_best"], false);
+
+        let better = create_test_score(&app, &hex_id, "player", 150.0).await;
+        assert_eq!(better["is_personal_best"], true);
+
+        assert_eq!(list_game_scores(&app, &format!("game_hex_id={hex_id}")).await.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_keep_best_only_replaces_with_better_score() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_policy(&app, "keep_best").await;
+
+        let (status, first) = submit_score(&app, &hex_id, "player", 100.0).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(first["outcome"], "created");
+
+        let (status, worse) = submit_score(&app, &hex_id, "player", 50.0).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(worse["outcome"], "unchanged");
+        assert_eq!(worse["is_personal_best"], false);
+        assert_eq!(worse["id"], first["id"]);
+        assert_eq!(worse["score_val"], 100.0);
+
+        let (status, better) = submit_score(&app, &hex_id, "player", 150.0).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(better["outcome"], "replaced");
+        assert_eq!(better["is_personal_best"], true);
+        assert_eq!(better["id"], first["id"]);
+        assert_eq!(better["score_val"], 150.0);
+
+        let scores = list_game_scores(&app, &format!("game_hex_id={hex_id}")).await;
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0]["score_val"], 150.0);
+    }
+
+    #[tokio::test]
+    async fn test_keep_best_respects_lower_is_better() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_policy(&app, "keep_best").await;
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "PUT",
+                &format!("/games/{hex_id}"),
+                Some(&json!({ "score_order": "lower_is_better" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        submit_score(&app, &hex_id, "runner", 60.0).await;
+        let (_, slower) = submit_score(&app, &hex_id, "runner", 70.0).await;
+        assert_eq!(slower["outcome"], "unchanged");
+
+        let (_, faster) = submit_score(&app, &hex_id, "runner", 40.0).await;
+        assert_eq!(faster["outcome"], "replaced");
+        assert_eq!(faster["score_val"], 40.0);
+    }
+
+    #[tokio::test]
+    async fn test_keep_latest_always_replaces() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_policy(&app, "keep_latest").await;
+
+        let (_, first) = submit_score(&app, &hex_id, "player", 100.0).await;
+        let (status, latest) = submit_score(&app, &hex_id, "player", 50.0).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(latest["outcome"], "replaced");
+        assert_eq!(latest["is_personal_best"], false);
+        assert_eq!(latest["id"], first["id"]);
+
+        let scores = list_game_scores(&app, &format!("game_hex_id={hex_id}")).await;
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0]["score_val"], 50.0);
+    }
+
+    #[tokio::test]
+    async fn test_distinct_users_lists_best_entry_per_player() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "History").await;
+        create_test_score(&app, &hex_id, "alice", 100.0).await;
+        create_test_score(&app, &hex_id, "alice", 300.0).await;
+        create_test_score(&app, &hex_id, "bob", 200.0).await;
+        create_test_score(&app, &hex_id, "bob", 50.0).await;
+
+        let scores = list_game_scores(&app, &format!("game_hex_id={hex_id}&distinct_users=true")).await;
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0]["user_id"], "alice");
+        assert_eq!(scores[0]["score_val"], 300.0);
+        assert_eq!(scores[0]["rank"], 1);
+        assert_eq!(scores[1]["user_id"], "bob");
+        assert_eq!(scores[1]["score_val"], 200.0);
+        assert_eq!(scores[1]["rank"], 2);
+
+        let global = list_game_scores(&app, "distinct_users=true").await;
+        assert_eq!(global.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_create_game_invalid_submission_policy() {
+        let app = create_test_app().await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "POST",
+                "/games",
+                Some(&json!({ "name": "Bad", "submission_policy": "keep_some" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[cfg(test)]
+mod time_window_tests {
+    use super::*;
+    use chrono::{DateTime, Duration, Utc};
+
+    async fn list_scores(app: &Router, query: &str) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/scores?{query}"), None))
+            .await
+            .unwrap();
+        let status = response.status();
+        (status, response_json(response).await)
+    }
+
+    #[tokio::test]
+    async fn test_daily_window_ranks_only_todays_scores() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Daily").await;
+        let now = Utc::now();
+        create_test_score_at(&pool, &hex_id, "old_champion", 1000.0, now - Duration::days(3)).await;
+        create_test_score_at(&pool, &hex_id, "today_low", 100.0, now).await;
+        create_test_score_at(&pool, &hex_id, "today_high", 200.0, now).await;
+
+        let (status, body) = list_scores(&app, &format!("game_hex_id={hex_id}&window=daily")).await;
+        assert_eq!(status, StatusCode::OK);
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data.len(), 2);
+        assert_eq!(data[0]["user_id"], "today_high");
+        assert_eq!(data[0]["rank"], 1);
+
+        let (_, body) = list_scores(&app, &format!("game_hex_id={hex_id}&window=all_time")).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 3);
+        assert_eq!(body["data"][0]["user_id"], "old_champion");
+    }
+
+    #[tokio::test]
+    async fn test_window_with_anchor_and_timezone() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Anchored").await;
+        let march = "2024-03-10T12:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        create_test_score_at(&pool, &hex_id, "march", 10.0, march).await;
+        create_test_score_at(&pool, &hex_id, "april", 20.0, march + Duration::days(30)).await;
+
+        let (_, body) = list_scores(
+            &app,
+            &format!("game_hex_id={hex_id}&window=monthly&anchor=2024-03-01T00:00:00Z&tz=Europe/London"),
+        )
+        .await;
+        let data = body["data"].as_array().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["user_id"], "march");
+
+        // Global listings honour the window too
+        let (_, body) = list_scores(&app, "window=weekly&anchor=2024-04-09T00:00:00Z").await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"][0]["user_id"], "april");
+    }
+
+    #[tokio::test]
+    async fn test_from_to_bounds() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Bounded").await;
+        let start = "2024-01-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap();
+        for day in 0..5 {
+            create_test_score_at(&pool, &hex_id, &format!("day{day}"), f64::from(day), start + Duration::days(day.into())).await;
+        }
+
+        let (_, body) = list_scores(
+            &app,
+            &format!("game_hex_id={hex_id}&from=2024-01-02T00:00:00Z&to=2024-01-04T00:00:00Z"),
+        )
+        .await;
+        let users: Vec<&str> = body["data"].as_array().unwrap().iter().map(|s| s["user_id"].as_str().unwrap()).collect();
+        assert_eq!(users, vec!["day2", "day1"]);
+    }
+
+    #[tokio::test]
+    async fn test_cursor_pagination_inside_window() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Paged").await;
+        let now = Utc::now();
+        create_test_score_at(&pool, &hex_id, "outside", 500.0, now - Duration::days(40)).await;
+        for i in 0..5 {
+            create_test_score_at(&pool, &hex_id, &format!("player{i}"), f64::from(i), now - Duration::minutes(i.into())).await;
+        }
+
+        let mut seen = Vec::new();
+        let mut query = format!("game_hex_id={hex_id}&window=monthly&sort_by=date&limit=2");
+        loop {
+            let (status, body) = list_scores(&app, &query).await;
+            assert_eq!(status, StatusCode::OK);
+            for score in body["data"].as_array().unwrap() {
+                seen.push(score["user_id"].as_str().unwrap().to_string());
+            }
+            match body["next_cursor"].as_str() {
+                Some(cursor) => {
+                    query = format!("game_hex_id={hex_id}&window=monthly&sort_by=date&limit=2&cursor={cursor}");
+                }
+                None => break,
+            }
+        }
+
+        assert_eq!(seen, vec!["player0", "player1", "player2", "player3", "player4"]);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_window_params() {
+        let (app, _pool) = create_test_app_with_pool().await;
+
+        let (status, _) = list_scores(&app, "window=yearly").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, _) = list_scores(&app, "window=daily&tz=Not/AZone").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, _) = list_scores(&app, "from=2024-02-01T00:00:00Z&to=2024-01-01T00:00:00Z").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[cfg(test)]
+mod season_tests {
+    use super::*;
+    use chrono::{DateTime, Duration, Utc};
+    use leadr_api::db::repository::SeasonRepository;
+
+    async fn create_test_season(app: &Router, hex_id: &str, starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> serde_json::Value {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                &format!("/games/{hex_id}/seasons"),
+                Some(&json!({ "name": "Season 1", "starts_at": starts_at, "ends_at": ends_at }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        response_json(response).await
+    }
+
+    #[tokio::test]
+    async fn test_create_list_and_get_seasons() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Seasonal").await;
+        let now = Utc::now();
+        let season = create_test_season(&app, &hex_id, now, now + Duration::days(30)).await;
+        assert_eq!(season["game_hex_id"], hex_id);
+        assert!(season["closed_at"].is_null());
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/seasons"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_json(response).await.as_array().unwrap().len(), 1);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/seasons/{}", season["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_json(response).await["name"], "Season 1");
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/seasons/9999"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_create_season_invalid_schedule() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Seasonal").await;
+        let now = Utc::now();
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "POST",
+                &format!("/games/{hex_id}/seasons"),
+                Some(&json!({ "name": "Backwards", "starts_at": now, "ends_at": now - Duration::days(1) }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_close_season_archives_standings() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Seasonal").await;
+        let now = Utc::now();
+        let season = create_test_season(&app, &hex_id, now - Duration::days(7), now + Duration::days(7)).await;
+        let season_id = season["id"].as_i64().unwrap();
+
+        create_test_score_at(&pool, &hex_id, "before", 999.0, now - Duration::days(8)).await;
+        create_test_score_at(&pool, &hex_id, "alice", 100.0, now - Duration::days(2)).await;
+        create_test_score_at(&pool, &hex_id, "alice", 300.0, now - Duration::days(1)).await;
+        create_test_score_at(&pool, &hex_id, "bob", 200.0, now - Duration::hours(1)).await;
+
+        let standings_uri = format!("/games/{hex_id}/seasons/{season_id}/standings");
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &standings_uri, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("POST", &format!("/games/{hex_id}/seasons/{season_id}/close"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(!response_json(response).await["closed_at"].is_null());
+
+        // The live board moving on does not change the archive
+        create_test_score(&app, &hex_id, "carol", 5000.0).await;
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &standings_uri, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_json(response).await;
+        let standings = body["data"].as_array().unwrap();
+        assert_eq!(standings.len(), 2);
+        assert_eq!(standings[0]["user_id"], "alice");
+        assert_eq!(standings[0]["score_val"], 300.0);
+        assert_eq!(standings[0]["rank"], 1);
+        assert_eq!(standings[1]["user_id"], "bob");
+        assert_eq!(standings[1]["rank"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_close_expired_seasons() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Seasonal").await;
+        let now = Utc::now();
+        let ended = create_test_season(&app, &hex_id, now - Duration::days(14), now - Duration::days(7)).await;
+        let running = create_test_season(&app, &hex_id, now - Duration::days(1), now + Duration::days(7)).await;
+        for i in 0..3 {
+            create_test_score_at(&pool, &hex_id, &format!("player{i}"), f64::from(i), now - Duration::days(10)).await;
+        }
+
+        let closed = SeasonRepository::close_expired(&pool).await.unwrap();
+        assert_eq!(closed.len(), 1);
+        assert_eq!(closed[0].id, ended["id"].as_i64().unwrap());
+        assert!(SeasonRepository::close_expired(&pool).await.unwrap().is_empty());
+
+        let running = SeasonRepository::get(&pool, &hex_id, running["id"].as_i64().unwrap()).await.unwrap();
+        assert!(!running.is_closed());
+
+        // Archived standings page by rank
+        let uri = format!("/games/{hex_id}/seasons/{}/standings?limit=2", ended["id"]);
+        let response = app.clone().oneshot(request_with_api_key("GET", &uri, None)).await.unwrap();
+        let body = response_json(response).await;
+        assert_eq!(body["data"][0]["user_id"], "player2");
+        assert!(body["has_more"].as_bool().unwrap());
+
+        let cursor = body["next_cursor"].as_str().unwrap();
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("{uri}&cursor={cursor}"), None))
+            .await
+            .unwrap();
+        let body = response_json(response).await;
+        assert_eq!(body["data"].as_array().unwrap().len(), 1);
+        assert_eq!(body["data"][0]["user_id"], "player0");
+        assert_eq!(body["data"][0]["rank"], 3);
+    }
+}
+
+#[cfg(test)]
+mod api_key_tests {
+    use super::*;
+
+    fn request_with_key(method: &str, uri: &str, key: &str, body: Option<&str>) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("leadr-api-key", key)
+            .header("content-type", "application/json")
+            .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+            .unwrap()
+    }
+
+    async fn create_test_key(app: &Router, scope: &str) -> serde_json::Value {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/api-keys",
+                Some(&json!({ "name": format!("{scope} key"), "scope": scope }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        response_json(response).await
+    }
+
+    fn score_body(hex_id: &str) -> String {
+        json!({
+            "game_hex_id": hex_id,
+            "score": "100",
+            "user_name": "Player",
+            "user_id": "player"
+        })
+        .to_string()
+    }
+
+    #[tokio::test]
+    async fn test_read_key_scope() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Scoped").await;
+        let created = create_test_key(&app, "read").await;
+        let key = created["key"].as_str().unwrap();
+        assert_eq!(created["scope"], "read");
+
+        let response = app.clone().oneshot(request_with_key("GET", "/games", key, None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("GET", &format!("/scores?game_hex_id={hex_id}"), key, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("POST", "/scores", key, Some(&score_body(&hex_id))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app
+            .oneshot(request_with_key("DELETE", &format!("/games/{hex_id}"), key, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_submit_key_scope() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Scoped").await;
+        let created = create_test_key(&app, "submit").await;
+        let key = created["key"].as_str().unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("POST", "/scores", key, Some(&score_body(&hex_id))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app.clone().oneshot(request_with_key("GET", "/scores", key, None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        for (method, uri) in [("GET", "/export"), ("GET", "/api-keys"), ("DELETE", "/scores/1")] {
+            let response = app.clone().oneshot(request_with_key(method, uri, key, None)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{method} {uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_admin_key_and_listing() {
+        let app = create_test_app().await;
+        let created = create_test_key(&app, "admin").await;
+        let key = created["key"].as_str().unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("POST", "/games", key, Some(&json!({ "name": "Admin Game" }).to_string())))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app.oneshot(request_with_key("GET", "/api-keys", key, None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let keys = response_json(response).await;
+        let listed = &keys.as_array().unwrap()[0];
+        assert_eq!(listed["id"], created["id"]);
+        assert_eq!(listed["prefix"], key[..12]);
+        assert!(listed.get("key").is_none());
+        assert!(listed.get("key_hash").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_revoked_key_is_rejected() {
+        let app = create_test_app().await;
+        let created = create_test_key(&app, "read").await;
+        let key = created["key"].as_str().unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("DELETE", &format!("/api-keys/{}", created["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = app.clone().oneshot(request_with_key("GET", "/games", key, None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .oneshot(request_with_api_key("DELETE", &format!("/api-keys/{}", created["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    async fn create_bound_key(app: &Router, scope: &str, game_hex_ids: &[&str]) -> String {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/api-keys",
+                Some(&json!({ "name": "Demo build", "scope": scope, "game_hex_ids": game_hex_ids }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let created = response_json(response).await;
+        assert_eq!(created["game_hex_ids"], json!(game_hex_ids));
+        created["key"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_game_bound_key_submissions() {
+        let app = create_test_app().await;
+        let demo = create_test_game(&app, "Demo").await;
+        let main_game = create_test_game(&app, "Main").await;
+        let key = create_bound_key(&app, "submit", &[&demo]).await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("POST", "/scores", &key, Some(&score_body(&demo))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .oneshot(request_with_key("POST", "/scores", &key, Some(&score_body(&main_game))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_game_bound_key_listings() {
+        let app = create_test_app().await;
+        let demo = create_test_game(&app, "Demo").await;
+        let main_game = create_test_game(&app, "Main").await;
+        let key = create_bound_key(&app, "read", &[&demo]).await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("GET", &format!("/scores?game_hex_id={demo}"), &key, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("GET", &format!("/scores?game_hex_id={main_game}"), &key, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        // Global listings would expose other games
+        let response = app.oneshot(request_with_key("GET", "/scores", &key, None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_game_bound_key_updates() {
+        let app = create_test_app().await;
+        let demo = create_test_game(&app, "Demo").await;
+        let main_game = create_test_game(&app, "Main").await;
+        let demo_score = create_test_score(&app, &demo, "player", 10.0).await;
+        let main_score = create_test_score(&app, &main_game, "player", 10.0).await;
+        let key = create_bound_key(&app, "admin", &[&demo]).await;
+        let update = json!({ "score": "20" }).to_string();
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("PUT", &format!("/scores/{}", demo_score["id"]), &key, Some(&update)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(request_with_key("PUT", &format!("/scores/{}", main_score["id"]), &key, Some(&update)))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_game_bound_key_score_routes() {
+        let app = create_test_app().await;
+        let demo = create_test_game(&app, "Demo").await;
+        let main_game = create_test_game(&app, "Main").await;
+        let demo_score = create_test_score(&app, &demo, "player", 10.0).await;
+        let main_score = create_test_score(&app, &main_game, "player", 10.0).await;
+        let deleted_score = create_test_score(&app, &main_game, "other", 5.0).await;
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("DELETE", &format!("/scores/{}", deleted_score["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        let key = create_bound_key(&app, "admin", &[&demo]).await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("GET", &format!("/scores/{}", demo_score["id"]), &key, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        for (method, uri) in [
+            ("GET", format!("/scores/{}", main_score["id"])),
+            ("DELETE", format!("/scores/{}", main_score["id"])),
+            ("POST", format!("/scores/{}/restore", deleted_score["id"])),
+        ] {
+            let response = app.clone().oneshot(request_with_key(method, &uri, &key, None)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{method} {uri}");
+        }
+
+        // Nothing was changed for the other game
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/scores/{}", main_score["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_game_bound_key_game_routes() {
+        let app = create_test_app().await;
+        let demo = create_test_game(&app, "Demo").await;
+        let main_game = create_test_game(&app, "Main").await;
+        let main_score = create_test_score(&app, &main_game, "player", 10.0).await;
+        let key = create_bound_key(&app, "admin", &[&demo]).await;
+        let season = json!({
+            "name": "Season 1",
+            "starts_at": "2030-01-01T00:00:00Z",
+            "ends_at": "2030-02-01T00:00:00Z"
+        })
+        .to_string();
+        let leaderboard = json!({ "slug": "hard", "name": "Hard" }).to_string();
+        let update = json!({ "name": "Renamed" }).to_string();
+
+        let response = app
+            .clone()
+            .oneshot(request_with_key("GET", &format!("/games/{demo}/seasons"), &key, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        for (method, uri, body) in [
+            ("GET", format!("/games/{main_game}"), None),
+            ("PUT", format!("/games/{main_game}"), Some(update.as_str())),
+            ("DELETE", format!("/games/{main_game}"), None),
+            ("POST", format!("/games/{main_game}/restore"), None),
+            ("POST", format!("/games/{main_game}/signing-secret"), None),
+            ("DELETE", format!("/games/{main_game}/signing-secret"), None),
+            ("POST", format!("/games/{main_game}/seasons"), Some(season.as_str())),
+            ("GET", format!("/games/{main_game}/seasons"), None),
+            ("GET", format!("/games/{main_game}/seasons/1"), None),
+            ("POST", format!("/games/{main_game}/seasons/1/close"), None),
+            ("GET", format!("/games/{main_game}/seasons/1/standings"), None),
+            ("GET", format!("/games/{main_game}/ranks/player"), None),
+            ("GET", format!("/games/{main_game}/leaderboard/around/player"), None),
+            (
+                "GET",
+                format!("/games/{main_game}/leaderboard/around?around_score_id={}", main_score["id"]),
+                None,
+            ),
+            ("POST", format!("/games/{main_game}/leaderboards"), Some(leaderboard.as_str())),
+            ("GET", format!("/games/{main_game}/leaderboards"), None),
+        ] {
+            let response = app.clone().oneshot(request_with_key(method, &uri, &key, body)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{method} {uri}");
+        }
+
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/games/{main_game}"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response_json(response).await["name"], "Main");
+    }
+
+    #[tokio::test]
+    async fn test_game_bound_key_cannot_reach_every_game() {
+        let app = create_test_app().await;
+        let demo = create_test_game(&app, "Demo").await;
+        let key = create_bound_key(&app, "admin", &[&demo]).await;
+        let new_key = json!({ "name": "Escalated", "scope": "admin" }).to_string();
+
+        for (method, uri, body) in [
+            ("POST", "/api-keys", Some(new_key.as_str())),
+            ("GET", "/api-keys", None),
+            ("DELETE", "/api-keys/1", None),
+            ("GET", "/export", None),
+            ("POST", "/import", Some("")),
+            ("POST", "/purge?older_than_days=30", None),
+        ] {
+            let response = app.clone().oneshot(request_with_key(method, uri, &key, body)).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{method} {uri}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_key_for_unknown_game() {
+        let app = create_test_app().await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "POST",
+                "/api-keys",
+                Some(&json!({ "name": "Bad", "scope": "read", "game_hex_ids": ["zzz999"] }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_create_key_invalid_scope() {
+        let app = create_test_app().await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "POST",
+                "/api-keys",
+                Some(&json!({ "name": "Bad", "scope": "superuser" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[cfg(test)]
+mod signing_tests {
+    use super::*;
+    use leadr_api::auth::SubmissionSignature;
+    use leadr_api::models::CreateScore;
+
+    async fn enable_signing(app: &Router, hex_id: &str) -> String {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                &format!("/games/{hex_id}/signing-secret"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = response_json(response).await;
+        assert_eq!(body["game_hex_id"], hex_id);
+        body["signing_secret"].as_str().unwrap().to_string()
+    }
+
+    fn score_data(hex_id: &str) -> serde_json::Value {
+        json!({
+            "game_hex_id": hex_id,
+            "score": "1,250",
+            "score_val": 1250.0,
+            "user_name": "Player",
+            "user_id": "player",
+            "extra": { "level": 3, "character": "mage" }
+        })
+    }
+
+    fn signed_request(body: &serde_json::Value, timestamp: i64, nonce: &str, signature: &str) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri("/scores")
+            .header("leadr-api-key", "test_api_key_123")
+            .header("content-type", "application/json")
+            .header("leadr-timestamp", timestamp.to_string())
+            .header("leadr-nonce", nonce)
+            .header("leadr-signature", signature)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    fn sign(secret: &str, body: &serde_json::Value, timestamp: i64, nonce: &str) -> String {
+        let create_data: CreateScore = serde_json::from_value(body.clone()).unwrap();
+        SubmissionSignature::sign(secret, timestamp, nonce, &create_data.canonical_payload())
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_submissions_accepted_without_secret() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Unsigned").await;
+
+        create_test_score(&app, &hex_id, "player", 10.0).await;
+    }
+
+    #[tokio::test]
+    async fn test_signed_submission_accepted() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let secret = enable_signing(&app, &hex_id).await;
+
+        let body = score_data(&hex_id);
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(&secret, &body, timestamp, "nonce-1");
+
+        let response = app
+            .clone()
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        // The secret is never exposed on the game itself
+        let response = app
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}"), None))
+            .await
+            .unwrap();
+        let game = response_json(response).await;
+        assert!(game.get("signing_secret").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_unsigned_submission_rejected_with_secret() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        enable_signing(&app, &hex_id).await;
+
+        let response = app
+            .oneshot(request_with_api_key(
+                "POST",
+                "/scores",
+                Some(&score_data(&hex_id).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_tampered_submission_rejected() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let secret = enable_signing(&app, &hex_id).await;
+
+        let body = score_data(&hex_id);
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(&secret, &body, timestamp, "nonce-1");
+
+        let mut tampered = body.clone();
+        tampered["score_val"] = json!(999_999.0);
+        let response = app
+            .clone()
+            .oneshot(signed_request(&tampered, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        // A signature made with another secret is rejected too
+        let forged = sign("not-the-secret", &body, timestamp, "nonce-2");
+        let response = app
+            .oneshot(signed_request(&body, timestamp, "nonce-2", &forged))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_stale_timestamp_rejected() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let secret = enable_signing(&app, &hex_id).await;
+
+        let body = score_data(&hex_id);
+        let timestamp = chrono::Utc::now().timestamp() - 3600;
+        let signature = sign(&secret, &body, timestamp, "nonce-1");
+
+        let response = app
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_replayed_nonce_rejected() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let secret = enable_signing(&app, &hex_id).await;
+
+        let body = score_data(&hex_id);
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(&secret, &body, timestamp, "nonce-1");
+
+        let response = app
+            .clone()
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_failed_signed_submission_can_be_retried() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let secret = enable_signing(&app, &hex_id).await;
+
+        let mut body = score_data(&hex_id);
+        body["leaderboard"] = json!("hard");
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(&secret, &body, timestamp, "nonce-1");
+
+        // The board does not exist yet, so the nonce is not used up
+        let response = app
+            .clone()
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let board = json!({ "slug": "hard", "name": "Hard" });
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("POST", &format!("/games/{hex_id}/leaderboards"), Some(&board.to_string())))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .clone()
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_rotate_and_clear_signing_secret() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let old_secret = enable_signing(&app, &hex_id).await;
+        let new_secret = enable_signing(&app, &hex_id).await;
+        assert_ne!(old_secret, new_secret);
+
+        // Submissions signed with the rotated-out secret are rejected
+        let body = score_data(&hex_id);
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = sign(&old_secret, &body, timestamp, "nonce-1");
+        let response = app
+            .clone()
+            .oneshot(signed_request(&body, timestamp, "nonce-1", &signature))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "DELETE",
+                &format!("/games/{hex_id}/signing-secret"),
+                None,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        create_test_score(&app, &hex_id, "player", 10.0).await;
+    }
+
+    #[tokio::test]
+    async fn test_signing_secret_requires_existing_game() {
+        let app = create_test_app().await;
+
+        let response = app
+            .oneshot(request_with_api_key("POST", "/games/abc123/signing-secret", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
+
+#[cfg(test)]
+mod score_validation_tests {
+    use super::*;
+
+    async fn create_game_with_rules(app: &Router, validation: serde_json::Value) -> String {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/games",
+                Some(&json!({ "name": "Validated Game", "validation": validation }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        response_json(response).await["hex_id"].as_str().unwrap().to_string()
+    }
+
+    async fn submit(app: &Router, score_data: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("POST", "/scores", Some(&score_data.to_string())))
+            .await
+            .unwrap();
+        let status = response.status();
+        (status, response_json(response).await)
+    }
+
+    fn score_data(hex_id: &str, user_id: &str, score_val: f64) -> serde_json::Value {
+        json!({
+            "game_hex_id": hex_id,
+            "score": score_val.to_string(),
+            "score_val": score_val,
+            "user_name": "Player",
+            "user_id": user_id
+        })
+    }
+
+    #[tokio::test]
+    async fn test_game_exposes_default_rules() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Defaults").await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}"), None))
+            .await
+            .unwrap();
+        let game = response_json(response).await;
+        assert_eq!(game["validation"]["integer_only"], false);
+        assert!(game["validation"]["min_score_val"].is_null());
+        assert!(game["validation"]["extra_schema"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_rejects_out_of_range_and_fractional_scores() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_rules(
+            &app,
+            json!({ "min_score_val": 0, "max_score_val": 1000000, "integer_only": true }),
+        )
+        .await;
+
+        let (status, _) = submit(&app, score_data(&hex_id, "player", 500.0)).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, body) = submit(&app, score_data(&hex_id, "player", 1e300)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("above the maximum"));
+
+        let (status, body) = submit(&app, score_data(&hex_id, "player", -5.0)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("below the minimum"));
+
+        let (status, body) = submit(&app, score_data(&hex_id, "player", 12.5)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("whole number"));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unparseable_score_without_score_val() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Parsing").await;
+
+        let (status, body) = submit(
+            &app,
+            json!({
+                "game_hex_id": hex_id,
+                "score": "lots",
+                "user_name": "Player",
+                "user_id": "player"
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("score_val is required"));
+    }
+
+    #[tokio::test]
+    async fn test_score_updates_are_validated() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_rules(
+            &app,
+            json!({
+                "min_score_val": 0,
+                "max_score_val": 1000,
+                "integer_only": true,
+                "extra_schema": { "type": "object", "required": ["level"] }
+            }),
+        )
+        .await;
+        let mut valid = score_data(&hex_id, "player", 500.0);
+        valid["extra"] = json!({ "level": 1 });
+        let (status, body) = submit(&app, valid).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let uri = format!("/scores/{}", body["id"]);
+
+        for (update, message) in [
+            (json!({ "score": "garbage" }), "score_val is required"),
+            (json!({ "score_val": 1e300 }), "above the maximum"),
+            (json!({ "score": "12.5" }), "whole number"),
+            (json!({ "extra": { "time": 3 } }), "schema"),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(request_with_api_key("PUT", &uri, Some(&update.to_string())))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY, "{update}");
+            let body = response_json(response).await;
+            assert!(body["error"].as_str().unwrap().contains(message), "{update}");
+        }
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("PUT", &uri, Some(&json!({ "score": "700" }).to_string())))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_json(response).await;
+        assert_eq!(body["score_val"], 700.0);
+        assert_eq!(body["extra"]["level"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_extra_must_match_schema() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_rules(
+            &app,
+            json!({
+                "extra_schema": {
+                    "type": "object",
+                    "properties": { "level": { "type": "integer" } },
+                    "required": ["level"]
+                }
+            }),
+        )
+        .await;
+
+        let mut valid = score_data(&hex_id, "player", 10.0);
+        valid["extra"] = json!({ "level": 4 });
+        let (status, _) = submit(&app, valid).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let mut invalid = score_data(&hex_id, "player", 10.0);
+        invalid["extra"] = json!({ "level": "four" });
+        let (status, body) = submit(&app, invalid).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("schema"));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_per_user() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_rules(&app, json!({ "max_submissions_per_minute": 2 })).await;
+
+        for score_val in [1.0, 2.0] {
+            let (status, _) = submit(&app, score_data(&hex_id, "spammer", score_val)).await;
+            assert_eq!(status, StatusCode::CREATED);
+        }
+
+        let (status, body) = submit(&app, score_data(&hex_id, "spammer", 3.0)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].as_str().unwrap().contains("per minute"));
+
+        // Other players have their own allowance
+        let (status, _) = submit(&app, score_data(&hex_id, "other", 3.0)).await;
+        assert_eq!(status, StatusCode::CREATED);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_rules_rejected() {
+        let app = create_test_app().await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/games",
+                Some(&json!({ "name": "Bad", "validation": { "min_score_val": 10, "max_score_val": 1 } }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let hex_id = create_test_game(&app, "Updatable").await;
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "PUT",
+                &format!("/games/{hex_id}"),
+                Some(&json!({ "validation": { "extra_schema": { "type": 5 } } }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_update_replaces_rules() {
+        let app = create_test_app().await;
+        let hex_id = create_game_with_rules(&app, json!({ "max_score_val": 10 })).await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "PUT",
+                &format!("/games/{hex_id}"),
+                Some(&json!({ "validation": { "integer_only": true } }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let game = response_json(response).await;
+        assert!(game["validation"]["max_score_val"].is_null());
+        assert_eq!(game["validation"]["integer_only"], true);
+
+        let (status, _) = submit(&app, score_data(&hex_id, "player", 50.0)).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        // Updates without `validation` leave the rules alone
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "PUT",
+                &format!("/games/{hex_id}"),
+                Some(&json!({ "name": "Renamed" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        let game = response_json(response).await;
+        assert_eq!(game["validation"]["integer_only"], true);
+    }
+}
+
+#[cfg(test)]
+mod export_tests {
+    use super::*;
+
+    async fn export_csv(app: &Router, query: &str) -> Vec<csv::StringRecord> {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/export{query}"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let mut reader = csv::Reader::from_reader(body.as_ref());
+        let headers = reader.headers().unwrap().clone();
+        assert_eq!(&headers[0], "game_hex_id");
+        assert!(!headers.iter().any(|h| h == "score_updated_at"));
+        reader.records().map(Result::unwrap).collect()
+    }
+
+    #[tokio::test]
+    async fn test_export_rows_match_schema() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Exported").await;
+        let empty_hex_id = create_test_game(&app, "No Scores").await;
+        create_test_score(&app, &hex_id, "player1", 100.0).await;
+        create_test_score(&app, &hex_id, "player2", 250.5).await;
+
+        let records = export_csv(&app, "").await;
+        assert_eq!(records.len(), 3);
+
+        let scored: Vec<_> = records.iter().filter(|r| &r[0] == hex_id.as_str()).collect();
+        assert_eq!(scored.len(), 2);
+        assert_eq!(&scored[0][1], "Exported");
+        assert_eq!(&scored[0][6], "default");
+        assert_eq!(&scored[1][12], "250.5");
+        assert_eq!(&scored[1][14], "player2");
+
+        let empty: Vec<_> = records.iter().filter(|r| &r[0] == empty_hex_id.as_str()).collect();
+        assert_eq!(empty.len(), 1);
+        assert_eq!(&empty[0][6], "default");
+        assert_eq!(&empty[0][10], "");
+    }
+
+    #[tokio::test]
+    async fn test_export_empty_database_has_header() {
+        let app = create_test_app().await;
+        assert!(export_csv(&app, "").await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_export_filters_by_game() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Wanted").await;
+        let other_hex_id = create_test_game(&app, "Other").await;
+        create_test_score(&app, &hex_id, "player", 1.0).await;
+        create_test_score(&app, &other_hex_id, "player", 2.0).await;
+
+        let records = export_csv(&app, &format!("?game_hex_id={hex_id}")).await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0][0], hex_id.as_str());
+    }
+
+    #[tokio::test]
+    async fn test_export_filters_by_since() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Incremental").await;
+        let now = chrono::Utc::now();
+        create_test_score_at(&pool, &hex_id, "old", 1.0, now - chrono::Duration::days(3)).await;
+        create_test_score_at(&pool, &hex_id, "new", 2.0, now - chrono::Duration::hours(1)).await;
+
+        let since = (now - chrono::Duration::days(1)).to_rfc3339();
+        let query = serde_urlencoded::to_string([("since", since)]).unwrap();
+        let records = export_csv(&app, &format!("?{query}")).await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0][14], "new");
+    }
+
+    #[tokio::test]
+    async fn test_export_include_deleted() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Deletions").await;
+        create_test_score(&app, &hex_id, "kept", 1.0).await;
+        let removed = create_test_score(&app, &hex_id, "removed", 2.0).await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("DELETE", &format!("/scores/{}", removed["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        // Complete backups include soft-deleted rows by default
+        let records = export_csv(&app, "").await;
+        assert_eq!(records.len(), 2);
+        assert!(records.iter().any(|r| &r[14] == "removed" && !r[17].is_empty()));
+
+        let records = export_csv(&app, "?include_deleted=false").await;
+        assert_eq!(records.len(), 1);
+        assert_eq!(&records[0][14], "kept");
+    }
+
+    async fn export_body(app: &Router, query: &str) -> (axum::http::HeaderMap, axum::body::Bytes) {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/export{query}"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let headers = response.headers().clone();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (headers, body)
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_is_typed() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Typed").await;
+        let score_data = json!({
+            "game_hex_id": hex_id,
+            "score": "1,000",
+            "score_val": 1000.0,
+            "user_name": "Player",
+            "user_id": "player",
+            "extra": { "level": 5, "tags": ["speedrun"] }
+        });
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("POST", "/scores", Some(&score_data.to_string())))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let (headers, body) = export_body(&app, "?format=jsonl").await;
+        assert_eq!(headers.get("content-type").unwrap(), "application/x-ndjson");
+        assert!(headers.get("content-disposition").unwrap().to_str().unwrap().ends_with(".jsonl\""));
+
+        let lines: Vec<serde_json::Value> = std::str::from_utf8(&body)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+
+        assert_eq!(lines[0]["type"], "game");
+        assert_eq!(lines[0]["hex_id"], hex_id.as_str());
+        assert_eq!(lines[0]["name"], "Typed");
+
+        assert_eq!(lines[1]["type"], "leaderboard");
+        assert_eq!(lines[1]["game_hex_id"], hex_id.as_str());
+        assert_eq!(lines[1]["slug"], "default");
+
+        assert_eq!(lines[2]["type"], "score");
+        assert_eq!(lines[2]["game_hex_id"], hex_id.as_str());
+        assert_eq!(lines[2]["leaderboard"], "default");
+        assert_eq!(lines[2]["score"], "1,000");
+        assert_eq!(lines[2]["score_val"], 1000.0);
+        assert_eq!(lines[2]["extra"], score_data["extra"]);
+    }
+
+    #[tokio::test]
+    async fn test_export_jsonl_applies_filters() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Wanted").await;
+        let other_hex_id = create_test_game(&app, "Other").await;
+        create_test_score(&app, &hex_id, "player", 1.0).await;
+        create_test_score(&app, &other_hex_id, "player", 2.0).await;
+
+        let (_, body) = export_body(&app, &format!("?format=jsonl&game_hex_id={hex_id}")).await;
+        let lines: Vec<serde_json::Value> = std::str::from_utf8(&body)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|line| line["hex_id"] == hex_id.as_str() || line["game_hex_id"] == hex_id.as_str()));
+    }
+
+    #[tokio::test]
+    async fn test_export_sqlite_snapshot() {
+        // VACUUM INTO needs a file-backed source database
+        std::env::set_var("LEADR_API_KEY", "test_api_key_123");
+        let dir = tempfile::tempdir().unwrap();
+        let source = dir.path().join("source.db");
+        std::fs::File::create(&source).unwrap();
+        let pool = sqlx::sqlite::SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect(&format!("sqlite:{}", source.display()))
+            .await
+            .unwrap();
+        db::run_migrations(&pool).await.unwrap();
+        let app = create_app(pool);
+        let hex_id = create_test_game(&app, "Snapshot").await;
+        create_test_score(&app, &hex_id, "player1", 10.0).await;
+        create_test_score(&app, &hex_id, "player2", 20.0).await;
+
+        let (headers, body) = export_body(&app, "?format=sqlite").await;
+        assert_eq!(headers.get("content-type").unwrap(), "application/vnd.sqlite3");
+        assert!(body.starts_with(b"SQLite format 3\0"));
+
+        let path = dir.path().join("snapshot.db");
+        std::fs::write(&path, &body).unwrap();
+
+        let snapshot = db::create_pool(&format!("sqlite:{}", path.display())).await.unwrap();
+        let games: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM game WHERE hex_id = ?1")
+            .bind(&hex_id)
+            .fetch_one(&snapshot)
+            .await
+            .unwrap();
+        let scores: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM score")
+            .fetch_one(&snapshot)
+            .await
+            .unwrap();
+        assert_eq!(games, 1);
+        assert_eq!(scores, 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_sqlite_rejects_filters() {
+        let app = create_test_app().await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", "/export?format=sqlite&include_deleted=false", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", "/export?format=xml", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_export_rejects_invalid_filters() {
+        let app = create_test_app().await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", "/export?since=yesterday", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", "/export?game_hex_id=short", None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
+
+#[cfg(test)]
+mod import_tests {
+    use super::*;
+
+    fn import_request(query: &str, content_type: &str, body: &str) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri(format!("/import{query}"))
+            .header("leadr-api-key", "test_api_key_123")
+            .header("content-type", content_type)
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn import(app: &Router, query: &str, content_type: &str, body: &str) -> serde_json::Value {
+        let response = app
+            .clone()
+            .oneshot(import_request(query, content_type, body))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        response_json(response).await
+    }
+
+    async fn export(app: &Router, query: &str) -> String {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/export{query}"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    async fn get_json(app: &Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", uri, None))
+            .await
+            .unwrap();
+        let status = response.status();
+        (status, response_json(response).await)
+    }
+
+    const CSV_HEADER: &str = "game_hex_id,game_name,game_description,game_created_at,game_updated_at,game_deleted_at,score_id,score_value,score_val,user_name,user_id,extra,score_submitted_at,score_deleted_at";
+
+    #[tokio::test]
+    async fn test_jsonl_round_trip_preserves_ids() {
+        let source = create_test_app().await;
+        let hex_id = create_test_game(&source, "Round Trip").await;
+        create_test_score(&source, &hex_id, "player1", 10.0).await;
+        let deleted = create_test_score(&source, &hex_id, "player2", 20.0).await;
+        let response = source
+            .clone()
+            .oneshot(request_with_api_key("DELETE", &format!("/scores/{}", deleted["id"]), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let dump = export(&source, "?format=jsonl").await;
+
+        let target = create_test_app().await;
+        let report = import(&target, "", "application/x-ndjson", &dump).await;
+        assert_eq!(report["mode"], "merge");
+        assert_eq!(report["games"]["created"], 1);
+        assert_eq!(report["scores"]["created"], 2);
+        assert!(report["issues"].as_array().unwrap().is_empty());
+
+        // Same export from both sides, soft-deleted score included
+        assert_eq!(export(&target, "?format=jsonl").await, dump);
+
+        let (status, _) = get_json(&target, &format!("/scores/{}", deleted["id"])).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_round_trip_keeps_leaderboards() {
+        let source = create_test_app().await;
+        let hex_id = create_test_game(&source, "Boards").await;
+        for slug in ["hard", "empty"] {
+            let response = source
+                .clone()
+                .oneshot(request_with_api_key(
+                    "POST",
+                    &format!("/games/{hex_id}/leaderboards"),
+                    Some(&json!({ "slug": slug, "name": slug.to_uppercase(), "description": "Extra board" }).to_string()),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+        let easy = create_test_score(&source, &hex_id, "player", 10.0).await;
+        let response = source
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/scores",
+                Some(
+                    &json!({
+                        "game_hex_id": hex_id,
+                        "leaderboard": "hard",
+                        "score": "20",
+                        "user_name": "Player",
+                        "user_id": "player"
+                    })
+                    .to_string(),
+                ),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let hard = response_json(response).await;
+
+        for (format, content_type) in [("jsonl", "application/x-ndjson"), ("csv", "text/csv")] {
+            let dump = export(&source, &format!("?format={format}")).await;
+
+            let target = create_test_app().await;
+            let report = import(&target, "", content_type, &dump).await;
+            assert_eq!(report["leaderboards"]["created"], 3, "{format}");
+            assert!(report["issues"].as_array().unwrap().is_empty(), "{format}");
+
+            let (_, boards) = get_json(&target, &format!("/games/{hex_id}/leaderboards")).await;
+            let boards: Vec<_> = boards
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|b| (b["slug"].as_str().unwrap(), b["score_count"].as_i64().unwrap(), b["id"].clone()))
+                .collect();
+            let slugs: Vec<_> = boards.iter().map(|(slug, count, _)| (*slug, *count)).collect();
+            assert_eq!(slugs, vec![("default", 1), ("hard", 1), ("empty", 0)], "{format}");
+
+            let (_, score) = get_json(&target, &format!("/scores/{}", hard["id"])).await;
+            assert_eq!(score["leaderboard_id"], boards[1].2, "{format}");
+            let (_, score) = get_json(&target, &format!("/scores/{}", easy["id"])).await;
+            assert_eq!(score["leaderboard_id"], boards[0].2, "{format}");
+
+            let (_, empty) = get_json(&target, &format!("/games/{hex_id}/leaderboards")).await;
+            assert_eq!(empty[2]["name"], "EMPTY", "{format}");
+            assert_eq!(empty[2]["description"], "Extra board", "{format}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_csv_import_keeps_score_ids_and_deletions() {
+        let app = create_test_app().await;
+        let csv = format!(
+            "{CSV_HEADER}\n\
+             ABC123,Imported,From CSV,2024-01-01T10:00:00Z,2024-01-01T10:00:00Z,,41,100,100.0,Player1,user1,\"{{\"\"level\"\": 1}}\",2024-01-01T10:05:00Z,\n\
+             ABC123,Imported,From CSV,2024-01-01T10:00:00Z,2024-01-01T10:00:00Z,,42,200,200.0,Player2,user2,,2024-01-01T10:10:00Z,2024-01-02T00:00:00Z\n"
+        );
+
+        let report = import(&app, "", "text/csv", &csv).await;
+        assert_eq!(report["games"]["created"], 1);
+        assert_eq!(report["scores"]["created"], 2);
+
+        let (status, score) = get_json(&app, "/scores/41").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(score["game_hex_id"], "abc123");
+        assert_eq!(score["extra"]["level"], 1);
+
+        let (status, _) = get_json(&app, "/scores/42").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_append_skips_and_merge_updates_existing() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Original").await;
+        let score = create_test_score(&app, &hex_id, "player", 10.0).await;
+
+        let dump = export(&app, "?format=jsonl").await.replace("\"Original\"", "\"Renamed\"");
+
+        let report = import(&app, "?mode=append", "application/x-ndjson", &dump).await;
+        assert_eq!(report["games"]["skipped"], 1);
+        assert_eq!(report["scores"]["skipped"], 1);
+        assert_eq!(report["issues"][0]["outcome"], "skipped");
+        assert_eq!(report["issues"][0]["key"], hex_id.as_str());
+        let (_, game) = get_json(&app, &format!("/games/{hex_id}")).await;
+        assert_eq!(game["name"], "Original");
+
+        let report = import(&app, "?mode=merge", "application/x-ndjson", &dump).await;
+        assert_eq!(report["games"]["updated"], 1);
+        assert_eq!(report["scores"]["updated"], 1);
+        let (_, game) = get_json(&app, &format!("/games/{hex_id}")).await;
+        assert_eq!(game["name"], "Renamed");
+        let (status, _) = get_json(&app, &format!("/scores/{}", score["id"])).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_import_keeps_existing_player_names() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Names").await;
+        let score = create_test_score(&app, &hex_id, "player", 10.0).await;
+        let dump = export(&app, "?format=jsonl").await;
+        let newcomer = json!({
+            "type": "score",
+            "game_hex_id": hex_id,
+            "score": "5",
+            "user_name": "Newcomer",
+            "user_id": "newcomer",
+            "submitted_at": "2024-01-01T00:00:00Z"
+        });
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "PUT",
+                "/players/player",
+                Some(&json!({ "user_name": "Current" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let report = import(&app, "?mode=merge", "application/x-ndjson", &format!("{dump}\n{newcomer}")).await;
+        assert_eq!(report["scores"]["updated"], 1);
+        assert_eq!(report["scores"]["created"], 1);
+
+        let (_, player) = get_json(&app, "/players/player").await;
+        assert_eq!(player["user_name"], "Current");
+        let (_, stored) = get_json(&app, &format!("/scores/{}", score["id"])).await;
+        assert_eq!(stored["user_name"], "Current");
+        let (_, player) = get_json(&app, "/players/newcomer").await;
+        assert_eq!(player["user_name"], "Newcomer");
+    }
+
+    #[tokio::test]
+    async fn test_replace_soft_deletes_missing_records() {
+        let app = create_test_app().await;
+        let kept = create_test_game(&app, "Kept").await;
+        create_test_score(&app, &kept, "player", 1.0).await;
+        let dump = export(&app, &format!("?format=jsonl&game_hex_id={kept}")).await;
+
+        let dropped = create_test_game(&app, "Dropped").await;
+        create_test_score(&app, &dropped, "player", 2.0).await;
+        let extra_score = create_test_score(&app, &kept, "newcomer", 3.0).await;
+
+        let report = import(&app, "?mode=replace", "application/x-ndjson", &dump).await;
+        assert_eq!(report["games"]["deleted"], 1);
+        assert_eq!(report["scores"]["deleted"], 2);
+
+        let (status, _) = get_json(&app, &format!("/games/{dropped}")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = get_json(&app, &format!("/scores/{}", extra_score["id"])).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = get_json(&app, &format!("/games/{kept}")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_dry_run_saves_nothing() {
+        let app = create_test_app().await;
+        let csv = format!(
+            "{CSV_HEADER}\nabc123,Dry Run,,2024-01-01T10:00:00Z,2024-01-01T10:00:00Z,,7,5,5.0,Player,user,,2024-01-01T10:05:00Z,\n"
+        );
+
+        let report = import(&app, "?dry_run=true", "text/csv", &csv).await;
+        assert_eq!(report["dry_run"], true);
+        assert_eq!(report["games"]["created"], 1);
+        assert_eq!(report["scores"]["created"], 1);
+
+        let (status, _) = get_json(&app, "/games/abc123").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_failed_rows_are_reported() {
+        let app = create_test_app().await;
+        let jsonl = [
+            r#"{"type":"game","hex_id":"abc123","name":"Good","created_at":"2024-01-01T00:00:00Z"}"#,
+            r#"{"type":"score","id":1,"game_hex_id":"abc123","score":"10","user_name":"","user_id":"user","submitted_at":"2024-01-01T00:00:00Z"}"#,
+            r#"{"type":"score","id":2,"game_hex_id":"zzz999","score":"10","user_name":"Player","user_id":"user","submitted_at":"2024-01-01T00:00:00Z"}"#,
+            "not json",
+            r#"{"type":"score","id":3,"game_hex_id":"abc123","score":"10","user_name":"Player","user_id":"user","submitted_at":"2024-01-01T00:00:00Z"}"#,
+        ]
+        .join("\n");
+
+        let report = import(&app, "?format=jsonl", "text/plain", &jsonl).await;
+        assert_eq!(report["games"]["created"], 1);
+        assert_eq!(report["scores"]["created"], 1);
+        assert_eq!(report["scores"]["failed"], 2);
+
+        let issues = report["issues"].as_array().unwrap();
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0]["line"], 2);
+        assert_eq!(issues[0]["key"], "1");
+        assert!(issues[0]["reason"].as_str().unwrap().contains("User name"));
+        assert!(issues[1]["reason"].as_str().unwrap().contains("Unknown game"));
+        assert_eq!(issues[2]["line"], 4);
+        assert!(issues[2]["record"].is_null());
+    }
+
+    #[tokio::test]
+    async fn test_import_rejects_ids_clashing_with_slugs() {
+        let app = create_test_app().await;
+        let jsonl = [
+            r#"{"type":"game","hex_id":"abc123","slug":"galaga","name":"Galaga","created_at":"2024-01-01T00:00:00Z"}"#,
+            r#"{"type":"game","hex_id":"galaga","name":"Shadow","created_at":"2024-01-01T00:00:00Z"}"#,
+            r#"{"type":"game","hex_id":"def456","slug":"abc123","name":"Other","created_at":"2024-01-01T00:00:00Z"}"#,
+        ]
+        .join("\n");
+
+        let report = import(&app, "?format=jsonl", "text/plain", &jsonl).await;
+        assert_eq!(report["games"]["created"], 1);
+        assert_eq!(report["games"]["failed"], 2);
+        let issues = report["issues"].as_array().unwrap();
+        assert!(issues[0]["reason"].as_str().unwrap().contains("slug"));
+        assert!(issues[1]["reason"].as_str().unwrap().contains("already used"));
+    }
+
+    #[tokio::test]
+    async fn test_import_requires_admin_and_valid_params() {
+        let app = create_test_app().await;
+
+        let response = app
+            .clone()
+            .oneshot(request_without_api_key("POST", "/import"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app
+            .clone()
+            .oneshot(import_request("?mode=overwrite", "text/csv", CSV_HEADER))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_seed_from_csv_uses_import() {
+        let (_, pool) = create_test_app_with_pool().await;
+        db::seed::seed_from_csv(&pool, "test_seed.csv").await.unwrap();
+
+        let ids: Vec<i64> = sqlx::query_scalar("SELECT id FROM score ORDER BY id")
+            .fetch_all(&pool)
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![1, 2, 3]);
+        let games: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM game")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(games, 3);
+    }
+}
+
+#[cfg(test)]
+mod restore_tests {
+    use super::*;
+
+    fn hex_ids(page: &serde_json::Value) -> Vec<&str> {
+        page["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|game| game["hex_id"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_restore_game_restores_its_scores() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Restorable").await;
+        let kept = create_test_score(&app, &hex_id, "player1", 10.0).await;
+        let removed_earlier = create_test_score(&app, &hex_id, "player2", 20.0).await;
+
+        let (status, _) = send(&app, "DELETE", &format!("/scores/{}", removed_earlier["id"]), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        // Make sure the game is deleted at a later instant than the score
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        let (status, _) = send(&app, "DELETE", &format!("/games/{hex_id}"), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let (status, game) = send(&app, "POST", &format!("/games/{hex_id}/restore"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["hex_id"], hex_id.as_str());
+        assert!(game["deleted_at"].is_null());
+
+        let (status, _) = send(&app, "GET", &format!("/scores/{}", kept["id"]), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(&app, "GET", &format!("/scores/{}", removed_earlier["id"]), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // Already live
+        let (status, _) = send(&app, "POST", &format!("/games/{hex_id}/restore"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_restore_score() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Scores").await;
+        let score = create_test_score(&app, &hex_id, "player", 10.0).await;
+        let uri = format!("/scores/{}/restore", score["id"]);
+
+        let (status, _) = send(&app, "POST", &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        send(&app, "DELETE", &format!("/scores/{}", score["id"]), None).await;
+        let (status, restored) = send(&app, "POST", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(restored["id"], score["id"]);
+        assert!(restored["deleted_at"].is_null());
+
+        let (status, _) = send(&app, "POST", "/scores/999999/restore", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_restore_score_of_deleted_game_is_rejected() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Gone").await;
+        let score = create_test_score(&app, &hex_id, "player", 10.0).await;
+        send(&app, "DELETE", &format!("/games/{hex_id}"), None).await;
+
+        let (status, body) = send(&app, "POST", &format!("/scores/{}/restore", score["id"]), None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["error"].to_string().contains("restore the game"));
+    }
+
+    #[tokio::test]
+    async fn test_list_games_deleted_filters() {
+        let app = create_test_app().await;
+        let live = create_test_game(&app, "Live").await;
+        let deleted = create_test_game(&app, "Deleted").await;
+        send(&app, "DELETE", &format!("/games/{deleted}"), None).await;
+
+        let (_, page) = send(&app, "GET", "/games", None).await;
+        assert_eq!(hex_ids(&page), vec![live.as_str()]);
+
+        let (_, page) = send(&app, "GET", "/games?include_deleted=true", None).await;
+        let mut listed = hex_ids(&page);
+        listed.sort_unstable();
+        let mut expected = vec![live.as_str(), deleted.as_str()];
+        expected.sort_unstable();
+        assert_eq!(listed, expected);
+
+        let (_, page) = send(&app, "GET", "/games?only_deleted=true", None).await;
+        assert_eq!(hex_ids(&page), vec![deleted.as_str()]);
+        assert!(page["data"][0]["deleted_at"].is_string());
+
+        let (status, _) = send(&app, "GET", "/games?only_deleted=maybe", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_list_scores_deleted_filters() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Board").await;
+        create_test_score(&app, &hex_id, "player1", 10.0).await;
+        let deleted = create_test_score(&app, &hex_id, "player2", 50.0).await;
+        send(&app, "DELETE", &format!("/scores/{}", deleted["id"]), None).await;
+
+        let (_, page) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}&include_deleted=true"), None).await;
+        let data = page["data"].as_array().unwrap();
+        assert_eq!(data.len(), 2);
+        // The deleted score sorts first by value but does not take the top rank
+        assert_eq!(data[0]["id"], deleted["id"]);
+        assert!(data[0]["rank"].is_null());
+        assert_eq!(data[1]["rank"], 1);
+
+        let (_, page) = send(&app, "GET", "/scores?only_deleted=true", None).await;
+        let data = page["data"].as_array().unwrap();
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0]["id"], deleted["id"]);
+    }
+
+    #[tokio::test]
+    async fn test_deleted_listings_and_restore_need_admin_scope() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Scoped").await;
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/api-keys",
+                Some(&json!({ "name": "reader", "scope": "read" }).to_string()),
+            ))
+            .await
+            .unwrap();
+        let created = response_json(response).await;
+        let key = created["key"].as_str().unwrap();
+
+        for (method, uri, expected) in [
+            ("GET", "/games".to_string(), StatusCode::OK),
+            ("GET", "/games?include_deleted=true".to_string(), StatusCode::FORBIDDEN),
+            ("GET", "/scores?only_deleted=true".to_string(), StatusCode::FORBIDDEN),
+            ("POST", format!("/games/{hex_id}/restore"), StatusCode::FORBIDDEN),
+            ("POST", "/scores/1/restore".to_string(), StatusCode::FORBIDDEN),
+        ] {
+            let request = Request::builder()
+                .method(method)
+                .uri(&uri)
+                .header("leadr-api-key", key)
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), expected, "{method} {uri}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod purge_tests {
+    use super::*;
+
+    async fn purge(app: &Router, query: &str) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("POST", &format!("/purge{query}"), None))
+            .await
+            .unwrap();
+        let status = response.status();
+        (status, response_json(response).await)
+    }
+
+    async fn delete(app: &Router, uri: &str) {
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("DELETE", uri, None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    }
+
+    /// Moves every soft-deletion back by `days` days.
+    async fn age_deletions(pool: &db::DbPool, days: i64) {
+        let modifier = format!("-{days} days");
+        sqlx::query("UPDATE game SET deleted_at = datetime(deleted_at, ?1) WHERE deleted_at IS NOT NULL")
+            .bind(&modifier)
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("UPDATE score SET deleted_at = datetime(deleted_at, ?1) WHERE deleted_at IS NOT NULL")
+            .bind(&modifier)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    async fn count(pool: &db::DbPool, sql: &str) -> i64 {
+        sqlx::query_scalar(sql).fetch_one(pool).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_purge_removes_old_deletions_and_dependents() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let live = create_test_game(&app, "Live").await;
+        let gone = create_test_game(&app, "Gone").await;
+        create_test_score(&app, &live, "kept", 1.0).await;
+        let old_score = create_test_score(&app, &live, "old", 2.0).await;
+        create_test_score(&app, &gone, "player", 3.0).await;
+
+        let season_id: i64 = sqlx::query_scalar(
+            "INSERT INTO season (game_hex_id, name, starts_at, ends_at, closed_at) VALUES (?1, 'S1', '2024-01-01', '2024-02-01', '2024-02-01') RETURNING id",
+        )
+        .bind(&gone)
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO season_standing (season_id, rank, user_id, user_name, score, score_val, submitted_at) VALUES (?1, 1, 'player', 'Player', '3', 3.0, '2024-01-15')",
+        )
+        .bind(season_id)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        delete(&app, &format!("/scores/{}", old_score["id"])).await;
+        delete(&app, &format!("/games/{gone}")).await;
+        age_deletions(&pool, 40).await;
+        let recent_score = create_test_score(&app, &live, "recent", 4.0).await;
+        delete(&app, &format!("/scores/{}", recent_score["id"])).await;
+
+        let (status, report) = purge(&app, "?older_than_days=30").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(report["games"], 1);
+        assert_eq!(report["scores"], 2);
+        assert_eq!(report["seasons"], 1);
+        assert_eq!(report["games_kept"], 0);
+
+        assert_eq!(count(&pool, "SELECT COUNT(*) FROM game").await, 1);
+        assert_eq!(count(&pool, "SELECT COUNT(*) FROM season_standing").await, 0);
+        // The live score and the recently deleted one remain
+        assert_eq!(count(&pool, "SELECT COUNT(*) FROM score").await, 2);
+
+        let (_, report) = purge(&app, "?older_than_days=30").await;
+        assert_eq!(report["games"], 0);
+        assert_eq!(report["scores"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_purge_keeps_games_on_active_key_allow_lists() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Bound").await;
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key(
+                "POST",
+                "/api-keys",
+                Some(&json!({ "name": "Bound", "scope": "read", "game_hex_ids": [hex_id] }).to_string()),
+            ))
+            .await
+            .unwrap();
+        let key_id = response_json(response).await["id"].clone();
+        delete(&app, &format!("/games/{hex_id}")).await;
+
+        let before = (chrono::Utc::now() + chrono::Duration::minutes(1)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
+        let (_, report) = purge(&app, &format!("?before={before}")).await;
+        assert_eq!(report["games"], 0);
+        assert_eq!(report["games_kept"], 1);
+
+        delete(&app, &format!("/api-keys/{key_id}")).await;
+        let (_, report) = purge(&app, &format!("?before={before}")).await;
+        assert_eq!(report["games"], 1);
+        assert_eq!(count(&pool, "SELECT COUNT(*) FROM api_key_game").await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_purge_requires_one_cutoff() {
+        let app = create_test_app().await;
+
+        let (status, _) = purge(&app, "").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let (status, _) = purge(&app, "?older_than_days=1&before=2024-01-01T00:00:00Z").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let (status, _) = purge(&app, "?older_than_days=-1").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let response = app
+            .clone()
+            .oneshot(request_without_api_key("POST", "/purge?older_than_days=1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+}
+
+#[cfg(test)]
+mod player_data_tests {
+    use super::*;
+
+    /// Sets up two games with scores for `alice`, one deleted, and a closed season she placed in.
+    async fn setup(app: &Router) -> (String, String) {
+        let first = create_test_game(app, "First").await;
+        let second = create_test_game(app, "Second").await;
+
+        let now = chrono::Utc::now();
+        let season = json!({
+            "name": "Season 1",
+            "starts_at": (now - chrono::Duration::hours(1)).to_rfc3339(),
+            "ends_at": (now + chrono::Duration::hours(1)).to_rfc3339(),
+        });
+        let (status, season) = send(app, "POST", &format!("/games/{first}/seasons"), Some(&season.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let score = json!({
+            "game_hex_id": first,
+            "score": "100",
+            "user_name": "Alice",
+            "user_id": "alice",
+            "extra": { "email": "alice@example.com" }
+        });
+        let (status, _) = send(app, "POST", "/scores", Some(&score.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let deleted = create_test_score(app, &second, "alice", 5.0).await;
+        send(app, "DELETE", &format!("/scores/{}", deleted["id"]), None).await;
+        create_test_score(app, &first, "bob", 50.0).await;
+
+        let (status, _) = send(app, "POST", &format!("/games/{first}/seasons/{}/close", season["id"]), None).await;
+        assert_eq!(status, StatusCode::OK);
+        (first, second)
+    }
+
+    async fn audit_actions(pool: &db::DbPool, target: &str) -> Vec<(String, String)> {
+        sqlx::query_as("SELECT action, details FROM audit_log WHERE target = ?1 ORDER BY id")
+            .bind(target)
+            .fetch_all(pool)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_player_data() {
+        let (app, pool) = create_test_app_with_pool().await;
+        setup(&app).await;
+
+        let (status, data) = send(&app, "GET", "/players/alice/data", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(data["user_id"], "alice");
+        let scores = data["scores"].as_array().unwrap();
+        assert_eq!(scores.len(), 2);
+        assert!(scores.iter().all(|score| score["user_id"] == "alice"));
+        assert_eq!(scores.iter().filter(|score| !score["deleted_at"].is_null()).count(), 1);
+        assert_eq!(data["season_standings"].as_array().unwrap().len(), 1);
+
+        let audit = audit_actions(&pool, "alice").await;
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].0, "player.data_export");
+
+        let (status, _) = send(&app, "GET", "/players/nobody/data", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_anonymize_player() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let (first, _) = setup(&app).await;
+
+        let (status, erasure) = send(&app, "DELETE", "/players/alice", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(erasure["mode"], "anonymize");
+        assert_eq!(erasure["scores"], 2);
+        assert_eq!(erasure["season_standings"], 1);
+
+        // Her entry stays on the board with its value, detached from her
+        let (_, page) = send(&app, "GET", &format!("/scores?game_hex_id={first}"), None).await;
+        let top = &page["data"][0];
+        assert_eq!(top["score_val"], 100.0);
+        assert_eq!(top["user_name"], "Anonymous player");
+        assert_ne!(top["user_id"], "alice");
+        assert!(top["extra"].is_null());
+
+        let (status, _) = send(&app, "GET", "/players/alice/data", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let audit = audit_actions(&pool, "alice").await;
+        assert_eq!(audit.len(), 1);
+        assert_eq!(audit[0].0, "player.erasure");
+        let details: serde_json::Value = serde_json::from_str(&audit[0].1).unwrap();
+        assert_eq!(details["mode"], "anonymize");
+    }
+
+    #[tokio::test]
+    async fn test_delete_player() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let (first, _) = setup(&app).await;
+
+        let (status, erasure) = send(&app, "DELETE", "/players/alice?mode=delete", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(erasure["scores"], 2);
+
+        let remaining: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM score WHERE user_id = 'alice'")
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+        assert_eq!(remaining, 0);
+        let (_, page) = send(&app, "GET", &format!("/scores?game_hex_id={first}"), None).await;
+        assert_eq!(page["data"].as_array().unwrap().len(), 1);
+
+        let (status, _) = send(&app, "DELETE", "/players/alice?mode=delete", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "DELETE", "/players/bob?mode=shred", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_player_endpoints_need_admin_scope() {
+        let app = create_test_app().await;
+        let (_, created) = send(&app, "POST", "/api-keys", Some(&json!({ "name": "reader", "scope": "read" }).to_string())).await;
+        let key = created["key"].as_str().unwrap();
+
+        for (method, uri) in [("GET", "/players/alice/data"), ("DELETE", "/players/alice")] {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("leadr-api-key", key)
+                .body(Body::empty())
+                .unwrap();
+            let response = app.clone().oneshot(request).await.unwrap();
+            assert_eq!(response.status(), StatusCode::FORBIDDEN, "{method} {uri}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod player_profile_tests {
+    use super::*;
+
+    async fn submit(app: &Router, hex_id: &str, user_id: &str, user_name: &str, score: &str) -> serde_json::Value {
+        let body = json!({
+            "game_hex_id": hex_id,
+            "score": score,
+            "user_name": user_name,
+            "user_id": user_id,
+        });
+        let (status, score) = send(app, "POST", "/scores", Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        score
+    }
+
+    #[tokio::test]
+    async fn test_first_submission_creates_profile() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Profiles").await;
+
+        submit(&app, &hex_id, "alice", "Alice", "10").await;
+        let (status, player) = send(&app, "GET", "/players/alice", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(player["user_id"], "alice");
+        assert_eq!(player["user_name"], "Alice");
+        assert!(player["avatar_url"].is_null());
+
+        // Later submissions keep the profile's name
+        let score = submit(&app, &hex_id, "alice", "Imposter", "20").await;
+        assert_eq!(score["user_name"], "Alice");
+        let (_, player) = send(&app, "GET", "/players/alice", None).await;
+        assert_eq!(player["user_name"], "Alice");
+
+        let (status, _) = send(&app, "GET", "/players/nobody", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_rename_shows_on_every_score() {
+        let app = create_test_app().await;
+        let first = create_test_game(&app, "First").await;
+        let second = create_test_game(&app, "Second").await;
+
+        let now = chrono::Utc::now();
+        let season = json!({
+            "name": "Season 1",
+            "starts_at": (now - chrono::Duration::hours(1)).to_rfc3339(),
+            "ends_at": (now + chrono::Duration::hours(1)).to_rfc3339(),
+        });
+        let (_, season) = send(&app, "POST", &format!("/games/{first}/seasons"), Some(&season.to_string())).await;
+
+        submit(&app, &first, "alice", "Alice", "10").await;
+        submit(&app, &second, "alice", "Alice", "20").await;
+        submit(&app, &first, "bob", "Bob", "30").await;
+        let (status, _) = send(&app, "POST", &format!("/games/{first}/seasons/{}/close", season["id"]), None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let update = json!({
+            "user_name": "Alice Liddell",
+            "avatar_url": "https://example.com/alice.png",
+            "metadata": { "country": "GB" }
+        });
+        let (status, player) = send(&app, "PUT", "/players/alice", Some(&update.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(player["user_name"], "Alice Liddell");
+        assert_eq!(player["avatar_url"], "https://example.com/alice.png");
+        assert_eq!(player["metadata"]["country"], "GB");
+
+        for hex_id in [&first, &second] {
+            let (_, scores) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}"), None).await;
+            for score in scores["data"].as_array().unwrap() {
+                let expected = if score["user_id"] == "alice" { "Alice Liddell" } else { "Bob" };
+                assert_eq!(score["user_name"], expected);
+            }
+        }
+
+        // Standings archived before the rename keep the old name
+        let (_, standings) = send(&app, "GET", &format!("/games/{first}/seasons/{}/standings", season["id"]), None).await;
+        let alice = standings["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|s| s["user_id"] == "alice")
+            .unwrap();
+        assert_eq!(alice["user_name"], "Alice");
+
+        // Omitted fields are left alone
+        let (_, player) = send(&app, "PUT", "/players/alice", Some(&json!({}).to_string())).await;
+        assert_eq!(player["user_name"], "Alice Liddell");
+        assert_eq!(player["avatar_url"], "https://example.com/alice.png");
+    }
+
+    #[tokio::test]
+    async fn test_update_player_validation() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Profiles").await;
+        submit(&app, &hex_id, "alice", "Alice", "10").await;
+
+        let update = json!({ "avatar_url": "ftp://example.com/alice.png" });
+        let (status, _) = send(&app, "PUT", "/players/alice", Some(&update.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let update = json!({ "user_name": "" });
+        let (status, _) = send(&app, "PUT", "/players/alice", Some(&update.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let update = json!({ "user_name": "Ghost" });
+        let (status, _) = send(&app, "PUT", "/players/nobody", Some(&update.to_string())).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_update_score_moves_player_but_does_not_rename() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Profiles").await;
+        let score = submit(&app, &hex_id, "alice", "Alice", "10").await;
+        submit(&app, &hex_id, "alice", "Alice", "5").await;
+
+        // Renames go through the player, not through one of their scores
+        let update = json!({ "user_name": "Alicia" });
+        let (status, _) = send(&app, "PUT", &format!("/scores/{}", score["id"]), Some(&update.to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let (_, player) = send(&app, "GET", "/players/alice", None).await;
+        assert_eq!(player["user_name"], "Alice");
+
+        // Moving a score to a new user_id creates their profile
+        let update = json!({ "user_id": "carol" });
+        let (status, updated) = send(&app, "PUT", &format!("/scores/{}", score["id"]), Some(&update.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["user_id"], "carol");
+        let (status, carol) = send(&app, "GET", "/players/carol", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(carol["user_name"], updated["user_name"]);
+    }
+
+    #[tokio::test]
+    async fn test_scores_reference_players() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Profiles").await;
+        submit(&app, &hex_id, "alice", "Alice", "10").await;
+
+        let result = sqlx::query(
+            "INSERT INTO score (game_hex_id, score, score_val, user_id) VALUES (?1, '1', 1, 'ghost')",
+        )
+        .bind(&hex_id)
+        .execute(&pool)
+        .await;
+        assert!(result.is_err());
+
+        // Erasure removes the profile along with the scores
+        let (status, erasure) = send(&app, "DELETE", "/players/alice", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(erasure["player_deleted"], true);
+        let (status, _) = send(&app, "GET", "/players/alice", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_read_key_can_get_but_not_update_player() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Profiles").await;
+        submit(&app, &hex_id, "alice", "Alice", "10").await;
+        let (_, created) = send(&app, "POST", "/api-keys", Some(&json!({ "name": "reader", "scope": "read" }).to_string())).await;
+        let key = created["key"].as_str().unwrap();
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/players/alice")
+            .header("leadr-api-key", key)
+            .body(Body::empty())
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/players/alice")
+            .header("leadr-api-key", key)
+            .header("content-type", "application/json")
+            .body(Body::from(json!({ "user_name": "Mallory" }).to_string()))
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+}
+
+#[cfg(test)]
+mod player_stats_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_player_stats_per_game_and_summary() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let arcade = create_test_game(&app, "Arcade").await;
+        let (_, speedrun) = send(
+            &app,
+            "POST",
+            "/games",
+            Some(&json!({ "name": "Speedrun", "score_order": "lower_is_better" }).to_string()),
+        )
+        .await;
+        let speedrun = speedrun["hex_id"].as_str().unwrap().to_string();
+
+        let start = chrono::Utc::now() - chrono::Duration::days(10);
+        let day = |n: i64| start + chrono::Duration::days(n);
+        create_test_score_at(&pool, &arcade, "alice", 100.0, day(0)).await;
+        create_test_score_at(&pool, &arcade, "alice", 300.0, day(1)).await;
+        create_test_score_at(&pool, &arcade, "alice", 200.0, day(2)).await;
+        create_test_score_at(&pool, &arcade, "bob", 500.0, day(2)).await;
+        create_test_score_at(&pool, &speedrun, "alice", 60.0, day(3)).await;
+        create_test_score_at(&pool, &speedrun, "alice", 40.0, day(4)).await;
+        create_test_score_at(&pool, &speedrun, "bob", 50.0, day(4)).await;
+
+        let (status, stats) = send(&app, "GET", "/players/alice/stats", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stats["user_id"], "alice");
+
+        // Most recently played first
+        let games = stats["games"].as_array().unwrap();
+        assert_eq!(games.len(), 2);
+        assert_eq!(games[0]["game_hex_id"], speedrun);
+        assert_eq!(games[0]["game_name"], "Speedrun");
+        assert_eq!(games[0]["submissions"], 2);
+        assert_eq!(games[0]["best_score"]["score_val"], 40.0);
+        assert_eq!(games[0]["latest_score"]["score_val"], 40.0);
+        assert_eq!(games[0]["rank"], 1);
+        assert_eq!(games[0]["average_score_val"], 50.0);
+
+        assert_eq!(games[1]["game_hex_id"], arcade);
+        assert_eq!(games[1]["submissions"], 3);
+        assert_eq!(games[1]["best_score"]["score_val"], 300.0);
+        assert_eq!(games[1]["latest_score"]["score_val"], 200.0);
+        assert_eq!(games[1]["rank"], 2);
+        assert_eq!(games[1]["average_score_val"], 200.0);
+        let first_played: chrono::DateTime<chrono::Utc> =
+            serde_json::from_value(games[1]["first_played_at"].clone()).unwrap();
+        assert_eq!(first_played.timestamp(), day(0).timestamp());
+
+        let summary = &stats["summary"];
+        assert_eq!(summary["games_played"], 2);
+        assert_eq!(summary["submissions"], 5);
+        assert_eq!(summary["average_score_val"], 140.0);
+        assert_eq!(summary["best_rank"], 1);
+        let last_played: chrono::DateTime<chrono::Utc> =
+            serde_json::from_value(summary["last_played_at"].clone()).unwrap();
+        assert_eq!(last_played.timestamp(), day(4).timestamp());
+
+        // Ranks follow the requested policy
+        create_test_score_at(&pool, &arcade, "carol", 500.0, day(0)).await;
+        let (_, stats) = send(&app, "GET", "/players/alice/stats", None).await;
+        assert_eq!(stats["games"][1]["rank"], 3);
+        let (_, stats) = send(&app, "GET", "/players/alice/stats?rank_policy=dense", None).await;
+        assert_eq!(stats["games"][1]["rank"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_player_stats_ignore_deleted_scores() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Arcade").await;
+        let score = create_test_score(&app, &hex_id, "alice", 10.0).await;
+
+        send(&app, "DELETE", &format!("/scores/{}", score["id"]), None).await;
+        let (status, stats) = send(&app, "GET", "/players/alice/stats", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stats["games"], json!([]));
+        assert_eq!(stats["summary"]["submissions"], 0);
+        assert!(stats["summary"]["average_score_val"].is_null());
+
+        let (status, _) = send(&app, "GET", "/players/nobody/stats", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_player_stats_for_restricted_key() {
+        let app = create_test_app().await;
+        let first = create_test_game(&app, "First").await;
+        let second = create_test_game(&app, "Second").await;
+        create_test_score(&app, &first, "alice", 10.0).await;
+        create_test_score(&app, &second, "alice", 20.0).await;
+
+        let (_, created) = send(
+            &app,
+            "POST",
+            "/api-keys",
+            Some(&json!({ "name": "Bound", "scope": "read", "game_hex_ids": [first] }).to_string()),
+        )
+        .await;
+        let request = Request::builder()
+            .method("GET")
+            .uri("/players/alice/stats")
+            .header("leadr-api-key", created["key"].as_str().unwrap())
+            .body(Body::empty())
+            .unwrap();
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stats = response_json(response).await;
+        assert_eq!(stats["games"].as_array().unwrap().len(), 1);
+        assert_eq!(stats["games"][0]["game_hex_id"], first);
+        assert_eq!(stats["summary"]["submissions"], 1);
+    }
+}
+
+#[cfg(test)]
+mod game_stats_tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_game_stats() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Balance").await;
+
+        let start = chrono::Utc::now() - chrono::Duration::days(3);
+        for (i, value) in (1..=10).enumerate() {
+            let user_id = format!("player{}", i % 4);
+            let submitted_at = start + chrono::Duration::days(i64::from(i >= 6));
+            create_test_score_at(&pool, &hex_id, &user_id, f64::from(value) * 10.0, submitted_at).await;
+        }
+        let deleted = create_test_score(&app, &hex_id, "cheater", 9999.0).await;
+        send(&app, "DELETE", &format!("/scores/{}", deleted["id"]), None).await;
+
+        let (status, stats) = send(&app, "GET", &format!("/games/{hex_id}/stats?buckets=3"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stats["game_hex_id"], hex_id);
+        assert_eq!(stats["total_scores"], 10);
+        assert_eq!(stats["unique_players"], 4);
+
+        let score_val = &stats["score_val"];
+        assert_eq!(score_val["min"], 10.0);
+        assert_eq!(score_val["max"], 100.0);
+        assert_eq!(score_val["mean"], 55.0);
+        assert_eq!(score_val["median"], 55.0);
+        assert_eq!(score_val["p50"], 50.0);
+        assert_eq!(score_val["p90"], 90.0);
+        assert_eq!(score_val["p99"], 100.0);
+
+        let days = stats["submissions_per_day"].as_array().unwrap();
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0]["date"], start.date_naive().to_string());
+        assert_eq!(days[0]["submissions"], 6);
+        assert_eq!(days[1]["submissions"], 4);
+
+        // 10..40, 40..70, 70..=100
+        let histogram = stats["histogram"].as_array().unwrap();
+        let counts: Vec<i64> = histogram.iter().map(|b| b["count"].as_i64().unwrap()).collect();
+        assert_eq!(counts, vec![3, 3, 4]);
+        assert_eq!(histogram[0]["lower"], 10.0);
+        assert_eq!(histogram[2]["upper"], 100.0);
+    }
+
+    #[tokio::test]
+    async fn test_game_stats_edge_cases() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Empty").await;
+
+        let (status, stats) = send(&app, "GET", &format!("/games/{hex_id}/stats"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stats["total_scores"], 0);
+        assert!(stats["score_val"].is_null());
+        assert_eq!(stats["histogram"], json!([]));
+
+        create_test_score(&app, &hex_id, "alice", 7.0).await;
+        create_test_score(&app, &hex_id, "bob", 7.0).await;
+        let (_, stats) = send(&app, "GET", &format!("/games/{hex_id}/stats"), None).await;
+        assert_eq!(stats["histogram"], json!([{ "lower": 7.0, "upper": 7.0, "count": 2 }]));
+
+        for buckets in ["0", "101", "many"] {
+            let (status, _) = send(&app, "GET", &format!("/games/{hex_id}/stats?buckets={buckets}"), None).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "buckets={buckets}");
+        }
+        let (status, _) = send(&app, "GET", "/games/abcdef/stats", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_list_games_with_counts() {
+        let app = create_test_app().await;
+        let busy = create_test_game(&app, "Busy").await;
+        let quiet = create_test_game(&app, "Quiet").await;
+        create_test_score(&app, &busy, "alice", 1.0).await;
+        create_test_score(&app, &busy, "alice", 2.0).await;
+        create_test_score(&app, &busy, "bob", 3.0).await;
+
+        let (_, games) = send(&app, "GET", "/games", None).await;
+        assert!(games["data"][0].get("score_count").is_none());
+
+        let (status, games) = send(&app, "GET", "/games?include_counts=true", None).await;
+        assert_eq!(status, StatusCode::OK);
+        for game in games["data"].as_array().unwrap() {
+            let (scores, players) = if game["hex_id"] == busy { (3, 2) } else { (0, 0) };
+            assert_eq!(game["score_count"], scores, "{}", game["hex_id"]);
+            assert_eq!(game["player_count"], players, "{}", game["hex_id"]);
+        }
+        assert!(games["data"].as_array().unwrap().iter().any(|g| g["hex_id"] == quiet));
+    }
+}
+
+#[cfg(test)]
+mod stream_tests {
+    use super::*;
+    use futures_util::SinkExt;
+    use tokio_stream::StreamExt;
+    use tokio_tungstenite::tungstenite::{
+        client::IntoClientRequest,
+        protocol::{frame::coding::CloseCode, CloseFrame},
+        Message,
+    };
+
+    /// Reads SSE frames until `count` events have arrived, returning `(name, data)` pairs.
+    async fn next_events(body: &mut axum::body::BodyDataStream, count: usize) -> Vec<(String, serde_json::Value)> {
+        let mut buffer = String::new();
+        let mut events = Vec::new();
+        while events.len() < count {
+            let chunk = tokio::time::timeout(std::time::Duration::from_secs(5), body.next())
+                .await
+                .expect("timed out waiting for an event")
+                .unwrap()
+                .unwrap();
+            buffer.push_str(std::str::from_utf8(&chunk).unwrap());
+            while let Some(end) = buffer.find("\n\n") {
+                let block: String = buffer.drain(..end + 2).collect();
+                let name = block.lines().find_map(|l| l.strip_prefix("event: ")).map(str::to_string);
+                let data = block.lines().find_map(|l| l.strip_prefix("data: "));
+                if let (Some(name), Some(data)) = (name, data) {
+                    events.push((name, serde_json::from_str(data).unwrap()));
+                }
+            }
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn test_sse_stream_publishes_score_events() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Live").await;
+        let other = create_test_game(&app, "Other").await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/stream"), None))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "text/event-stream");
+        let mut body = response.into_body().into_data_stream();
+
+        // Events for other games are filtered out
+        create_test_score(&app, &other, "bob", 1.0).await;
+        let score = create_test_score(&app, &hex_id, "alice", 100.0).await;
+        let events = next_events(&mut body, 2).await;
+        assert_eq!(events[0].0, "score_created");
+        assert_eq!(events[0].1["type"], "score_created");
+        assert_eq!(events[0].1["score"]["id"], score["id"]);
+        assert_eq!(events[1].0, "top_changed");
+        assert_eq!(events[1].1["game_hex_id"], hex_id);
+        assert_eq!(events[1].1["scores"][0]["id"], score["id"]);
+        assert_eq!(events[1].1["scores"][0]["rank"], 1);
+
+        let update = json!({ "score": "150" });
+        send(&app, "PUT", &format!("/scores/{}", score["id"]), Some(&update.to_string())).await;
+        let events = next_events(&mut body, 2).await;
+        assert_eq!(events[0].0, "score_updated");
+        assert_eq!(events[0].1["score"]["score_val"], 150.0);
+        assert_eq!(events[1].0, "top_changed");
+
+        send(&app, "DELETE", &format!("/scores/{}", score["id"]), None).await;
+        let events = next_events(&mut body, 2).await;
+        assert_eq!(events[0].0, "score_deleted");
+        assert_eq!(events[0].1["score_id"], score["id"]);
+        assert_eq!(events[1].0, "top_changed");
+        assert_eq!(events[1].1["scores"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_top_changed_only_when_top_changes() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Crowded").await;
+        for i in 0..10 {
+            create_test_score(&app, &hex_id, &format!("player{i}"), 100.0 + f64::from(i)).await;
+        }
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/stream"), None))
+            .await
+            .unwrap();
+        let mut body = response.into_body().into_data_stream();
+
+        // Below the top 10, then into it
+        create_test_score(&app, &hex_id, "low", 1.0).await;
+        create_test_score(&app, &hex_id, "high", 500.0).await;
+        let events = next_events(&mut body, 3).await;
+        let names: Vec<&str> = events.iter().map(|(name, _)| name.as_str()).collect();
+        assert_eq!(names, vec!["score_created", "score_created", "top_changed"]);
+        assert_eq!(events[2].1["scores"][0]["user_id"], "high");
+        assert_eq!(events[2].1["scores"].as_array().unwrap().len(), 10);
+    }
+
+    #[tokio::test]
+    async fn test_top_changed_per_board() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        let board = json!({ "slug": "hard", "name": "Hard" });
+        let (_, hard) = send(&app, "POST", &format!("/games/{hex_id}/leaderboards"), Some(&board.to_string())).await;
+        create_test_score(&app, &hex_id, "alice", 500.0).await;
+
+        let response = app
+            .clone()
+            .oneshot(request_with_api_key("GET", &format!("/games/{hex_id}/stream"), None))
+            .await
+            .unwrap();
+        let mut body = response.into_body().into_data_stream();
+
+        // The hard board's top only holds its own entries
+        let score = json!({ "game_hex_id": hex_id, "leaderboard": "hard", "score": "5", "user_name": "Bob", "user_id": "bob" });
+        send(&app, "POST", "/scores", Some(&score.to_string())).await;
+        let events = next_events(&mut body, 2).await;
+        assert_eq!(events[1].0, "top_changed");
+        assert_eq!(events[1].1["leaderboard_id"], hard["id"]);
+        let top = events[1].1["scores"].as_array().unwrap();
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0]["user_id"], "bob");
+        assert_eq!(top[0]["rank"], 1);
+    }
+
+    #[tokio::test]
+    async fn test_stream_requires_existing_game() {
+        let app = create_test_app().await;
+        let (status, _) = send(&app, "GET", "/games/abcdef/stream", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let hex_id = create_test_game(&app, "Live").await;
+        let (status, _) = send(&app, "GET", &format!("/games/{hex_id}/ws"), None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_websocket_stream() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Live").await;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let server = app.clone();
+        tokio::spawn(async move { axum::serve(listener, server).await.unwrap() });
+
+        let mut request = format!("ws://{addr}/games/{hex_id}/ws").into_client_request().unwrap();
+        request.headers_mut().insert("leadr-api-key", "test_api_key_123".parse().unwrap());
+        let (mut socket, response) = tokio_tungstenite::connect_async(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::SWITCHING_PROTOCOLS);
+
+        let score = create_test_score(&app, &hex_id, "alice", 10.0).await;
+        let message = tokio::time::timeout(std::time::Duration::from_secs(5), socket.next())
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let event: serde_json::Value = serde_json::from_str(message.to_text().unwrap()).unwrap();
+        assert_eq!(event["type"], "score_created");
+        assert_eq!(event["score"]["id"], score["id"]);
+        let message = socket.next().await.unwrap().unwrap();
+        let event: serde_json::Value = serde_json::from_str(message.to_text().unwrap()).unwrap();
+        assert_eq!(event["type"], "top_changed");
+
+        // A ping from the client is answered with a pong
+        socket.send(Message::Ping(b"ping".to_vec())).await.unwrap();
+        let message = socket.next().await.unwrap().unwrap();
+        assert_eq!(message, Message::Pong(b"ping".to_vec()));
+
+        // Closing is echoed
+        let close = CloseFrame { code: CloseCode::Normal, reason: "".into() };
+        socket.send(Message::Close(Some(close))).await.unwrap();
+        let message = socket.next().await.unwrap().unwrap();
+        assert!(matches!(message, Message::Close(Some(frame)) if frame.code == CloseCode::Normal));
+    }
+}
+
+#[cfg(test)]
+mod webhook_tests {
+    use super::*;
+    use axum::{extract::State, http::HeaderMap, routing::post};
+    use leadr_api::auth::SubmissionSignature;
+    use leadr_api::webhooks;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    /// A local HTTP server standing in for a webhook target; it records every request and
+    /// answers 500 to the first `failures` of them.
+    #[derive(Clone, Default)]
+    struct StandIn {
+        requests: Arc<Mutex<Vec<(HeaderMap, String)>>>,
+        failures: Arc<AtomicUsize>,
+    }
+
+    async fn receive(State(stand_in): State<StandIn>, headers: HeaderMap, body: String) -> StatusCode {
+        stand_in.requests.lock().unwrap().push((headers, body));
+        let failing = stand_in
+            .failures
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok();
+        if failing {
+            StatusCode::INTERNAL_SERVER_ERROR
+        } else {
+            StatusCode::OK
+        }
+    }
+
+    async fn start_stand_in(failures: usize) -> (String, StandIn) {
+        let stand_in = StandIn::default();
+        stand_in.failures.store(failures, Ordering::SeqCst);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = Router::new().route("/hook", post(receive)).with_state(stand_in.clone());
+        tokio::spawn(async move { axum::serve(listener, server).await.unwrap() });
+        (url, stand_in)
+    }
+
+    #[tokio::test]
+    async fn test_webhook_crud() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Hooked").await;
+
+        let body = json!({
+            "url": "https://example.com/hook",
+            "events": ["score.top_n", "score.created", "score.created"],
+            "game_hex_id": hex_id
+        });
+        let (status, created) = send(&app, "POST", "/webhooks", Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["events"], json!(["score.created", "score.top_n"]));
+        assert_eq!(created["game_hex_id"], hex_id);
+        assert_eq!(created["active"], true);
+        let secret = created["secret"].as_str().unwrap().to_string();
+        assert_eq!(secret.len(), 64);
+        let id = created["id"].as_i64().unwrap();
+
+        // The secret is never listed
+        let (status, webhooks) = send(&app, "GET", "/webhooks", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(webhooks.as_array().unwrap().len(), 1);
+        assert!(webhooks[0].get("secret").is_none());
+        let (status, webhook) = send(&app, "GET", &format!("/webhooks/{id}"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(webhook.get("secret").is_none());
+
+        let body = json!({ "events": ["game.deleted"], "active": false });
+        let (status, updated) = send(&app, "PUT", &format!("/webhooks/{id}"), Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(updated["events"], json!(["game.deleted"]));
+        assert_eq!(updated["active"], false);
+        assert_eq!(updated["url"], "https://example.com/hook");
+
+        let (status, rotated) = send(&app, "POST", &format!("/webhooks/{id}/secret"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rotated["webhook_id"], id);
+        assert_ne!(rotated["secret"], secret);
+
+        let (status, _) = send(&app, "DELETE", &format!("/webhooks/{id}"), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, "GET", &format!("/webhooks/{id}"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "DELETE", &format!("/webhooks/{id}"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_validation() {
+        let app = create_test_app().await;
+
+        for body in [
+            json!({ "url": "ftp://example.com/hook", "events": ["score.created"] }),
+            json!({ "url": "not a url", "events": ["score.created"] }),
+            json!({ "url": "https://example.com/hook", "events": [] }),
+            json!({ "url": "https://example.com/hook", "events": ["score.exploded"] }),
+            json!({ "url": "https://example.com/hook", "events": ["score.created"], "game_hex_id": "abc123" }),
+        ] {
+            let (status, _) = send(&app, "POST", "/webhooks", Some(&body.to_string())).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "{body}");
+        }
+
+        // Keys restricted to some games cannot see every game's events through a webhook
+        let hex_id = create_test_game(&app, "Restricted").await;
+        let body = json!({ "name": "Game admin", "scope": "admin", "game_hex_ids": [hex_id] });
+        let (_, key) = send(&app, "POST", "/api-keys", Some(&body.to_string())).await;
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/webhooks")
+                    .header("leadr-api-key", key["key"].as_str().unwrap())
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_webhook_delivers_signed_events() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Hooked").await;
+        let other = create_test_game(&app, "Other").await;
+        let (url, stand_in) = start_stand_in(0).await;
+
+        let body = json!({ "url": url, "events": ["score.created", "score.top_n"], "game_hex_id": hex_id });
+        let (_, created) = send(&app, "POST", "/webhooks", Some(&body.to_string())).await;
+        let secret = created["secret"].as_str().unwrap().to_string();
+        let id = created["id"].as_i64().unwrap();
+
+        // Only the filtered game's events are queued
+        create_test_score(&app, &other, "bob", 1.0).await;
+        let score = create_test_score(&app, &hex_id, "alice", 100.0).await;
+
+        let client = webhooks::client();
+        assert_eq!(webhooks::deliver_due(&pool, &client).await.unwrap(), 2);
+        assert_eq!(webhooks::deliver_due(&pool, &client).await.unwrap(), 0);
+
+        let requests = stand_in.requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 2);
+        let (headers, body) = &requests[0];
+        assert_eq!(headers["leadr-event"], "score.created");
+        assert_eq!(headers["content-type"], "application/json");
+        let timestamp: i64 = headers["leadr-timestamp"].to_str().unwrap().parse().unwrap();
+        let delivery_id = headers["leadr-delivery"].to_str().unwrap();
+        let expected = SubmissionSignature::sign(&secret, timestamp, delivery_id, body);
+        assert_eq!(headers["leadr-signature"], expected.as_str());
+
+        let payload: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(payload["event"], "score.created");
+        assert_eq!(payload["delivery_id"].to_string(), delivery_id);
+        assert_eq!(payload["game_hex_id"], hex_id);
+        assert_eq!(payload["data"]["score"]["id"], score["id"]);
+        let payload: serde_json::Value = serde_json::from_str(&requests[1].1).unwrap();
+        assert_eq!(payload["event"], "score.top_n");
+        assert_eq!(payload["data"]["scores"][0]["id"], score["id"]);
+
+        let (status, deliveries) = send(&app, "GET", &format!("/webhooks/{id}/deliveries"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let deliveries = deliveries.as_array().unwrap();
+        assert_eq!(deliveries.len(), 2);
+        assert_eq!(deliveries[0]["event"], "score.top_n");
+        for delivery in deliveries {
+            assert_eq!(delivery["status"], "succeeded");
+            assert_eq!(delivery["attempts"], 1);
+            assert_eq!(delivery["response_status"], 200);
+            assert!(delivery["next_attempt_at"].is_null());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_webhook_retries_failed_deliveries_with_backoff() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Doomed").await;
+        let (url, stand_in) = start_stand_in(1).await;
+
+        let body = json!({ "url": url, "events": ["game.deleted"] });
+        let (_, created) = send(&app, "POST", "/webhooks", Some(&body.to_string())).await;
+        let id = created["id"].as_i64().unwrap();
+
+        let (status, _) = send(&app, "DELETE", &format!("/games/{hex_id}"), None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+
+        let client = webhooks::client();
+        assert_eq!(webhooks::deliver_due(&pool, &client).await.unwrap(), 1);
+        let (_, deliveries) = send(&app, "GET", &format!("/webhooks/{id}/deliveries"), None).await;
+        let delivery = &deliveries[0];
+        assert_eq!(delivery["event"], "game.deleted");
+        assert_eq!(delivery["payload"]["game_hex_id"], hex_id);
+        assert_eq!(delivery["status"], "pending");
+        assert_eq!(delivery["attempts"], 1);
+        assert_eq!(delivery["response_status"], 500);
+        assert!(delivery["last_error"].as_str().unwrap().contains("500"));
+        let next_attempt_at: chrono::DateTime<chrono::Utc> =
+            serde_json::from_value(delivery["next_attempt_at"].clone()).unwrap();
+        let last_attempt_at: chrono::DateTime<chrono::Utc> =
+            serde_json::from_value(delivery["last_attempt_at"].clone()).unwrap();
+        assert_eq!((next_attempt_at - last_attempt_at).num_seconds(), 30);
+
+        // Nothing is retried before the backoff has elapsed
+        assert_eq!(webhooks::deliver_due(&pool, &client).await.unwrap(), 0);
+
+        sqlx::query("UPDATE webhook_delivery SET next_attempt_at = '2000-01-01 00:00:00'")
+            .execute(&pool)
+            .await
+            .unwrap();
+        assert_eq!(webhooks::deliver_due(&pool, &client).await.unwrap(), 1);
+
+        let requests = stand_in.requests.lock().unwrap().clone();
+        assert_eq!(requests.len(), 2);
+        // Retries reuse the delivery id
+        assert_eq!(requests[0].0["leadr-delivery"], requests[1].0["leadr-delivery"]);
+
+        let (_, deliveries) =
+            send(&app, "GET", &format!("/webhooks/{id}/deliveries?status=succeeded"), None).await;
+        assert_eq!(deliveries.as_array().unwrap().len(), 1);
+        assert_eq!(deliveries[0]["attempts"], 2);
+        assert_eq!(deliveries[0]["response_status"], 200);
+        assert!(deliveries[0]["last_error"].is_null());
+        let (_, deliveries) = send(&app, "GET", &format!("/webhooks/{id}/deliveries?status=pending"), None).await;
+        assert!(deliveries.as_array().unwrap().is_empty());
+        let (status, _) = send(&app, "GET", &format!("/webhooks/{id}/deliveries?status=lost"), None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
+
+#[cfg(test)]
+mod batch_tests {
+    use super::*;
+    use leadr_api::auth::SubmissionSignature;
+    use leadr_api::models::CreateScore;
+
+    fn item(hex_id: &str, user_id: &str, score_val: f64) -> serde_json::Value {
+        json!({
+            "game_hex_id": hex_id,
+            "score": score_val.to_string(),
+            "score_val": score_val,
+            "user_name": format!("Player {user_id}"),
+            "user_id": user_id
+        })
+    }
+
+    async fn submit_batch(app: &Router, body: serde_json::Value) -> (StatusCode, serde_json::Value) {
+        send(app, "POST", "/scores/batch", Some(&body.to_string())).await
+    }
+
+    #[tokio::test]
+    async fn test_batch_saves_every_score() {
+        let app = create_test_app().await;
+        let first = create_test_game(&app, "First").await;
+        let second = create_test_game(&app, "Second").await;
+
+        let (status, body) = submit_batch(
+            &app,
+            json!({ "scores": [item(&first, "a", 10.0), item(&first, "b", 20.0), item(&second, "a", 30.0)] }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["mode"], "all_or_nothing");
+        assert_eq!(body["committed"], true);
+        assert_eq!(body["created"], 3);
+        assert_eq!(body["failed"], 0);
+
+        let results = body["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        for (index, result) in results.iter().enumerate() {
+            assert_eq!(result["index"], index);
+            assert_eq!(result["status"], 201);
+            assert_eq!(result["outcome"], "created");
+            assert!(result["score"]["id"].is_i64());
+        }
+        assert_eq!(results[2]["score"]["game_hex_id"], second);
+
+        assert_eq!(count_scores(&app, &first).await, 2);
+        assert_eq!(count_scores(&app, &second).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_all_or_nothing_batch_rolls_back_on_failure() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Atomic").await;
+
+        let (status, body) = submit_batch(
+            &app,
+            json!({ "scores": [item(&hex_id, "a", 10.0), item("ZZZZZZ", "b", 20.0), item(&hex_id, "", 30.0)] }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["committed"], false);
+        assert_eq!(body["created"], 1);
+        assert_eq!(body["failed"], 2);
+
+        let results = body["results"].as_array().unwrap();
+        assert_eq!(results[0]["status"], 201);
+        assert!(results[0]["score"].is_null());
+        assert_eq!(results[1]["status"], 404);
+        assert_eq!(results[2]["status"], 422);
+        assert!(results[2]["error"].is_string());
+
+        assert_eq!(count_scores(&app, &hex_id).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_partial_batch_saves_successful_scores() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Partial").await;
+
+        let (status, body) = submit_batch(
+            &app,
+            json!({
+                "mode": "partial",
+                "scores": [item(&hex_id, "a", 10.0), item(&hex_id, "", 20.0), item(&hex_id, "c", 30.0)]
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["mode"], "partial");
+        assert_eq!(body["committed"], true);
+        assert_eq!(body["created"], 2);
+        assert_eq!(body["failed"], 1);
+        assert_eq!(body["results"][1]["status"], 422);
+        assert!(body["results"][2]["score"]["id"].is_i64());
+
+        assert_eq!(count_scores(&app, &hex_id).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_size_is_limited() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Limits").await;
+
+        let (status, _) = submit_batch(&app, json!({ "scores": [] })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let scores: Vec<_> = (0..=leadr_api::models::MAX_BATCH_SIZE)
+            .map(|i| item(&hex_id, &format!("p{i}"), i as f64))
+            .collect();
+        let (status, body) = submit_batch(&app, json!({ "scores": scores })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body["results"].is_null());
+        assert_eq!(count_scores(&app, &hex_id).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_batch_keeps_submitted_at() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Offline").await;
+
+        let played_at = chrono::Utc::now() - chrono::Duration::days(2);
+        let mut backdated = item(&hex_id, "a", 10.0);
+        backdated["submitted_at"] = json!(played_at.to_rfc3339());
+
+        let (status, body) = submit_batch(&app, json!({ "scores": [backdated] })).await;
+        assert_eq!(status, StatusCode::OK);
+        let saved = chrono::DateTime::parse_from_rfc3339(body["results"][0]["score"]["submitted_at"].as_str().unwrap())
+            .unwrap();
+        assert_eq!(saved.timestamp(), played_at.timestamp());
+
+        let mut future = item(&hex_id, "b", 20.0);
+        future["submitted_at"] = json!((chrono::Utc::now() + chrono::Duration::hours(1)).to_rfc3339());
+        let (status, body) = submit_batch(&app, json!({ "scores": [future] })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["results"][0]["status"], 422);
+    }
+
+    #[tokio::test]
+    async fn test_keep_latest_ignores_older_batch_scores() {
+        let app = create_test_app().await;
+        let (status, game) = send(
+            &app,
+            "POST",
+            "/games",
+            Some(&json!({ "name": "Latest", "submission_policy": "keep_latest" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let hex_id = game["hex_id"].as_str().unwrap();
+
+        let current = create_test_score(&app, hex_id, "a", 50.0).await;
+
+        let mut older = item(hex_id, "a", 80.0);
+        older["submitted_at"] = json!((chrono::Utc::now() - chrono::Duration::hours(1)).to_rfc3339());
+        let (status, body) = submit_batch(&app, json!({ "scores": [older] })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["unchanged"], 1);
+        assert_eq!(body["results"][0]["status"], 200);
+        assert_eq!(body["results"][0]["score"]["id"], current["id"]);
+        assert_eq!(body["results"][0]["score"]["score_val"], 50.0);
+
+        let (_, body) = submit_batch(&app, json!({ "scores": [item(hex_id, "a", 20.0)] })).await;
+        assert_eq!(body["replaced"], 1);
+        assert_eq!(body["results"][0]["score"]["score_val"], 20.0);
+    }
+
+    #[tokio::test]
+    async fn test_signed_game_requires_item_signatures() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Signed").await;
+        let (status, body) = send(&app, "POST", &format!("/games/{hex_id}/signing-secret"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let secret = body["signing_secret"].as_str().unwrap().to_string();
+
+        let (status, body) = submit_batch(&app, json!({ "scores": [item(&hex_id, "a", 10.0)] })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(body["results"][0]["status"], 401);
+
+        let mut signed = item(&hex_id, "a", 10.0);
+        let create_data: CreateScore = serde_json::from_value(signed.clone()).unwrap();
+        let timestamp = chrono::Utc::now().timestamp();
+        let signature = SubmissionSignature::sign(&secret, timestamp, "batch-nonce", &create_data.canonical_payload());
+        signed["signature"] = json!({ "timestamp": timestamp, "nonce": "batch-nonce", "signature": signature });
+
+        let (status, body) = submit_batch(&app, json!({ "scores": [signed.clone()] })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["created"], 1);
+
+        // The nonce cannot be replayed, within a batch or across batches
+        let (status, body) = submit_batch(&app, json!({ "mode": "partial", "scores": [signed] })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["failed"], 1);
+        assert_eq!(body["results"][0]["status"], 401);
+        assert_eq!(count_scores(&app, &hex_id).await, 1);
+    }
+}
+
+#[cfg(test)]
+mod idempotency_tests {
+    use super::*;
+
+    fn score_body(hex_id: &str, user_id: &str, score_val: f64) -> serde_json::Value {
+        json!({
+            "game_hex_id": hex_id,
+            "score": score_val.to_string(),
+            "score_val": score_val,
+            "user_name": format!("Player {user_id}"),
+            "user_id": user_id
+        })
+    }
+
+    async fn post_with_key(
+        app: &Router,
+        uri: &str,
+        idempotency_key: &str,
+        body: &serde_json::Value,
+    ) -> (StatusCode, bool, serde_json::Value) {
+        let request = Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("leadr-api-key", "test_api_key_123")
+            .header("content-type", "application/json")
+            .header("idempotency-key", idempotency_key)
+            .body(Body::from(body.to_string()))
+            .unwrap();
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let replayed = response.headers().contains_key("idempotent-replayed");
+        (status, replayed, response_json(response).await)
+    }
+
+    #[tokio::test]
+    async fn test_retry_replays_original_response() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Retries").await;
+        let body = score_body(&hex_id, "player", 100.0);
+
+        let (status, replayed, first) = post_with_key(&app, "/scores", "retry-1", &body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(!replayed);
+
+        let (status, replayed, retry) = post_with_key(&app, "/scores", "retry-1", &body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(replayed);
+        assert_eq!(retry, first);
+
+        assert_eq!(count_scores(&app, &hex_id).await, 1);
+
+        // Without a key, or with another one, the score is submitted again
+        create_test_score(&app, &hex_id, "player", 100.0).await;
+        let (status, replayed, _) = post_with_key(&app, "/scores", "retry-2", &body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(!replayed);
+        assert_eq!(count_scores(&app, &hex_id).await, 3);
+    }
+
+    #[tokio::test]
+    async fn test_reused_key_with_different_request_conflicts() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Conflicts").await;
+
+        let (status, _, _) = post_with_key(&app, "/scores", "shared", &score_body(&hex_id, "a", 10.0)).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (status, _, body) = post_with_key(&app, "/scores", "shared", &score_body(&hex_id, "a", 20.0)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert!(body["error"].as_str().unwrap().contains("different request"));
+        assert_eq!(count_scores(&app, &hex_id).await, 1);
+    }
+
+    #[tokio::test]
+    async fn test_failed_requests_are_not_remembered() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Failures").await;
+
+        let (status, _, _) = post_with_key(&app, "/scores", "fix-me", &score_body(&hex_id, "", 10.0)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, replayed, _) = post_with_key(&app, "/scores", "fix-me", &score_body(&hex_id, "a", 10.0)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(!replayed);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_key_rejected() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Invalid").await;
+
+        let (status, _, _) = post_with_key(&app, "/scores", &"k".repeat(256), &score_body(&hex_id, "a", 10.0)).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(count_scores(&app, &hex_id).await, 0);
+    }
+
+    #[tokio::test]
+    async fn test_expired_key_runs_again() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let hex_id = create_test_game(&app, "Expiry").await;
+        let body = score_body(&hex_id, "player", 100.0);
+
+        post_with_key(&app, "/scores", "short-lived", &body).await;
+        sqlx::query("UPDATE idempotency_key SET expires_at = ?1")
+            .bind((chrono::Utc::now() - chrono::Duration::seconds(1)).naive_utc())
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (status, replayed, _) = post_with_key(&app, "/scores", "short-lived", &body).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(!replayed);
+        assert_eq!(count_scores(&app, &hex_id).await, 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_retry_replays_original_response() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Batch Retries").await;
+        let body = json!({ "scores": [score_body(&hex_id, "a", 10.0), score_body(&hex_id, "b", 20.0)] });
+
+        let (status, _, first) = post_with_key(&app, "/scores/batch", "batch-1", &body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(first["created"], 2);
+
+        let (status, replayed, retry) = post_with_key(&app, "/scores/batch", "batch-1", &body).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(replayed);
+        assert_eq!(retry, first);
+        assert_eq!(count_scores(&app, &hex_id).await, 2);
+
+        // Keys are scoped to the endpoint
+        let (status, replayed, _) = post_with_key(&app, "/scores", "batch-1", &score_body(&hex_id, "c", 30.0)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert!(!replayed);
+    }
+}
+
+#[cfg(test)]
+mod game_slug_tests {
+    use super::*;
+
+    async fn create_slugged_game(app: &Router, slug: &str) -> String {
+        let body = json!({ "name": "Space Invaders (Hard)", "slug": slug });
+        let (status, game) = send(app, "POST", "/games", Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(game["slug"], slug);
+        game["hex_id"].as_str().unwrap().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_slug_resolves_on_game_routes() {
+        let app = create_test_app().await;
+        let hex_id = create_slugged_game(&app, "space-invaders-hard").await;
+        create_test_score(&app, &hex_id, "player", 100.0).await;
+
+        let (status, game) = send(&app, "GET", "/games/space-invaders-hard", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["hex_id"], hex_id);
+
+        let (status, stats) = send(&app, "GET", "/games/space-invaders-hard/stats", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stats["total_scores"], 1);
+
+        let (status, rank) = send(&app, "GET", "/games/space-invaders-hard/ranks/player", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rank["rank"], 1);
+
+        let (status, game) = send(
+            &app,
+            "PUT",
+            "/games/space-invaders-hard",
+            Some(&json!({ "description": "Faster aliens" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["description"], "Faster aliens");
+
+        let (status, _) = send(&app, "DELETE", "/games/space-invaders-hard", None).await;
+        assert_eq!(status, StatusCode::NO_CONTENT);
+        let (status, _) = send(&app, "GET", "/games/space-invaders-hard", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, game) = send(&app, "POST", "/games/space-invaders-hard/restore", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["hex_id"], hex_id);
+
+        // The hex_id keeps working
+        let (status, game) = send(&app, "GET", &format!("/games/{hex_id}"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["slug"], "space-invaders-hard");
+    }
+
+    #[tokio::test]
+    async fn test_slug_resolves_on_score_routes() {
+        let app = create_test_app().await;
+        let hex_id = create_slugged_game(&app, "pinball").await;
+
+        let score = json!({ "game_hex_id": "pinball", "score": "100", "user_name": "Player", "user_id": "player" });
+        let (status, created) = send(&app, "POST", "/scores", Some(&score.to_string())).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(created["game_hex_id"], hex_id);
+
+        let batch = json!({ "scores": [
+            { "game_hex_id": "pinball", "score": "200", "user_name": "Other", "user_id": "other" }
+        ] });
+        let (status, batch) = send(&app, "POST", "/scores/batch", Some(&batch.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(batch["results"][0]["score"]["game_hex_id"], hex_id);
+
+        let (status, page) = send(&app, "GET", "/scores?game_hex_id=pinball&leaderboard=default", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(page["data"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_slug_must_be_unique() {
+        let app = create_test_app().await;
+        let hex_id = create_slugged_game(&app, "tetris").await;
+
+        let body = json!({ "name": "Another Tetris", "slug": "tetris" });
+        let (status, _) = send(&app, "POST", "/games", Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        // A slug cannot shadow another game's hex_id either
+        let other = create_test_game(&app, "Other").await;
+        let body = json!({ "name": "Shadow", "slug": other });
+        let (status, _) = send(&app, "POST", "/games", Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        let (status, _) = send(
+            &app,
+            "PUT",
+            &format!("/games/{other}"),
+            Some(&json!({ "slug": "tetris" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CONFLICT);
+
+        // A game can keep its own slug
+        let (status, _) = send(
+            &app,
+            "PUT",
+            &format!("/games/{hex_id}"),
+            Some(&json!({ "slug": "tetris", "name": "Tetris" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_invalid_slug_rejected() {
+        let app = create_test_app().await;
+
+        for slug in ["No Spaces", "UPPER", "x", "-leading"] {
+            let body = json!({ "name": "Bad Slug", "slug": slug });
+            let (status, _) = send(&app, "POST", "/games", Some(&body.to_string())).await;
+            assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY, "slug {slug:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_slug_can_be_changed_and_removed() {
+        let app = create_test_app().await;
+        let hex_id = create_slugged_game(&app, "old-name").await;
+
+        let (status, game) = send(
+            &app,
+            "PUT",
+            "/games/old-name",
+            Some(&json!({ "slug": "new-name" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["slug"], "new-name");
+
+        let (status, _) = send(&app, "GET", "/games/old-name", None).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        let (status, _) = send(&app, "GET", "/games/new-name", None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, game) = send(&app, "PUT", "/games/new-name", Some(&json!({ "slug": "" }).to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(game["slug"].is_null());
+        let (status, _) = send(&app, "GET", &format!("/games/{hex_id}"), None).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_longer_hex_ids_accepted() {
+        let (app, pool) = create_test_app_with_pool().await;
+        let create_data: leadr_api::models::CreateGame =
+            serde_json::from_value(json!({ "name": "Long Id" })).unwrap();
+        leadr_api::db::repository::GameRepository::create_with_hex_id(
+            &pool,
+            create_data,
+            "0123456789abcdef".to_string(),
+            chrono::Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        let (status, game) = send(&app, "GET", "/games/0123456789abcdef", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(game["name"], "Long Id");
+        create_test_score(&app, "0123456789abcdef", "player", 10.0).await;
+    }
+
+    #[tokio::test]
+    async fn test_custom_hex_id_cannot_shadow_slug() {
+        let (app, pool) = create_test_app_with_pool().await;
+        create_slugged_game(&app, "asteroids").await;
+
+        let create_data: leadr_api::models::CreateGame =
+            serde_json::from_value(json!({ "name": "Shadow" })).unwrap();
+        let result = leadr_api::db::repository::GameRepository::create_with_hex_id(
+            &pool,
+            create_data,
+            "asteroids".to_string(),
+            chrono::Utc::now(),
+        )
+        .await;
+        assert!(matches!(result, Err(leadr_api::error::ApiError::Conflict(_))));
+    }
+}
+
+#[cfg(test)]
+mod leaderboard_tests {
+    use super::*;
+
+    async fn create_board(app: &Router, hex_id: &str, slug: &str) -> serde_json::Value {
+        let body = json!({ "slug": slug, "name": format!("Board {slug}") });
+        let (status, board) = send(
+            app,
+            "POST",
+            &format!("/games/{hex_id}/leaderboards"),
+            Some(&body.to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        board
+    }
+
+    async fn submit(app: &Router, hex_id: &str, board: Option<&str>, user_id: &str, score_val: f64) -> (StatusCode, serde_json::Value) {
+        let mut body = json!({
+            "game_hex_id": hex_id,
+            "score": score_val.to_string(),
+            "user_name": format!("Player {user_id}"),
+            "user_id": user_id
+        });
+        if let Some(board) = board {
+            body["leaderboard"] = json!(board);
+        }
+        send(app, "POST", "/scores", Some(&body.to_string())).await
+    }
+
+    #[tokio::test]
+    async fn test_games_start_with_a_default_board() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        create_test_score(&app, &hex_id, "player", 10.0).await;
+
+        let (status, boards) = send(&app, "GET", &format!("/games/{hex_id}/leaderboards"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let boards = boards.as_array().unwrap();
+        assert_eq!(boards.len(), 1);
+        assert_eq!(boards[0]["slug"], "default");
+        assert_eq!(boards[0]["game_hex_id"], hex_id);
+        assert_eq!(boards[0]["score_count"], 1);
+
+        let board = create_board(&app, &hex_id, "level-1").await;
+        assert_eq!(board["slug"], "level-1");
+        assert_eq!(board["name"], "Board level-1");
+        assert_eq!(board["score_count"], 0);
+
+        let (_, boards) = send(&app, "GET", &format!("/games/{hex_id}/leaderboards"), None).await;
+        let slugs: Vec<&str> = boards
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|board| board["slug"].as_str().unwrap())
+            .collect();
+        assert_eq!(slugs, ["default", "level-1"]);
+    }
+
+    #[tokio::test]
+    async fn test_create_board_errors() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        create_board(&app, &hex_id, "easy").await;
+
+        let uri = format!("/games/{hex_id}/leaderboards");
+        let (status, _) = send(&app, "POST", &uri, Some(&json!({ "slug": "easy", "name": "Again" }).to_string())).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = send(&app, "POST", &uri, Some(&json!({ "slug": "default", "name": "Again" }).to_string())).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = send(&app, "POST", &uri, Some(&json!({ "slug": "Very Hard", "name": "Hard" }).to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        let (status, _) = send(&app, "POST", &uri, Some(&json!({ "slug": "hard", "name": " " }).to_string())).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, _) = send(
+            &app,
+            "POST",
+            "/games/zzz999/leaderboards",
+            Some(&json!({ "slug": "hard", "name": "Hard" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_scores_attach_to_the_named_board() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        let easy = create_board(&app, &hex_id, "easy").await;
+
+        let (status, default_score) = submit(&app, &hex_id, None, "alice", 10.0).await;
+        assert_eq!(status, StatusCode::CREATED);
+        let (status, easy_score) = submit(&app, &hex_id, Some("easy"), "bob", 50.0).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(easy_score["leaderboard_id"], easy["id"]);
+        assert_ne!(default_score["leaderboard_id"], easy["id"]);
+
+        let (status, _) = submit(&app, &hex_id, Some("nightmare"), "carol", 1.0).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, score) = send(&app, "GET", &format!("/scores/{}", easy_score["id"]), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(score["leaderboard_id"], easy["id"]);
+    }
+
+    #[tokio::test]
+    async fn test_global_listing_ranks_each_board_separately() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        create_board(&app, &hex_id, "hard").await;
+        submit(&app, &hex_id, None, "alice", 10.0).await;
+        submit(&app, &hex_id, None, "bob", 30.0).await;
+        submit(&app, &hex_id, Some("hard"), "carol", 20.0).await;
+
+        let (status, page) = send(&app, "GET", "/scores", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let rank_of = |user_id: &str| {
+            page["data"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|score| score["user_id"] == user_id)
+                .unwrap()["rank"]
+                .clone()
+        };
+        assert_eq!(rank_of("bob"), 1);
+        assert_eq!(rank_of("alice"), 2);
+        assert_eq!(rank_of("carol"), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_one_board_or_all_boards() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        create_board(&app, &hex_id, "hard").await;
+        submit(&app, &hex_id, None, "alice", 10.0).await;
+        submit(&app, &hex_id, None, "bob", 30.0).await;
+        submit(&app, &hex_id, Some("hard"), "carol", 20.0).await;
+
+        let (status, page) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}&leaderboard=hard"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let scores = page["data"].as_array().unwrap();
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0]["user_id"], "carol");
+        assert_eq!(scores[0]["rank"], 1);
+
+        let (_, page) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}&leaderboard=default"), None).await;
+        let users: Vec<&str> = page["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|score| score["user_id"].as_str().unwrap())
+            .collect();
+        assert_eq!(users, ["bob", "alice"]);
+
+        // Without a board, every board of the game ranks together
+        let (_, page) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}"), None).await;
+        let ranked: Vec<(&str, i64)> = page["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|score| (score["user_id"].as_str().unwrap(), score["rank"].as_i64().unwrap()))
+            .collect();
+        assert_eq!(ranked, [("bob", 1), ("carol", 2), ("alice", 3)]);
+
+        let (status, _) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}&leaderboard=easy"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "GET", "/scores?leaderboard=hard", None).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_submission_policy_applies_per_board() {
+        let app = create_test_app().await;
+        let (status, game) = send(
+            &app,
+            "POST",
+            "/games",
+            Some(&json!({ "name": "Best Only", "submission_policy": "keep_best" }).to_string()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        let hex_id = game["hex_id"].as_str().unwrap().to_string();
+        create_board(&app, &hex_id, "hard").await;
+
+        let (status, first) = submit(&app, &hex_id, None, "alice", 100.0).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(first["is_personal_best"], true);
+
+        // A lower score on another board is a separate entry and a best on that board
+        let (status, hard) = submit(&app, &hex_id, Some("hard"), "alice", 40.0).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(hard["outcome"], "created");
+        assert_eq!(hard["is_personal_best"], true);
+
+        let (status, better) = submit(&app, &hex_id, Some("hard"), "alice", 60.0).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(better["outcome"], "replaced");
+        assert_eq!(better["id"], hard["id"]);
+
+        let (_, boards) = send(&app, "GET", &format!("/games/{hex_id}/leaderboards"), None).await;
+        let counts: Vec<i64> = boards
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|board| board["score_count"].as_i64().unwrap())
+            .collect();
+        assert_eq!(counts, [1, 1]);
+
+        let (_, page) = send(&app, "GET", &format!("/scores?game_hex_id={hex_id}&leaderboard=default"), None).await;
+        assert_eq!(page["data"][0]["score_val"], 100.0);
+    }
+
+    #[tokio::test]
+    async fn test_batch_items_name_their_board() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        let hard = create_board(&app, &hex_id, "hard").await;
+
+        let body = json!({
+            "mode": "partial",
+            "scores": [
+                { "game_hex_id": hex_id, "leaderboard": "hard", "score": "5", "user_name": "A", "user_id": "a" },
+                { "game_hex_id": hex_id, "leaderboard": "missing", "score": "6", "user_name": "B", "user_id": "b" }
+            ]
+        });
+        let (status, batch) = send(&app, "POST", "/scores/batch", Some(&body.to_string())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(batch["results"][0]["status"], 201);
+        assert_eq!(batch["results"][0]["score"]["leaderboard_id"], hard["id"]);
+        assert_eq!(batch["results"][1]["status"], 404);
+    }
+
+    /// alice plays both boards; bob only the default one, carol and dave only the hard one.
+    async fn create_two_board_game(app: &Router) -> String {
+        let hex_id = create_test_game(app, "Boards").await;
+        create_board(app, &hex_id, "hard").await;
+        submit(app, &hex_id, None, "alice", 10.0).await;
+        submit(app, &hex_id, None, "bob", 50.0).await;
+        submit(app, &hex_id, Some("hard"), "alice", 90.0).await;
+        submit(app, &hex_id, Some("hard"), "carol", 100.0).await;
+        submit(app, &hex_id, Some("hard"), "dave", 95.0).await;
+        hex_id
+    }
+
+    #[tokio::test]
+    async fn test_rank_on_one_board_or_all_boards() {
+        let app = create_test_app().await;
+        let hex_id = create_two_board_game(&app).await;
+
+        let (status, rank) = send(&app, "GET", &format!("/games/{hex_id}/ranks/alice?leaderboard=default"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(rank["rank"], 2);
+        assert_eq!(rank["total_scores"], 2);
+        assert_eq!(rank["best_score"]["score_val"], 10.0);
+
+        let (_, rank) = send(&app, "GET", &format!("/games/{hex_id}/ranks/alice?leaderboard=hard"), None).await;
+        assert_eq!(rank["rank"], 3);
+        assert_eq!(rank["total_scores"], 3);
+        assert_eq!(rank["best_score"]["score_val"], 90.0);
+
+        // Without a board, every board of the game ranks together
+        let (_, rank) = send(&app, "GET", &format!("/games/{hex_id}/ranks/alice"), None).await;
+        assert_eq!(rank["rank"], 3);
+        assert_eq!(rank["total_scores"], 5);
+
+        let (status, _) = send(&app, "GET", &format!("/games/{hex_id}/ranks/bob?leaderboard=hard"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) = send(&app, "GET", &format!("/games/{hex_id}/ranks/alice?leaderboard=easy"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_window_around_a_player_on_one_board() {
+        let app = create_test_app().await;
+        let hex_id = create_two_board_game(&app).await;
+
+        let uri = format!("/games/{hex_id}/leaderboard/around/alice?leaderboard=default");
+        let (status, window) = send(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+        let ranked: Vec<(&str, i64)> = window["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|score| (score["user_id"].as_str().unwrap(), score["rank"].as_i64().unwrap()))
+            .collect();
+        assert_eq!(ranked, [("bob", 1), ("alice", 2)]);
+        assert_eq!(window["has_more_above"], false);
+        assert_eq!(window["has_more_below"], false);
+
+        let (_, window) = send(&app, "GET", &format!("/games/{hex_id}/leaderboard/around/alice"), None).await;
+        assert_eq!(window["data"].as_array().unwrap().len(), 5);
+        assert_eq!(window["data"][2]["user_id"], "alice");
+        assert_eq!(window["data"][2]["score_val"], 90.0);
+
+        let uri = format!("/games/{hex_id}/leaderboard/around/alice?leaderboard=easy");
+        let (status, _) = send(&app, "GET", &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_distinct_listing_keeps_a_best_entry_per_board() {
+        let app = create_test_app().await;
+        let hex_id = create_two_board_game(&app).await;
+        submit(&app, &hex_id, None, "alice", 20.0).await;
+
+        let uri = format!("/scores?game_hex_id={hex_id}&distinct_users=true");
+        let (_, page) = send(&app, "GET", &uri, None).await;
+        let entries: Vec<(&str, f64)> = page["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|score| (score["user_id"].as_str().unwrap(), score["score_val"].as_f64().unwrap()))
+            .collect();
+        assert_eq!(entries, [("carol", 100.0), ("dave", 95.0), ("alice", 90.0), ("bob", 50.0), ("alice", 20.0)]);
+    }
+
+    #[tokio::test]
+    async fn test_game_stats_on_one_board_or_all_boards() {
+        let app = create_test_app().await;
+        let hex_id = create_two_board_game(&app).await;
+
+        let (status, stats) = send(&app, "GET", &format!("/games/{hex_id}/stats?leaderboard=hard"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(stats["total_scores"], 3);
+        assert_eq!(stats["unique_players"], 3);
+        assert_eq!(stats["score_val"]["min"], 90.0);
+        assert_eq!(stats["score_val"]["median"], 95.0);
+
+        let (_, stats) = send(&app, "GET", &format!("/games/{hex_id}/stats"), None).await;
+        assert_eq!(stats["total_scores"], 5);
+        assert_eq!(stats["unique_players"], 4);
+        assert_eq!(stats["score_val"]["min"], 10.0);
+
+        let (status, _) = send(&app, "GET", &format!("/games/{hex_id}/stats?leaderboard=easy"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_player_stats_rank_each_board_separately() {
+        let app = create_test_app().await;
+        let hex_id = create_two_board_game(&app).await;
+
+        // 2nd of 2 on the default board beats 3rd of 3 on the hard one
+        let (status, stats) = send(&app, "GET", "/players/alice/stats", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let games = stats["games"].as_array().unwrap();
+        assert_eq!(games.len(), 1);
+        assert_eq!(games[0]["game_hex_id"], hex_id);
+        assert_eq!(games[0]["rank"], 2);
+        assert_eq!(games[0]["best_score"]["score_val"], 10.0);
+        assert_eq!(games[0]["submissions"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_season_standings_rank_each_board_separately() {
+        let app = create_test_app().await;
+        let hex_id = create_test_game(&app, "Boards").await;
+        let now = chrono::Utc::now();
+        let season = json!({
+            "name": "Season 1",
+            "starts_at": now - chrono::Duration::days(1),
+            "ends_at": now + chrono::Duration::days(1)
+        });
+        let (_, season) = send(&app, "POST", &format!("/games/{hex_id}/seasons"), Some(&season.to_string())).await;
+        let hard = create_board(&app, &hex_id, "hard").await;
+        submit(&app, &hex_id, None, "alice", 10.0).await;
+        submit(&app, &hex_id, None, "bob", 50.0).await;
+        submit(&app, &hex_id, Some("hard"), "alice", 90.0).await;
+        submit(&app, &hex_id, Some("hard"), "carol", 100.0).await;
+
+        let (status, _) = send(&app, "POST", &format!("/games/{hex_id}/seasons/{}/close", season["id"]), None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let standings_uri = format!("/games/{hex_id}/seasons/{}/standings", season["id"]);
+        let (status, page) = send(&app, "GET", &format!("{standings_uri}?leaderboard=hard"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let standings = page["data"].as_array().unwrap();
+        assert_eq!(standings.len(), 2);
+        assert_eq!(standings[0]["user_id"], "carol");
+        assert_eq!(standings[0]["leaderboard_id"], hard["id"]);
+        assert_eq!(standings[1]["user_id"], "alice");
+        assert_eq!(standings[1]["score_val"], 90.0);
+        assert_eq!(standings[1]["rank"], 2);
+
+        let (_, page) = send(&app, "GET", &format!("{standings_uri}?leaderboard=default"), None).await;
+        let ranked: Vec<(&str, i64)> = page["data"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|standing| (standing["user_id"].as_str().unwrap(), standing["rank"].as_i64().unwrap()))
+            .collect();
+        assert_eq!(ranked, [("bob", 1), ("alice", 2)]);
+
+        // Without a board, every board's standings are listed by rank
+        let (_, page) = send(&app, "GET", &standings_uri, None).await;
+        assert_eq!(page["data"].as_array().unwrap().len(), 4);
+
+        let (status, _) = send(&app, "GET", &format!("{standings_uri}?leaderboard=easy"), None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+}
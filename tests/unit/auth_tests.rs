@@ -1,5 +1,5 @@
 use axum::http::{HeaderMap, HeaderValue};
-use leadr_api::auth::{ApiKeyAuth, API_KEY_HEADER};
+use leadr_api::auth::{ApiKeyAuth, API_KEY_HEADER, SIGNATURE_TOLERANCE_SECS};
 
 #[test]
 fn test_new_api_key_auth() {
@@ -135,7 +135,7 @@ fn test_constant_time_comparison_properties() {
     let auth = ApiKeyAuth::new("correct_key".to_string());
 
     // These should all take similar time (hard to test in unit tests)
-    let test_cases = vec![
+    let test_cases = [
         "wrong_key_1",
         "wrong_key_2",
         "completely_different",
@@ -149,3 +149,114 @@ fn test_constant_time_comparison_properties() {
 
     assert_eq!(results, vec![false, false, false, true]);
 }
+
+#[test]
+fn test_api_key_scope_hierarchy() {
+    use leadr_api::models::ApiKeyScope;
+
+    assert!(ApiKeyScope::Admin.allows(ApiKeyScope::Submit));
+    assert!(ApiKeyScope::Submit.allows(ApiKeyScope::Read));
+    assert!(ApiKeyScope::Read.allows(ApiKeyScope::Read));
+    assert!(!ApiKeyScope::Read.allows(ApiKeyScope::Submit));
+    assert!(!ApiKeyScope::Submit.allows(ApiKeyScope::Admin));
+}
+
+#[test]
+fn test_api_key_scope_round_trip() {
+    use leadr_api::models::ApiKeyScope;
+
+    for scope in [ApiKeyScope::Read, ApiKeyScope::Submit, ApiKeyScope::Admin] {
+        assert_eq!(scope.as_str().parse::<ApiKeyScope>().unwrap(), scope);
+    }
+    assert!("superuser".parse::<ApiKeyScope>().is_err());
+}
+
+#[test]
+fn test_api_key_row_with_unknown_scope_is_rejected() {
+    use leadr_api::models::{ApiKey, ApiKeyRow, ApiKeyScope};
+
+    let row = |scope: &str| ApiKeyRow {
+        id: 1,
+        name: "Client".to_string(),
+        prefix: "leadr_abcdef".to_string(),
+        scope: scope.to_string(),
+        created_at: chrono::Utc::now().naive_utc(),
+        revoked_at: None,
+    };
+    assert_eq!(ApiKey::try_from(row("submit")).unwrap().scope, ApiKeyScope::Submit);
+    assert!(ApiKey::try_from(row("superuser")).is_err());
+}
+
+#[test]
+fn test_generated_api_keys_are_unique_and_hashed() {
+    use leadr_api::models::ApiKey;
+
+    let key = ApiKey::generate_key();
+    assert!(key.starts_with("leadr_"));
+    assert_ne!(key, ApiKey::generate_key());
+
+    let hash = ApiKey::hash_key(&key);
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, ApiKey::hash_key(&key));
+    assert_ne!(hash, ApiKey::hash_key("leadr_other"));
+}
+
+#[test]
+fn test_authenticated_key_game_restrictions() {
+    use leadr_api::auth::AuthenticatedKey;
+    use leadr_api::models::ApiKeyScope;
+
+    let master = AuthenticatedKey::master();
+    assert!(!master.is_restricted());
+    assert!(master.check_game("abc123").is_ok());
+
+    let bound = AuthenticatedKey {
+        id: Some(1),
+        scope: ApiKeyScope::Submit,
+        game_hex_ids: vec!["abc123".to_string()],
+    };
+    assert!(bound.is_restricted());
+    assert!(bound.check_game("abc123").is_ok());
+    assert!(bound.check_game("def456").is_err());
+}
+
+#[test]
+fn test_submission_signature_verification() {
+    use leadr_api::auth::SubmissionSignature;
+
+    let now = chrono::Utc::now();
+    let payload = "abc123\n100\n100\nPlayer\nplayer\n";
+    let signature = SubmissionSignature {
+        timestamp: now.timestamp(),
+        nonce: "nonce".to_string(),
+        signature: SubmissionSignature::sign("secret", now.timestamp(), "nonce", payload),
+    };
+    assert_eq!(signature.signature.len(), 64);
+    assert!(signature.verify("secret", payload, now).is_ok());
+    assert!(signature.verify("other", payload, now).is_err());
+    assert!(signature.verify("secret", "abc123\n999\n999\nPlayer\nplayer\n", now).is_err());
+
+    let stale = now + chrono::Duration::seconds(SIGNATURE_TOLERANCE_SECS + 1);
+    assert!(signature.verify("secret", payload, stale).is_err());
+}
+
+#[test]
+fn test_submission_signature_from_headers() {
+    use leadr_api::auth::{
+        SubmissionSignature, SIGNATURE_HEADER, SIGNATURE_NONCE_HEADER, SIGNATURE_TIMESTAMP_HEADER,
+    };
+
+    let mut headers = HeaderMap::new();
+    headers.insert(SIGNATURE_TIMESTAMP_HEADER, HeaderValue::from_static("1700000000"));
+    headers.insert(SIGNATURE_NONCE_HEADER, HeaderValue::from_static("nonce"));
+    assert!(SubmissionSignature::from_headers(&headers).is_err());
+
+    headers.insert(SIGNATURE_HEADER, HeaderValue::from_static("ABCDEF"));
+    let signature = SubmissionSignature::from_headers(&headers).unwrap();
+    assert_eq!(signature.timestamp, 1_700_000_000);
+    assert_eq!(signature.nonce, "nonce");
+    assert_eq!(signature.signature, "abcdef");
+
+    headers.insert(SIGNATURE_TIMESTAMP_HEADER, HeaderValue::from_static("yesterday"));
+    assert!(SubmissionSignature::from_headers(&headers).is_err());
+}
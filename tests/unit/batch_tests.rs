@@ -0,0 +1,65 @@
+use chrono::{Duration, Utc};
+use leadr_api::models::batch::{BatchItemResult, BatchMode, BatchSubmission, CreateScoreBatch, MAX_BATCH_SIZE};
+use serde_json::json;
+
+fn batch_of(count: usize) -> CreateScoreBatch {
+    let scores: Vec<_> = (0..count)
+        .map(|i| {
+            json!({
+                "game_hex_id": "abc123",
+                "score": i.to_string(),
+                "user_name": "Player",
+                "user_id": "player"
+            })
+        })
+        .collect();
+    serde_json::from_value(json!({ "scores": scores })).unwrap()
+}
+
+#[test]
+fn test_batch_mode_defaults_to_all_or_nothing() {
+    assert_eq!(batch_of(1).mode, BatchMode::AllOrNothing);
+
+    let batch: CreateScoreBatch = serde_json::from_value(json!({ "mode": "partial", "scores": [] })).unwrap();
+    assert_eq!(batch.mode, BatchMode::Partial);
+}
+
+#[test]
+fn test_batch_size_validation() {
+    assert!(batch_of(0).validate().is_err());
+    assert!(batch_of(1).validate().is_ok());
+    assert!(batch_of(MAX_BATCH_SIZE).validate().is_ok());
+    assert!(batch_of(MAX_BATCH_SIZE + 1).validate().is_err());
+}
+
+#[test]
+fn test_resolve_submitted_at() {
+    let now = Utc::now();
+    let mut item = batch_of(1).scores.remove(0);
+    assert_eq!(item.resolve_submitted_at(now).unwrap(), now);
+
+    item.submitted_at = Some(now - Duration::days(3));
+    assert_eq!(item.resolve_submitted_at(now).unwrap(), now - Duration::days(3));
+
+    // Small clock differences are tolerated
+    item.submitted_at = Some(now + Duration::seconds(30));
+    assert!(item.resolve_submitted_at(now).is_ok());
+
+    item.submitted_at = Some(now + Duration::hours(1));
+    assert!(item.resolve_submitted_at(now).is_err());
+}
+
+#[test]
+fn test_batch_submission_tallies_results() {
+    let results = vec![
+        BatchItemResult::failed(0, 404, "Resource not found".to_string()),
+        BatchItemResult::failed(1, 422, "User name cannot be empty".to_string()),
+    ];
+    let submission = BatchSubmission::new(BatchMode::Partial, true, results);
+    assert_eq!(submission.created, 0);
+    assert_eq!(submission.replaced, 0);
+    assert_eq!(submission.unchanged, 0);
+    assert_eq!(submission.failed, 2);
+    assert_eq!(submission.results[1].status, 422);
+    assert!(submission.results[1].score.is_none());
+}
@@ -1,4 +1,12 @@
-use leadr_api::models::game::{Game, UpdateGame};
+use leadr_api::models::game::{
+    hex_id_len_from_env, Game, ScoreOrder, ScoreValidation, SubmissionPolicy, UpdateGame, DEFAULT_HEX_ID_LEN,
+    HEX_ID_LEN_VAR,
+};
+use leadr_api::models::stats::{
+    build_histogram, percentile_offset, GameStatsQueryParams, DEFAULT_HISTOGRAM_BUCKETS, MAX_HISTOGRAM_BUCKETS,
+};
+use leadr_api::utils::pagination::{ScoreSortField, SortOrder};
+use serde_json::json;
 
 #[test]
 fn test_generate_hex_id_format() {
@@ -7,6 +15,30 @@ fn test_generate_hex_id_format() {
     assert!(hex_id.chars().all(|c| c.is_ascii_hexdigit()));
 }
 
+#[test]
+fn test_generate_hex_id_with_len() {
+    let hex_id = Game::generate_hex_id_with_len(12);
+    assert_eq!(hex_id.len(), 12);
+    assert!(Game::validate_hex_id(&hex_id).is_ok());
+}
+
+#[test]
+fn test_hex_id_len_from_env() {
+    std::env::remove_var(HEX_ID_LEN_VAR);
+    assert_eq!(hex_id_len_from_env(), Ok(DEFAULT_HEX_ID_LEN));
+
+    std::env::set_var(HEX_ID_LEN_VAR, "10");
+    assert_eq!(hex_id_len_from_env(), Ok(10));
+
+    std::env::set_var(HEX_ID_LEN_VAR, "4");
+    assert!(hex_id_len_from_env().is_err());
+
+    std::env::set_var(HEX_ID_LEN_VAR, "long");
+    assert!(hex_id_len_from_env().is_err());
+
+    std::env::remove_var(HEX_ID_LEN_VAR);
+}
+
 #[test]
 fn test_generate_hex_id_uniqueness() {
     let id1 = Game::generate_hex_id();
@@ -90,7 +122,12 @@ fn test_update_name_only() {
 
     let update = UpdateGame {
         name: Some("Updated Name".to_string()),
+        slug: None,
         description: None,
+        score_order: None,
+        default_sort_by: None,
+        submission_policy: None,
+        validation: None,
     };
 
     game.update(update);
@@ -107,7 +144,12 @@ fn test_update_description_only() {
 
     let update = UpdateGame {
         name: None,
+        slug: None,
         description: Some("Updated Description".to_string()),
+        score_order: None,
+        default_sort_by: None,
+        submission_policy: None,
+        validation: None,
     };
 
     game.update(update);
@@ -122,7 +164,12 @@ fn test_update_both_fields() {
 
     let update = UpdateGame {
         name: Some("New Name".to_string()),
+        slug: None,
         description: Some("New Description".to_string()),
+        score_order: None,
+        default_sort_by: None,
+        submission_policy: None,
+        validation: None,
     };
 
     game.update(update);
@@ -142,7 +189,12 @@ fn test_update_with_empty_changes() {
 
     let update = UpdateGame {
         name: None,
+        slug: None,
         description: None,
+        score_order: None,
+        default_sort_by: None,
+        submission_policy: None,
+        validation: None,
     };
 
     game.update(update);
@@ -159,12 +211,49 @@ fn test_validate_hex_id_valid() {
     assert!(Game::validate_hex_id("000000").is_ok());
     assert!(Game::validate_hex_id("ffffff").is_ok());
     assert!(Game::validate_hex_id("a1b2c3").is_ok());
+    assert!(Game::validate_hex_id("0123456789abcdef").is_ok());
+}
+
+#[test]
+fn test_validate_slug() {
+    assert!(Game::validate_slug("space-invaders-hard").is_ok());
+    assert!(Game::validate_slug("tetris").is_ok());
+    assert!(Game::validate_slug("2048").is_ok());
+
+    assert!(Game::validate_slug("ab").is_err()); // too short
+    assert!(Game::validate_slug(&"a".repeat(65)).is_err()); // too long
+    assert!(Game::validate_slug("Space-Invaders").is_err()); // uppercase not allowed
+    assert!(Game::validate_slug("space_invaders").is_err()); // underscore not allowed
+    assert!(Game::validate_slug("-invaders").is_err());
+    assert!(Game::validate_slug("invaders-").is_err());
+    assert!(Game::validate_slug("space--invaders").is_err());
+}
+
+#[test]
+fn test_update_game_slug() {
+    let mut game = Game::new("Slugged".to_string(), None);
+    let update = |slug: &str| UpdateGame {
+        name: None,
+        slug: Some(slug.to_string()),
+        description: None,
+        score_order: None,
+        default_sort_by: None,
+        submission_policy: None,
+        validation: None,
+    };
+
+    game.update(update("slugged"));
+    assert_eq!(game.slug.as_deref(), Some("slugged"));
+
+    // An empty slug removes it
+    game.update(update(""));
+    assert_eq!(game.slug, None);
 }
 
 #[test]
 fn test_validate_hex_id_invalid_length() {
     assert!(Game::validate_hex_id("abc12").is_err());
-    assert!(Game::validate_hex_id("abc1234").is_err());
+    assert!(Game::validate_hex_id(&"a".repeat(33)).is_err());
     assert!(Game::validate_hex_id("").is_err());
 }
 
@@ -193,3 +282,158 @@ fn test_validate_name_empty() {
 fn test_validate_name_too_long() {
     assert!(Game::validate_name(&"a".repeat(256)).is_err());
 }
+
+#[test]
+fn test_new_game_default_score_settings() {
+    let game = Game::new("Test".to_string(), None);
+    assert_eq!(game.score_order, ScoreOrder::HigherIsBetter);
+    assert_eq!(game.default_sort_by, ScoreSortField::Score);
+}
+
+#[test]
+fn test_update_score_settings() {
+    let mut game = Game::new("Speedrun".to_string(), None);
+
+    let update = UpdateGame {
+        name: None,
+        slug: None,
+        description: None,
+        score_order: Some(ScoreOrder::LowerIsBetter),
+        default_sort_by: Some(ScoreSortField::Date),
+        submission_policy: None,
+        validation: None,
+    };
+    game.update(update);
+
+    assert_eq!(game.score_order, ScoreOrder::LowerIsBetter);
+    assert_eq!(game.default_sort_by, ScoreSortField::Date);
+}
+
+#[test]
+fn test_score_order_best_first() {
+    assert_eq!(ScoreOrder::HigherIsBetter.best_first(), SortOrder::Descending);
+    assert_eq!(ScoreOrder::LowerIsBetter.best_first(), SortOrder::Ascending);
+}
+
+#[test]
+fn test_score_order_round_trip() {
+    for order in [ScoreOrder::HigherIsBetter, ScoreOrder::LowerIsBetter] {
+        assert_eq!(order.as_str().parse::<ScoreOrder>().unwrap(), order);
+    }
+    assert!("sideways".parse::<ScoreOrder>().is_err());
+}
+
+#[test]
+fn test_score_order_is_better() {
+    assert!(ScoreOrder::HigherIsBetter.is_better(10.0, 5.0));
+    assert!(!ScoreOrder::HigherIsBetter.is_better(5.0, 5.0));
+    assert!(ScoreOrder::LowerIsBetter.is_better(5.0, 10.0));
+    assert!(!ScoreOrder::LowerIsBetter.is_better(10.0, 5.0));
+}
+
+#[test]
+fn test_submission_policy_round_trip() {
+    assert_eq!(Game::new("Test".to_string(), None).submission_policy, SubmissionPolicy::KeepAll);
+    for policy in [
+        SubmissionPolicy::KeepAll,
+        SubmissionPolicy::KeepBest,
+        SubmissionPolicy::KeepLatest,
+    ] {
+        assert_eq!(policy.as_str().parse::<SubmissionPolicy>().unwrap(), policy);
+    }
+    assert!("keep_some".parse::<SubmissionPolicy>().is_err());
+}
+
+#[test]
+fn test_score_validation_default_accepts_finite_values() {
+    let rules = ScoreValidation::default();
+    assert!(rules.validate().is_ok());
+    assert!(rules.check_score(1e300, None).is_ok());
+    assert!(rules.check_score(-12.5, Some(&json!({ "anything": true }))).is_ok());
+    assert!(rules.check_score(f64::NAN, None).is_err());
+    assert!(rules.check_score(f64::INFINITY, None).is_err());
+}
+
+#[test]
+fn test_score_validation_bounds_and_integers() {
+    let rules = ScoreValidation {
+        min_score_val: Some(0.0),
+        max_score_val: Some(1000.0),
+        integer_only: true,
+        ..ScoreValidation::default()
+    };
+
+    assert!(rules.check_score(0.0, None).is_ok());
+    assert!(rules.check_score(1000.0, None).is_ok());
+    assert!(rules.check_score(-1.0, None).unwrap_err().contains("below the minimum"));
+    assert!(rules.check_score(1001.0, None).unwrap_err().contains("above the maximum"));
+    assert!(rules.check_score(12.5, None).unwrap_err().contains("whole number"));
+}
+
+#[test]
+fn test_score_validation_extra_schema() {
+    let rules = ScoreValidation {
+        extra_schema: Some(json!({
+            "type": "object",
+            "properties": { "level": { "type": "integer", "minimum": 1 } },
+            "required": ["level"]
+        })),
+        ..ScoreValidation::default()
+    };
+
+    assert!(rules.check_score(10.0, Some(&json!({ "level": 3 }))).is_ok());
+    assert!(rules.check_score(10.0, None).is_ok());
+    assert!(rules.check_score(10.0, Some(&json!({ "level": 0 }))).is_err());
+    assert!(rules.check_score(10.0, Some(&json!({ "time": 12 }))).is_err());
+}
+
+#[test]
+fn test_score_validation_rejects_inconsistent_rules() {
+    let reversed = ScoreValidation {
+        min_score_val: Some(10.0),
+        max_score_val: Some(1.0),
+        ..ScoreValidation::default()
+    };
+    assert!(reversed.validate().is_err());
+
+    let zero_rate = ScoreValidation {
+        max_submissions_per_minute: Some(0),
+        ..ScoreValidation::default()
+    };
+    assert!(zero_rate.validate().is_err());
+
+    let bad_schema = ScoreValidation {
+        extra_schema: Some(json!({ "type": "not-a-type" })),
+        ..ScoreValidation::default()
+    };
+    assert!(bad_schema.validate().is_err());
+}
+
+#[test]
+fn test_game_stats_buckets_param() {
+    let params = |buckets| GameStatsQueryParams { buckets, leaderboard: None };
+    assert_eq!(GameStatsQueryParams::default().get_buckets(), Ok(DEFAULT_HISTOGRAM_BUCKETS));
+    assert_eq!(params(Some(25)).get_buckets(), Ok(25));
+    assert!(params(Some(0)).get_buckets().is_err());
+    assert!(params(Some(MAX_HISTOGRAM_BUCKETS + 1)).get_buckets().is_err());
+}
+
+#[test]
+fn test_percentile_offset() {
+    assert_eq!(percentile_offset(10, 50.0), 4);
+    assert_eq!(percentile_offset(10, 90.0), 8);
+    assert_eq!(percentile_offset(10, 99.0), 9);
+    assert_eq!(percentile_offset(1, 99.0), 0);
+    assert_eq!(percentile_offset(200, 0.0), 0);
+}
+
+#[test]
+fn test_build_histogram() {
+    let histogram = build_histogram(0.0, 10.0, 4, &[(0, 2), (3, 5)]);
+    let bounds: Vec<(f64, f64, i64)> = histogram.iter().map(|b| (b.lower, b.upper, b.count)).collect();
+    assert_eq!(bounds, vec![(0.0, 2.5, 2), (2.5, 5.0, 0), (5.0, 7.5, 0), (7.5, 10.0, 5)]);
+
+    let histogram = build_histogram(3.0, 3.0, 10, &[(0, 4)]);
+    assert_eq!(histogram.len(), 1);
+    assert_eq!(histogram[0].count, 4);
+}
@@ -0,0 +1,38 @@
+use chrono::Duration;
+use leadr_api::models::idempotency::{
+    idempotency_ttl_from_env, request_hash, validate_idempotency_key, DEFAULT_IDEMPOTENCY_TTL_SECS,
+    IDEMPOTENCY_TTL_VAR, MAX_IDEMPOTENCY_KEY_LEN,
+};
+
+#[test]
+fn test_validate_idempotency_key() {
+    assert!(validate_idempotency_key("3f1c9a2e-retry").is_ok());
+    assert!(validate_idempotency_key(&"k".repeat(MAX_IDEMPOTENCY_KEY_LEN)).is_ok());
+    assert!(validate_idempotency_key("").is_err());
+    assert!(validate_idempotency_key(&"k".repeat(MAX_IDEMPOTENCY_KEY_LEN + 1)).is_err());
+}
+
+#[test]
+fn test_request_hash() {
+    let hash = request_hash(br#"{"score":"100"}"#);
+    assert_eq!(hash.len(), 64);
+    assert_eq!(hash, request_hash(br#"{"score":"100"}"#));
+    assert_ne!(hash, request_hash(br#"{"score":"200"}"#));
+}
+
+#[test]
+fn test_idempotency_ttl_from_env() {
+    std::env::remove_var(IDEMPOTENCY_TTL_VAR);
+    assert_eq!(idempotency_ttl_from_env(), Ok(Duration::seconds(DEFAULT_IDEMPOTENCY_TTL_SECS)));
+
+    std::env::set_var(IDEMPOTENCY_TTL_VAR, " 3600 ");
+    assert_eq!(idempotency_ttl_from_env(), Ok(Duration::hours(1)));
+
+    std::env::set_var(IDEMPOTENCY_TTL_VAR, "0");
+    assert!(idempotency_ttl_from_env().is_err());
+
+    std::env::set_var(IDEMPOTENCY_TTL_VAR, "a day");
+    assert!(idempotency_ttl_from_env().is_err());
+
+    std::env::remove_var(IDEMPOTENCY_TTL_VAR);
+}
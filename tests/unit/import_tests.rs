@@ -0,0 +1,77 @@
+use leadr_api::models::import::{parse_csv, parse_jsonl, ImportRecord};
+
+const CSV_HEADER: &str = "game_hex_id,game_name,game_description,game_created_at,game_updated_at,game_deleted_at,score_id,score_value,score_val,user_name,user_id,extra,score_submitted_at,score_deleted_at";
+
+#[test]
+fn test_parse_csv_reads_each_game_once() {
+    let csv = format!(
+        "{CSV_HEADER}\n\
+         abc123,Game,,2024-01-01T00:00:00Z,,,1,10,10.0,P1,u1,,2024-01-01T00:01:00Z,\n\
+         ABC123,Game,,2024-01-01T00:00:00Z,,,2,20,20.0,P2,u2,,2024-01-01T00:02:00Z,2024-01-02T00:00:00Z\n\
+         def456,Empty,,2024-01-01T00:00:00Z,,,,,,,,,,\n"
+    );
+    let lines = parse_csv(csv.as_bytes()).unwrap();
+
+    let kinds: Vec<_> = lines
+        .iter()
+        .map(|l| (l.line, matches!(l.record, Ok(ImportRecord::Game(_)))))
+        .collect();
+    assert_eq!(kinds, vec![(2, true), (2, false), (3, false), (4, true)]);
+
+    match &lines[2].record {
+        Ok(ImportRecord::Score(score)) => {
+            assert_eq!(score.id, Some(2));
+            assert!(score.deleted_at.is_some());
+        }
+        other => panic!("expected score, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_parse_csv_reads_each_leaderboard_once() {
+    let csv = "game_hex_id,game_name,game_description,game_created_at,game_updated_at,game_deleted_at,\
+               leaderboard_slug,leaderboard_name,leaderboard_description,leaderboard_created_at,\
+               score_id,score_value,score_val,user_name,user_id,extra,score_submitted_at,score_deleted_at\n\
+               abc123,Game,,2024-01-01T00:00:00Z,,,hard,Hard,,2024-01-01T00:00:00Z,1,10,10.0,P1,u1,,2024-01-01T00:01:00Z,\n\
+               abc123,Game,,2024-01-01T00:00:00Z,,,hard,Hard,,2024-01-01T00:00:00Z,2,20,20.0,P2,u2,,2024-01-01T00:02:00Z,\n\
+               abc123,Game,,2024-01-01T00:00:00Z,,,empty,Empty,,2024-01-01T00:00:00Z,,,,,,,,\n";
+    let lines = parse_csv(csv.as_bytes()).unwrap();
+
+    let kinds: Vec<_> = lines
+        .iter()
+        .map(|l| match &l.record {
+            Ok(ImportRecord::Game(_)) => "game".to_string(),
+            Ok(ImportRecord::Leaderboard(board)) => format!("leaderboard {}", board.slug),
+            Ok(ImportRecord::Score(score)) => format!("score on {}", score.leaderboard.as_deref().unwrap()),
+            Err(e) => panic!("unexpected error: {e}"),
+        })
+        .collect();
+    assert_eq!(
+        kinds,
+        vec!["game", "leaderboard hard", "score on hard", "score on hard", "leaderboard empty"]
+    );
+}
+
+#[test]
+fn test_parse_csv_reports_bad_rows() {
+    let csv = format!("{CSV_HEADER}\nabc123,Game,,yesterday,,,,,,,,,,\n");
+    let lines = parse_csv(csv.as_bytes()).unwrap();
+    assert_eq!(lines.len(), 1);
+    assert!(lines[0].record.as_ref().unwrap_err().contains("game_created_at"));
+}
+
+#[test]
+fn test_parse_jsonl_numbers_lines_and_skips_blanks() {
+    let jsonl = concat!(
+        r#"{"type":"game","hex_id":"abc123","name":"Game","created_at":"2024-01-01T00:00:00Z"}"#,
+        "\n\n",
+        r#"{"type":"widget"}"#,
+        "\n"
+    );
+    let lines = parse_jsonl(jsonl.as_bytes());
+    assert_eq!(lines.len(), 2);
+    assert_eq!(lines[0].line, 1);
+    assert!(matches!(lines[0].record, Ok(ImportRecord::Game(_))));
+    assert_eq!(lines[1].line, 3);
+    assert!(lines[1].record.is_err());
+}
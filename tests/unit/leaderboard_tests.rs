@@ -0,0 +1,24 @@
+use leadr_api::models::leaderboard::{Leaderboard, MAX_LEADERBOARD_SLUG_LEN};
+
+#[test]
+fn test_validate_leaderboard_slug() {
+    assert!(Leaderboard::validate_slug("default").is_ok());
+    assert!(Leaderboard::validate_slug("level-1-hard").is_ok());
+    assert!(Leaderboard::validate_slug("a").is_ok());
+    assert!(Leaderboard::validate_slug(&"a".repeat(MAX_LEADERBOARD_SLUG_LEN)).is_ok());
+
+    assert!(Leaderboard::validate_slug("").is_err());
+    assert!(Leaderboard::validate_slug(&"a".repeat(MAX_LEADERBOARD_SLUG_LEN + 1)).is_err());
+    assert!(Leaderboard::validate_slug("Level-1").is_err());
+    assert!(Leaderboard::validate_slug("level_1").is_err());
+    assert!(Leaderboard::validate_slug("-easy").is_err());
+    assert!(Leaderboard::validate_slug("easy-").is_err());
+    assert!(Leaderboard::validate_slug("very--easy").is_err());
+}
+
+#[test]
+fn test_validate_leaderboard_name() {
+    assert!(Leaderboard::validate_name("Level 1 (Hard)").is_ok());
+    assert!(Leaderboard::validate_name("   ").is_err());
+    assert!(Leaderboard::validate_name(&"a".repeat(256)).is_err());
+}
@@ -1,4 +1,13 @@
 pub mod auth_tests;
+pub mod batch_tests;
 pub mod game_tests;
+pub mod idempotency_tests;
+pub mod import_tests;
+pub mod leaderboard_tests;
 pub mod pagination_tests;
+pub mod player_tests;
+pub mod purge_tests;
+pub mod ranking_tests;
 pub mod score_tests;
+pub mod season_tests;
+pub mod webhook_tests;
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use leadr_api::models::{Game, Score};
+use leadr_api::models::{Game, Score, ScoreOrder, ScoreValidation, SubmissionPolicy};
 use leadr_api::utils::pagination::cursor::*;
 use leadr_api::utils::pagination::*;
 use serde_json::json;
@@ -220,11 +220,18 @@ fn test_game_cursor_from_game() {
     let game = Game {
         id: 1,
         hex_id: "abc123".to_string(),
+        slug: None,
         name: "Test Game".to_string(),
         description: Some("Test".to_string()),
+        score_order: ScoreOrder::HigherIsBetter,
+        default_sort_by: ScoreSortField::Score,
+        submission_policy: SubmissionPolicy::KeepAll,
+        validation: ScoreValidation::default(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
         deleted_at: None,
+        score_count: None,
+        player_count: None,
     };
 
     let cursor = GameCursor::from_game(&game);
@@ -237,6 +244,7 @@ fn test_score_cursor_from_score_different_fields() {
     let score = Score {
         id: 123,
         game_hex_id: "abc123".to_string(),
+        leaderboard_id: 1,
         score: "1000".to_string(),
         score_val: 1000.5,
         user_name: "TestPlayer".to_string(),
@@ -244,6 +252,7 @@ fn test_score_cursor_from_score_different_fields() {
         extra: Some(json!({"level": 5})),
         submitted_at: Utc::now(),
         deleted_at: None,
+        rank: None,
     };
 
     // Test score_val field
@@ -273,11 +282,18 @@ fn test_round_trip_with_helpers() {
     let game = Game {
         id: 2,
         hex_id: "def456".to_string(),
+        slug: None,
         name: "Round Trip Game".to_string(),
         description: None,
+        score_order: ScoreOrder::HigherIsBetter,
+        default_sort_by: ScoreSortField::Score,
+        submission_policy: SubmissionPolicy::KeepAll,
+        validation: ScoreValidation::default(),
         created_at: Utc::now(),
         updated_at: Utc::now(),
         deleted_at: None,
+        score_count: None,
+        player_count: None,
     };
 
     let game_cursor = GameCursor::from_game(&game);
@@ -290,6 +306,7 @@ fn test_round_trip_with_helpers() {
     let score = Score {
         id: 456,
         game_hex_id: "def456".to_string(),
+        leaderboard_id: 1,
         score: "2000".to_string(),
         score_val: 2000.75,
         user_name: "RoundTripPlayer".to_string(),
@@ -297,6 +314,7 @@ fn test_round_trip_with_helpers() {
         extra: Some(json!({"test": true})),
         submitted_at: Utc::now(),
         deleted_at: None,
+        rank: None,
     };
 
     let score_cursor = ScoreCursor::from_score(&score, "score_val");
@@ -305,3 +323,119 @@ fn test_round_trip_with_helpers() {
     assert_eq!(decoded.id, score.id);
     assert_eq!(decoded.sort_value, "2000.75");
 }
+
+#[test]
+fn test_sort_params_with_game_defaults_lower_is_better() {
+    let mut game = Game::new("Speedrun".to_string(), None);
+    game.score_order = ScoreOrder::LowerIsBetter;
+
+    let params = ScoreSortParams::new(None, None).with_game_defaults(&game);
+    assert!(matches!(params.get_sort_field(), ScoreSortField::Score));
+    assert!(matches!(params.get_sort_order(), SortOrder::Ascending));
+}
+
+#[test]
+fn test_sort_params_with_game_defaults_respects_explicit_values() {
+    let mut game = Game::new("Speedrun".to_string(), None);
+    game.score_order = ScoreOrder::LowerIsBetter;
+    game.default_sort_by = ScoreSortField::Date;
+
+    let params = ScoreSortParams::new(None, None).with_game_defaults(&game);
+    assert!(matches!(params.get_sort_field(), ScoreSortField::Date));
+    assert!(matches!(params.get_sort_order(), SortOrder::Descending));
+
+    let params = ScoreSortParams::new(Some(ScoreSortField::Score), Some(SortOrder::Descending))
+        .with_game_defaults(&game);
+    assert!(matches!(params.get_sort_field(), ScoreSortField::Score));
+    assert!(matches!(params.get_sort_order(), SortOrder::Descending));
+}
+
+#[test]
+fn test_time_window_bounds_utc() {
+    let anchor = "2024-05-15T13:30:00Z".parse::<chrono::DateTime<Utc>>().unwrap();
+    let tz = chrono_tz::Tz::UTC;
+
+    assert!(TimeWindow::AllTime.bounds(anchor, tz).is_none());
+
+    let (from, to) = TimeWindow::Daily.bounds(anchor, tz).unwrap();
+    assert_eq!(from.to_rfc3339(), "2024-05-15T00:00:00+00:00");
+    assert_eq!(to.to_rfc3339(), "2024-05-16T00:00:00+00:00");
+
+    // 2024-05-15 is a Wednesday, weeks start on Monday
+    let (from, to) = TimeWindow::Weekly.bounds(anchor, tz).unwrap();
+    assert_eq!(from.to_rfc3339(), "2024-05-13T00:00:00+00:00");
+    assert_eq!(to.to_rfc3339(), "2024-05-20T00:00:00+00:00");
+
+    let (from, to) = TimeWindow::Monthly.bounds(anchor, tz).unwrap();
+    assert_eq!(from.to_rfc3339(), "2024-05-01T00:00:00+00:00");
+    assert_eq!(to.to_rfc3339(), "2024-06-01T00:00:00+00:00");
+}
+
+#[test]
+fn test_time_window_bounds_in_timezone() {
+    // Late evening in New York is already the next day in UTC
+    let anchor = "2024-05-16T02:00:00Z".parse::<chrono::DateTime<Utc>>().unwrap();
+
+    let (from, to) = TimeWindow::Daily
+        .bounds(anchor, chrono_tz::America::New_York)
+        .unwrap();
+    assert_eq!(from.to_rfc3339(), "2024-05-15T04:00:00+00:00");
+    assert_eq!(to.to_rfc3339(), "2024-05-16T04:00:00+00:00");
+}
+
+#[test]
+fn test_filter_params_combine_window_and_bounds() {
+    let params: ScoreQueryParams = serde_urlencoded::from_str(
+        "window=monthly&anchor=2024-05-15T12:00:00Z&from=2024-05-10T00:00:00Z&to=2024-07-01T00:00:00Z",
+    )
+    .unwrap();
+
+    let filter = params.to_filter_params().unwrap();
+    assert_eq!(filter.submitted_from.unwrap().to_rfc3339(), "2024-05-10T00:00:00+00:00");
+    assert_eq!(filter.submitted_to.unwrap().to_rfc3339(), "2024-06-01T00:00:00+00:00");
+}
+
+#[test]
+fn test_filter_params_rejects_inverted_bounds() {
+    let params: ScoreQueryParams =
+        serde_urlencoded::from_str("from=2024-05-10T00:00:00Z&to=2024-05-01T00:00:00Z").unwrap();
+    assert!(params.to_filter_params().is_err());
+}
+
+#[test]
+fn test_filter_params_invalid_timezone() {
+    assert!(serde_urlencoded::from_str::<ScoreQueryParams>("window=daily&tz=Mars/Olympus").is_err());
+}
+
+#[test]
+fn test_score_cursor_date_sql_value() {
+    let cursor = ScoreCursor {
+        id: 1,
+        sort_value: "2024-05-15T13:30:00.5+00:00".to_string(),
+    };
+    assert_eq!(cursor.to_sql_value("submitted_at"), "2024-05-15 13:30:00.500");
+    assert_eq!(cursor.to_sql_value("user_name"), cursor.sort_value);
+}
+
+#[test]
+fn test_deleted_filter_from_flags() {
+    assert_eq!(DeletedFilter::from_flags(None, None), DeletedFilter::Exclude);
+    assert_eq!(DeletedFilter::from_flags(Some(true), None), DeletedFilter::Include);
+    assert_eq!(DeletedFilter::from_flags(Some(false), Some(true)), DeletedFilter::Only);
+    assert_eq!(DeletedFilter::from_flags(Some(true), Some(true)), DeletedFilter::Only);
+
+    assert!(DeletedFilter::Include.includes_live() && DeletedFilter::Include.includes_deleted());
+    assert!(!DeletedFilter::Only.includes_live());
+    assert!(!DeletedFilter::Exclude.includes_deleted());
+    assert_eq!(DeletedFilter::Only.to_sql_condition("score.deleted_at"), "score.deleted_at IS NOT NULL");
+}
+
+#[test]
+fn test_score_query_deleted_flags() {
+    let params: ScoreQueryParams = serde_urlencoded::from_str("include_deleted=true").unwrap();
+    assert_eq!(params.to_filter_params().unwrap().deleted, DeletedFilter::Include);
+
+    let params: GameQueryParams = serde_urlencoded::from_str("limit=5&only_deleted=true").unwrap();
+    assert_eq!(params.deleted_filter(), DeletedFilter::Only);
+    assert_eq!(params.to_pagination_params().limit, Some(5));
+}
@@ -0,0 +1,68 @@
+use chrono::{TimeZone, Utc};
+use leadr_api::models::player::{PlayerGameStats, PlayerStatsSummary, UpdatePlayer, MAX_AVATAR_URL_LEN};
+use leadr_api::models::Score;
+
+#[test]
+fn test_update_player_validation() {
+    assert!(UpdatePlayer::default().validate().is_ok());
+
+    let update = UpdatePlayer {
+        user_name: Some("Alice".to_string()),
+        avatar_url: Some("https://example.com/alice.png".to_string()),
+        metadata: Some(serde_json::json!({ "country": "NZ" })),
+    };
+    assert!(update.validate().is_ok());
+
+    let update = UpdatePlayer { user_name: Some(String::new()), ..Default::default() };
+    assert!(update.validate().is_err());
+
+    let update = UpdatePlayer { avatar_url: Some("javascript:alert(1)".to_string()), ..Default::default() };
+    assert!(update.validate().is_err());
+
+    let long_url = format!("https://example.com/{}", "a".repeat(MAX_AVATAR_URL_LEN));
+    let update = UpdatePlayer { avatar_url: Some(long_url), ..Default::default() };
+    assert!(update.validate().is_err());
+}
+
+fn game_stats(game_hex_id: &str, submissions: i64, average_score_val: f64, rank: i64, day: u32) -> PlayerGameStats {
+    let played_at = Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap();
+    let score = Score {
+        id: 1,
+        game_hex_id: game_hex_id.to_string(),
+        leaderboard_id: 1,
+        score: average_score_val.to_string(),
+        score_val: average_score_val,
+        user_name: "Alice".to_string(),
+        user_id: "alice".to_string(),
+        extra: None,
+        submitted_at: played_at,
+        deleted_at: None,
+        rank: Some(rank),
+    };
+    PlayerGameStats {
+        game_hex_id: game_hex_id.to_string(),
+        game_name: game_hex_id.to_string(),
+        submissions,
+        best_score: score.clone(),
+        latest_score: score,
+        rank,
+        first_played_at: played_at,
+        last_played_at: played_at,
+        average_score_val,
+    }
+}
+
+#[test]
+fn test_player_stats_summary() {
+    let games = [game_stats("abc123", 3, 200.0, 4, 2), game_stats("def456", 1, 40.0, 2, 9)];
+    let summary = PlayerStatsSummary::from_games(&games);
+
+    assert_eq!(summary.games_played, 2);
+    assert_eq!(summary.submissions, 4);
+    assert_eq!(summary.average_score_val, Some(160.0));
+    assert_eq!(summary.best_rank, Some(2));
+    assert_eq!(summary.first_played_at, Some(Utc.with_ymd_and_hms(2024, 1, 2, 12, 0, 0).unwrap()));
+    assert_eq!(summary.last_played_at, Some(Utc.with_ymd_and_hms(2024, 1, 9, 12, 0, 0).unwrap()));
+
+    assert_eq!(PlayerStatsSummary::from_games(&[]), PlayerStatsSummary::default());
+}
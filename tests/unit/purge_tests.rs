@@ -0,0 +1,32 @@
+use chrono::{TimeZone, Utc};
+use leadr_api::models::purge::{purge_after_days_from_env, PurgeQueryParams, PURGE_AFTER_DAYS_VAR};
+
+#[test]
+fn test_purge_cutoff() {
+    let now = Utc.with_ymd_and_hms(2024, 3, 31, 12, 0, 0).unwrap();
+
+    let params = PurgeQueryParams { before: None, older_than_days: Some(30) };
+    assert_eq!(params.cutoff(now).unwrap(), Utc.with_ymd_and_hms(2024, 3, 1, 12, 0, 0).unwrap());
+
+    let before = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let params = PurgeQueryParams { before: Some(before), older_than_days: None };
+    assert_eq!(params.cutoff(now).unwrap(), before);
+
+    assert!(PurgeQueryParams::default().cutoff(now).is_err());
+    let params = PurgeQueryParams { before: Some(before), older_than_days: Some(1) };
+    assert!(params.cutoff(now).is_err());
+}
+
+#[test]
+fn test_purge_after_days_from_env() {
+    std::env::remove_var(PURGE_AFTER_DAYS_VAR);
+    assert_eq!(purge_after_days_from_env(), Ok(None));
+
+    std::env::set_var(PURGE_AFTER_DAYS_VAR, " 90 ");
+    assert_eq!(purge_after_days_from_env(), Ok(Some(90)));
+
+    std::env::set_var(PURGE_AFTER_DAYS_VAR, "ninety");
+    assert!(purge_after_days_from_env().is_err());
+
+    std::env::remove_var(PURGE_AFTER_DAYS_VAR);
+}
@@ -0,0 +1,60 @@
+use leadr_api::models::{AroundQueryParams, RankPolicy, DEFAULT_AROUND_RADIUS, MAX_AROUND_RADIUS};
+
+#[test]
+fn test_rank_policy_default_is_competition() {
+    assert_eq!(RankPolicy::default(), RankPolicy::Competition);
+}
+
+#[test]
+fn test_rank_policy_sql_window() {
+    assert_eq!(
+        RankPolicy::Dense.to_sql_window(None, "score_val DESC"),
+        "DENSE_RANK() OVER (ORDER BY score_val DESC)"
+    );
+    assert_eq!(
+        RankPolicy::Competition.to_sql_window(None, "score_val DESC"),
+        "RANK() OVER (ORDER BY score_val DESC)"
+    );
+    assert_eq!(
+        RankPolicy::Ordinal.to_sql_window(None, "score_val DESC"),
+        "ROW_NUMBER() OVER (ORDER BY score_val DESC, id ASC)"
+    );
+}
+
+#[test]
+fn test_rank_policy_sql_window_partitioned() {
+    assert_eq!(
+        RankPolicy::Competition.to_sql_window(Some("game_hex_id"), "score_val DESC"),
+        "RANK() OVER (PARTITION BY game_hex_id ORDER BY score_val DESC)"
+    );
+}
+
+#[test]
+fn test_rank_policy_deserialization() {
+    let policy: RankPolicy = serde_json::from_str("\"dense\"").unwrap();
+    assert_eq!(policy, RankPolicy::Dense);
+    assert!(serde_json::from_str::<RankPolicy>("\"invalid\"").is_err());
+}
+
+fn around_params(radius: Option<u32>) -> AroundQueryParams {
+    AroundQueryParams {
+        leaderboard: None,
+        radius,
+        around_score_id: None,
+        sort_by: None,
+        order: None,
+        rank_policy: None,
+    }
+}
+
+#[test]
+fn test_around_radius_default() {
+    assert_eq!(around_params(None).get_radius(), DEFAULT_AROUND_RADIUS);
+}
+
+#[test]
+fn test_around_radius_custom_and_capped() {
+    assert_eq!(around_params(Some(0)).get_radius(), 0);
+    assert_eq!(around_params(Some(3)).get_radius(), 3);
+    assert_eq!(around_params(Some(500)).get_radius(), MAX_AROUND_RADIUS);
+}
@@ -4,6 +4,7 @@ use serde_json::json;
 fn create_test_score_data() -> CreateScore {
     CreateScore {
         game_hex_id: "abc123".to_string(),
+        leaderboard: None,
         score: "1000".to_string(),
         score_val: Some(1000.5),
         user_name: "TestPlayer".to_string(),
@@ -17,7 +18,7 @@ fn test_new_score_creation() {
     let game_hex_id = "abc123".to_string();
     let create_data = create_test_score_data();
 
-    let score = Score::new(create_data);
+    let score = Score::new(create_data).unwrap();
 
     assert_eq!(score.game_hex_id, game_hex_id);
     assert_eq!(score.score, "1000");
@@ -33,6 +34,7 @@ fn test_new_score_creation() {
 fn test_new_score_without_score_val() {
     let create_data = CreateScore {
         game_hex_id: "game1".to_string(),
+        leaderboard: None,
         score: "500".to_string(),
         score_val: None,
         user_name: "Player".to_string(),
@@ -40,16 +42,28 @@ fn test_new_score_without_score_val() {
         extra: None,
     };
 
-    let score = Score::new(create_data);
+    let score = Score::new(create_data).unwrap();
 
     assert_eq!(score.score, "500");
     assert_eq!(score.score_val, 500.0); // Should default to parsed score
 }
 
+#[test]
+fn test_new_score_rejects_unparseable_score_without_score_val() {
+    let create_data = CreateScore {
+        score: "garbage".to_string(),
+        score_val: None,
+        ..create_test_score_data()
+    };
+
+    assert!(Score::new(create_data).is_err());
+}
+
 #[test]
 fn test_new_score_without_extra() {
     let create_data = CreateScore {
         game_hex_id: "game2".to_string(),
+        leaderboard: None,
         score: "250".to_string(),
         score_val: Some(250.7),
         user_name: "Player".to_string(),
@@ -57,20 +71,20 @@ fn test_new_score_without_extra() {
         extra: None,
     };
 
-    let score = Score::new(create_data);
+    let score = Score::new(create_data).unwrap();
 
     assert_eq!(score.extra, None);
 }
 
 #[test]
 fn test_is_deleted_false_by_default() {
-    let score = Score::new(create_test_score_data());
+    let score = Score::new(create_test_score_data()).unwrap();
     assert!(!score.is_deleted());
 }
 
 #[test]
 fn test_soft_delete() {
-    let mut score = Score::new(create_test_score_data());
+    let mut score = Score::new(create_test_score_data()).unwrap();
 
     score.soft_delete();
 
@@ -80,7 +94,7 @@ fn test_soft_delete() {
 
 #[test]
 fn test_restore_from_soft_delete() {
-    let mut score = Score::new(create_test_score_data());
+    let mut score = Score::new(create_test_score_data()).unwrap();
 
     score.soft_delete();
     assert!(score.is_deleted());
@@ -93,7 +107,7 @@ fn test_restore_from_soft_delete() {
 
 #[test]
 fn test_update_score_only() {
-    let mut score = Score::new(create_test_score_data());
+    let mut score = Score::new(create_test_score_data()).unwrap();
     let original_user_name = score.user_name.clone();
 
     let update = UpdateScore {
@@ -104,16 +118,33 @@ fn test_update_score_only() {
         extra: None,
     };
 
-    score.update(update);
+    score.update(update).unwrap();
 
     assert_eq!(score.score, "2000");
     assert_eq!(score.score_val, 2000.0); // Should auto-update when score changes
     assert_eq!(score.user_name, original_user_name);
 }
 
+#[test]
+fn test_update_rejects_unparseable_score_without_score_val() {
+    let mut score = Score::new(create_test_score_data()).unwrap();
+
+    let update = UpdateScore {
+        score: Some("garbage".to_string()),
+        score_val: None,
+        user_name: Some("NewPlayer".to_string()),
+        user_id: None,
+        extra: None,
+    };
+
+    assert!(score.update(update).is_err());
+    assert_eq!(score.score, "1000");
+    assert_eq!(score.score_val, 1000.5);
+}
+
 #[test]
 fn test_update_score_and_score_num() {
-    let mut score = Score::new(create_test_score_data());
+    let mut score = Score::new(create_test_score_data()).unwrap();
 
     let update = UpdateScore {
         score: Some("1500".to_string()),
@@ -123,7 +154,7 @@ fn test_update_score_and_score_num() {
         extra: None,
     };
 
-    score.update(update);
+    score.update(update).unwrap();
 
     assert_eq!(score.score, "1500");
     assert_eq!(score.score_val, 1500.75); // Should use explicit value
@@ -131,7 +162,7 @@ fn test_update_score_and_score_num() {
 
 #[test]
 fn test_update_user_info() {
-    let mut score = Score::new(create_test_score_data());
+    let mut score = Score::new(create_test_score_data()).unwrap();
     let original_score = score.score.clone();
 
     let update = UpdateScore {
@@ -142,7 +173,7 @@ fn test_update_user_info() {
         extra: None,
     };
 
-    score.update(update);
+    score.update(update).unwrap();
 
     assert_eq!(score.score, original_score);
     assert_eq!(score.user_name, "NewPlayer");
@@ -151,7 +182,7 @@ fn test_update_user_info() {
 
 #[test]
 fn test_update_extra_data() {
-    let mut score = Score::new(create_test_score_data());
+    let mut score = Score::new(create_test_score_data()).unwrap();
 
     let new_extra = json!({"achievements": ["speed_run", "perfect_score"]});
     let update = UpdateScore {
@@ -162,7 +193,7 @@ fn test_update_extra_data() {
         extra: Some(new_extra.clone()),
     };
 
-    score.update(update);
+    score.update(update).unwrap();
 
     assert_eq!(score.extra, Some(new_extra));
 }
@@ -216,3 +247,32 @@ fn test_validate_user_id_max_length() {
     let max_id = "a".repeat(255);
     assert!(Score::validate_user_id(&max_id).is_ok());
 }
+
+#[test]
+fn test_canonical_payload() {
+    let create_data = create_test_score_data();
+    assert_eq!(
+        create_data.canonical_payload(),
+        "abc123\n1000\n1000.5\nTestPlayer\nplayer123\n{\"level\":5,\"time\":120.5}"
+    );
+
+    let create_data = CreateScore {
+        score_val: None,
+        extra: Some(json!({"time": 1, "level": 2})),
+        ..create_test_score_data()
+    };
+    assert_eq!(
+        create_data.canonical_payload(),
+        "abc123\n1000\n\nTestPlayer\nplayer123\n{\"level\":2,\"time\":1}"
+    );
+
+    let create_data = CreateScore {
+        leaderboard: Some("hard".to_string()),
+        extra: None,
+        ..create_test_score_data()
+    };
+    assert_eq!(
+        create_data.canonical_payload(),
+        "abc123\n1000\n1000.5\nTestPlayer\nplayer123\n\nhard"
+    );
+}
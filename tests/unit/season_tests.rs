@@ -0,0 +1,45 @@
+use chrono::{DateTime, Duration, Utc};
+use leadr_api::models::season::Season;
+
+fn season_ending_at(ends_at: DateTime<Utc>, closed_at: Option<DateTime<Utc>>) -> Season {
+    Season {
+        id: 1,
+        game_hex_id: "abc123".to_string(),
+        name: "Season 1".to_string(),
+        starts_at: ends_at - Duration::days(30),
+        ends_at,
+        closed_at,
+        created_at: ends_at - Duration::days(31),
+    }
+}
+
+#[test]
+fn test_validate_season_name() {
+    assert!(Season::validate_name("Spring").is_ok());
+    assert!(Season::validate_name("   ").is_err());
+    assert!(Season::validate_name(&"a".repeat(256)).is_err());
+}
+
+#[test]
+fn test_validate_season_schedule() {
+    let now = Utc::now();
+    assert!(Season::validate_schedule(now, now + Duration::days(1)).is_ok());
+    assert!(Season::validate_schedule(now, now).is_err());
+    assert!(Season::validate_schedule(now, now - Duration::days(1)).is_err());
+}
+
+#[test]
+fn test_effective_end() {
+    let ends_at = Utc::now();
+
+    let open = season_ending_at(ends_at, None);
+    assert!(!open.is_closed());
+    assert_eq!(open.effective_end(), ends_at);
+
+    let closed_early = season_ending_at(ends_at, Some(ends_at - Duration::days(2)));
+    assert!(closed_early.is_closed());
+    assert_eq!(closed_early.effective_end(), ends_at - Duration::days(2));
+
+    let closed_late = season_ending_at(ends_at, Some(ends_at + Duration::minutes(1)));
+    assert_eq!(closed_late.effective_end(), ends_at);
+}
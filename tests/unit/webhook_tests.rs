@@ -0,0 +1,48 @@
+use chrono::Duration;
+use leadr_api::models::webhook::{
+    retry_delay, DeliveryQueryParams, Webhook, WebhookEvent, MAX_DELIVERY_LIMIT,
+};
+
+#[test]
+fn test_retry_delay_doubles_up_to_the_cap() {
+    assert_eq!(retry_delay(1), Duration::seconds(30));
+    assert_eq!(retry_delay(2), Duration::seconds(60));
+    assert_eq!(retry_delay(3), Duration::seconds(120));
+    assert_eq!(retry_delay(7), Duration::seconds(1920));
+    assert_eq!(retry_delay(8), Duration::hours(1));
+    assert_eq!(retry_delay(100), Duration::hours(1));
+}
+
+#[test]
+fn test_webhook_url_validation() {
+    assert!(Webhook::validate_url("https://example.com/hook").is_ok());
+    assert!(Webhook::validate_url("http://127.0.0.1:8080/hook?x=1").is_ok());
+    assert!(Webhook::validate_url("ftp://example.com/hook").is_err());
+    assert!(Webhook::validate_url("/relative/hook").is_err());
+    assert!(Webhook::validate_url(&format!("https://example.com/{}", "a".repeat(2048))).is_err());
+}
+
+#[test]
+fn test_webhook_events() {
+    let events = Webhook::normalize_events(vec![
+        WebhookEvent::GameDeleted,
+        WebhookEvent::ScoreCreated,
+        WebhookEvent::GameDeleted,
+    ])
+    .unwrap();
+    assert_eq!(events, vec![WebhookEvent::ScoreCreated, WebhookEvent::GameDeleted]);
+    assert_eq!(Webhook::events_json(&events), r#"["score.created","game.deleted"]"#);
+    assert!(Webhook::normalize_events(Vec::new()).is_err());
+
+    assert_eq!("score.top_n".parse::<WebhookEvent>().unwrap(), WebhookEvent::ScoreTopN);
+    assert!("score.exploded".parse::<WebhookEvent>().is_err());
+}
+
+#[test]
+fn test_delivery_limit() {
+    assert_eq!(DeliveryQueryParams::default().get_limit(), 50);
+    let params = DeliveryQueryParams { status: None, limit: Some(0) };
+    assert_eq!(params.get_limit(), 1);
+    let params = DeliveryQueryParams { status: None, limit: Some(10_000) };
+    assert_eq!(params.get_limit(), MAX_DELIVERY_LIMIT);
+}